@@ -0,0 +1,50 @@
+//! Typed errors for file reads.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(thiserror::Error, Debug)]
+pub enum FsError {
+    #[error("not found: {path:?}")]
+    NotFound { path: PathBuf },
+
+    #[error("permission denied: {path:?}")]
+    PermissionDenied { path: PathBuf },
+
+    /// The file is not UTF-8; `valid_up_to` is the byte offset of the first
+    /// invalid sequence.
+    #[error("invalid UTF-8 in {path:?} at byte {valid_up_to}")]
+    InvalidUtf8 { path: PathBuf, valid_up_to: u64 },
+
+    #[error("I/O error at {path:?}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+}
+
+/// Convenience alias used throughout the crate.
+pub type Result<T> = std::result::Result<T, FsError>;
+
+impl FsError {
+    /// Classify an I/O error raised while working on `path`.
+    pub fn from_io(path: &Path, source: io::Error) -> Self {
+        let path = path.to_path_buf();
+        match source.kind() {
+            io::ErrorKind::NotFound => Self::NotFound { path },
+            io::ErrorKind::PermissionDenied => Self::PermissionDenied { path },
+            _ => Self::Io { path, source },
+        }
+    }
+
+    /// Path the error refers to.
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::NotFound { path }
+            | Self::PermissionDenied { path }
+            | Self::InvalidUtf8 { path, .. }
+            | Self::Io { path, .. } => path,
+        }
+    }
+}
@@ -0,0 +1,17 @@
+//! Forge file system helpers.
+//!
+//! - [`read_to_string`] / [`read_stream`]: UTF-8 file reads with typed
+//!   errors ([`FsError`]) that tell a missing file, a permission problem and
+//!   non-UTF-8 content apart. [`read_stream`] yields the text in chunks, so
+//!   large files never have to sit in memory at once.
+//! - [`build_simple_tree`] / [`TreeBuilder`]: a deterministic file tree from
+//!   `/`-separated relative paths (directories first, then case-insensitive
+//!   by name), independent of insertion order.
+
+pub mod error;
+pub mod read;
+pub mod tree;
+
+pub use error::{FsError, Result};
+pub use read::{read_stream, read_to_string, TextChunks};
+pub use tree::{build_simple_tree, NodeKind, TreeBuilder, TreeNode};
@@ -0,0 +1,143 @@
+//! UTF-8 file reads: whole-file and streaming.
+
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::{Path, PathBuf};
+
+use crate::error::{FsError, Result};
+
+/// Chunk size used by [`read_stream`] (64 KiB)
+pub const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Read a whole file as UTF-8, byte for byte (whitespace, BOMs and line
+/// endings are preserved).
+pub fn read_to_string(path: impl AsRef<Path>) -> Result<String> {
+    let path = path.as_ref();
+    let bytes = std::fs::read(path).map_err(|e| FsError::from_io(path, e))?;
+    String::from_utf8(bytes).map_err(|e| FsError::InvalidUtf8 {
+        path: path.to_path_buf(),
+        valid_up_to: e.utf8_error().valid_up_to() as u64,
+    })
+}
+
+/// Open `path` for streaming UTF-8 reads in chunks of about
+/// [`DEFAULT_CHUNK_SIZE`] bytes. Opening fails with `NotFound` /
+/// `PermissionDenied`; invalid UTF-8 is reported by the chunk that hits it.
+///
+/// ```no_run
+/// let mut len = 0;
+/// for chunk in forge_fs::read_stream("big.log")? {
+///     len += chunk?.len();
+/// }
+/// # Ok::<(), forge_fs::FsError>(())
+/// ```
+pub fn read_stream(path: impl AsRef<Path>) -> Result<TextChunks> {
+    TextChunks::open(path, DEFAULT_CHUNK_SIZE)
+}
+
+/// Iterator over the UTF-8 text of a file. Chunks never split a character:
+/// an incomplete sequence at the end of one read is carried into the next.
+pub struct TextChunks {
+    path: PathBuf,
+    reader: BufReader<File>,
+    buf: Vec<u8>,
+    /// Bytes of an incomplete character carried from the previous read
+    carry: Vec<u8>,
+    /// Bytes handed out so far (for `InvalidUtf8::valid_up_to`)
+    offset: u64,
+    done: bool,
+}
+
+impl TextChunks {
+    /// Open `path` with chunks of about `chunk_size` bytes (at least 4).
+    pub fn open(path: impl AsRef<Path>, chunk_size: usize) -> Result<Self> {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|e| FsError::from_io(path, e))?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            reader: BufReader::new(file),
+            buf: vec![0; chunk_size.max(4)],
+            carry: Vec::new(),
+            offset: 0,
+            done: false,
+        })
+    }
+
+    fn invalid(&mut self, at: usize) -> FsError {
+        self.done = true;
+        FsError::InvalidUtf8 {
+            path: self.path.clone(),
+            valid_up_to: self.offset + at as u64,
+        }
+    }
+}
+
+impl Iterator for TextChunks {
+    type Item = Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            let n = match self.reader.read(&mut self.buf) {
+                Ok(n) => n,
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(FsError::from_io(&self.path, e)));
+                }
+            };
+            if n == 0 {
+                self.done = true;
+                if self.carry.is_empty() {
+                    return None;
+                }
+                // file ended inside a character
+                return Some(Err(self.invalid(0)));
+            }
+
+            let mut bytes = std::mem::take(&mut self.carry);
+            bytes.extend_from_slice(&self.buf[..n]);
+            let valid = match std::str::from_utf8(&bytes) {
+                Ok(_) => bytes.len(),
+                // `error_len() == None`: truncated sequence at the end, wait for more bytes
+                Err(e) if e.error_len().is_none() => e.valid_up_to(),
+                Err(e) => return Some(Err(self.invalid(e.valid_up_to()))),
+            };
+            self.carry = bytes.split_off(valid);
+            if bytes.is_empty() {
+                continue;
+            }
+            self.offset += bytes.len() as u64;
+            // validated above
+            return Some(Ok(String::from_utf8(bytes).expect("valid UTF-8 prefix")));
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunks_never_split_characters_and_report_invalid_offsets() {
+        let dir = tempfile::tempdir().unwrap();
+        let text = "añ€😀".repeat(50);
+        let path = dir.path().join("mixed.txt");
+        std::fs::write(&path, &text).unwrap();
+
+        // 5-byte reads land inside multi-byte characters every time
+        let chunks: Vec<String> = TextChunks::open(&path, 5).unwrap().map(|c| c.unwrap()).collect();
+        assert!(chunks.len() > 10);
+        assert_eq!(chunks.concat(), text);
+
+        let bad = dir.path().join("bad.bin");
+        std::fs::write(&bad, [b'o', b'k', 0xff, b'!']).unwrap();
+        let err = TextChunks::open(&bad, 1).unwrap().find_map(|c| c.err()).unwrap();
+        assert!(matches!(err, FsError::InvalidUtf8 { valid_up_to: 2, .. }), "{err}");
+        assert!(matches!(read_to_string(&bad), Err(FsError::InvalidUtf8 { valid_up_to: 2, .. })));
+
+        // truncated final character
+        std::fs::write(&bad, &"é".as_bytes()[..1]).unwrap();
+        assert!(matches!(read_stream(&bad).unwrap().next(), Some(Err(FsError::InvalidUtf8 { valid_up_to: 0, .. }))));
+    }
+}
@@ -0,0 +1,153 @@
+//! Deterministic file tree from relative paths.
+//!
+//! Paths are `/`-separated (`\` is accepted too); empty and `.` segments are
+//! dropped, so leading, trailing and doubled slashes do not matter. The last
+//! segment of a path is a file unless another path descends through it, in
+//! which case it is a directory. Children are ordered directories first, then
+//! case-insensitively by name (exact name as tie-break), so the same set of
+//! paths always yields the same tree.
+
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+/// File or directory
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NodeKind {
+    Dir,
+    File,
+}
+
+/// One node of a built tree. The root has an empty name and path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TreeNode {
+    /// Last path segment
+    pub name: String,
+    /// Normalized `/`-separated path from the root
+    pub path: String,
+    pub kind: NodeKind,
+    /// Sorted directories first, then case-insensitive by name
+    pub children: Vec<TreeNode>,
+}
+
+impl TreeNode {
+    pub fn is_dir(&self) -> bool {
+        self.kind == NodeKind::Dir
+    }
+
+    pub fn is_file(&self) -> bool {
+        self.kind == NodeKind::File
+    }
+
+    /// Node at `path` (normalized like inserted paths); `""` is the root.
+    pub fn find(&self, path: &str) -> Option<&TreeNode> {
+        segments(path).try_fold(self, |node, name| node.children.iter().find(|c| c.name == name))
+    }
+
+    /// Number of segments in this node's path (root: 0).
+    pub fn depth(&self) -> usize {
+        segments(&self.path).count()
+    }
+
+    /// This node and every descendant, depth-first in child order.
+    pub fn walk(&self) -> Vec<&TreeNode> {
+        let mut out = vec![self];
+        for child in &self.children {
+            out.extend(child.walk());
+        }
+        out
+    }
+}
+
+/// Accumulates paths; [`TreeBuilder::build`] produces the sorted tree.
+#[derive(Debug, Clone, Default)]
+pub struct TreeBuilder {
+    root: Entry,
+}
+
+/// Unsorted build-time node; a `BTreeMap` keeps insertion order irrelevant.
+#[derive(Debug, Clone, Default)]
+struct Entry {
+    /// Named as a directory (an ancestor of some other path)
+    dir: bool,
+    children: BTreeMap<String, Entry>,
+}
+
+impl TreeBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert one path; repeated paths and shared prefixes are merged.
+    pub fn insert(&mut self, path: &str) -> &mut Self {
+        let mut node = &mut self.root;
+        node.dir = true;
+        for name in segments(path) {
+            node.dir = true;
+            node = node.children.entry(name.to_string()).or_default();
+        }
+        self
+    }
+
+    pub fn build(&self) -> TreeNode {
+        finish(String::new(), String::new(), &self.root)
+    }
+}
+
+/// Build a tree from relative paths in one call.
+pub fn build_simple_tree<I, S>(paths: I) -> TreeNode
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut builder = TreeBuilder::new();
+    for path in paths {
+        builder.insert(path.as_ref());
+    }
+    builder.build()
+}
+
+fn finish(name: String, path: String, entry: &Entry) -> TreeNode {
+    let mut children: Vec<TreeNode> = entry
+        .children
+        .iter()
+        .map(|(child, e)| {
+            let child_path = if path.is_empty() { child.clone() } else { format!("{path}/{child}") };
+            finish(child.clone(), child_path, e)
+        })
+        .collect();
+    children.sort_by(child_order);
+    TreeNode {
+        name,
+        path,
+        kind: if entry.dir || !entry.children.is_empty() { NodeKind::Dir } else { NodeKind::File },
+        children,
+    }
+}
+
+/// Directories first, then case-insensitive by name, then exact name.
+fn child_order(a: &TreeNode, b: &TreeNode) -> Ordering {
+    b.is_dir()
+        .cmp(&a.is_dir())
+        .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+        .then_with(|| a.name.cmp(&b.name))
+}
+
+fn segments(path: &str) -> impl Iterator<Item = &str> {
+    path.split(['/', '\\']).filter(|s| !s.is_empty() && *s != ".")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_later_named_as_dir_becomes_a_dir() {
+        let a = build_simple_tree(["docs", "docs/readme.md"]);
+        let b = build_simple_tree(["docs/readme.md", "docs"]);
+        assert_eq!(a, b);
+        assert!(a.find("docs").unwrap().is_dir());
+        assert!(a.find("./docs//readme.md").unwrap().is_file());
+        assert_eq!(a.find("docs/readme.md").unwrap().depth(), 2);
+        assert!(build_simple_tree(Vec::<&str>::new()).children.is_empty());
+    }
+}
@@ -0,0 +1,55 @@
+// ============================================================================
+// BDD Fixture — forge-fs
+// Specs: resources/bdd/forge-fs/{file_read,tree_builder}.yaml
+// State shared by every glue module; each scenario binary pulls it in with
+// `#[path = "../common/mod.rs"] mod common;` and uses only the parts it needs.
+// ============================================================================
+
+#![allow(dead_code)]
+
+use forge_fs::TreeNode;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+/// State shared by the steps of a scenario.
+#[derive(Default)]
+pub struct World {
+    pub dir: Option<TempDir>,
+    pub path: PathBuf,
+    pub expected: String,
+    pub result: Option<forge_fs::Result<String>>,
+    pub paths: Vec<String>,
+    pub tree: Option<TreeNode>,
+    pub permutations: Vec<Vec<&'static str>>,
+    pub trees: Vec<TreeNode>,
+}
+
+impl World {
+    /// Write `contents` to a fresh temp file and remember it as expected.
+    pub fn write(&mut self, file: &str, contents: &str) {
+        let dir = tempfile::tempdir().unwrap();
+        self.path = dir.path().join(file);
+        std::fs::write(&self.path, contents).unwrap();
+        self.expected = contents.to_string();
+        self.dir = Some(dir);
+    }
+
+    pub fn contents(&self) -> &str {
+        match self.result.as_ref().expect("file was read") {
+            Ok(text) => text,
+            Err(err) => panic!("read failed: {err}"),
+        }
+    }
+
+    pub fn set_paths(&mut self, paths: &[&str]) {
+        self.paths = paths.iter().map(|p| p.to_string()).collect();
+    }
+
+    pub fn tree(&self) -> &TreeNode {
+        self.tree.as_ref().expect("tree was built")
+    }
+
+    pub fn node(&self, path: &str) -> &TreeNode {
+        self.tree().find(path).unwrap_or_else(|| panic!("no node at {path}"))
+    }
+}
@@ -0,0 +1,40 @@
+// ============================================================================
+// BDD Glue — build_a_tree_from_mixed_files_and_dirs
+// Spec: resources/bdd/forge-fs/tree_builder.yaml
+// Step functions called in order by tests/scenario_build_a_tree_from_mixed_files_and_dirs.rs. Bodies are
+// hand-written: regenerating only appends stubs for new steps.
+// ============================================================================
+
+#[path = "../common/mod.rs"]
+mod common;
+
+pub use common::World;
+
+use forge_fs::build_simple_tree;
+
+/// Step `set_up_and_get_paths`.
+pub fn set_up_and_get_paths(world: &mut World) {
+    world.set_paths(&["Cargo.toml", "src/lib.rs", "src/tree/mod.rs", "README.md", "tests/read.rs"]);
+}
+
+/// Step `execute_and_return_build_build_simple_tree`.
+pub fn execute_and_return_build_build_simple_tree(world: &mut World) {
+    world.tree = Some(build_simple_tree(&world.paths));
+}
+
+/// Step `verify_tree_files`.
+pub fn verify_tree_files(world: &mut World) {
+    for file in ["Cargo.toml", "README.md", "src/lib.rs", "src/tree/mod.rs", "tests/read.rs"] {
+        assert!(world.node(file).is_file(), "{file} should be a file");
+    }
+    assert_eq!(world.tree().walk().iter().filter(|n| n.is_file()).count(), 5);
+}
+
+/// Step `and_verify_dirs`.
+pub fn and_verify_dirs(world: &mut World) {
+    for dir in ["src", "src/tree", "tests"] {
+        assert!(world.node(dir).is_dir(), "{dir} should be a dir");
+    }
+    assert_eq!(world.node("src/tree/mod.rs").path, "src/tree/mod.rs");
+    assert_eq!(world.node("src/tree").children.len(), 1);
+}
@@ -0,0 +1,38 @@
+// ============================================================================
+// BDD Glue — de_duplicate_repeated_paths
+// Spec: resources/bdd/forge-fs/tree_builder.yaml
+// Step functions called in order by tests/scenario_de_duplicate_repeated_paths.rs. Bodies are
+// hand-written: regenerating only appends stubs for new steps.
+// ============================================================================
+
+#[path = "../common/mod.rs"]
+mod common;
+
+pub use common::World;
+
+use forge_fs::{build_simple_tree, TreeNode};
+
+/// Step `set_up_and_get_paths_with_duplicates`.
+pub fn set_up_and_get_paths_with_duplicates(world: &mut World) {
+    world.set_paths(&["src/lib.rs", "src/lib.rs", "src", "src/main.rs", "src/lib.rs", "docs/a.md", "docs/a.md"]);
+}
+
+/// Step `execute_and_return_build_build_simple_tree`.
+pub fn execute_and_return_build_build_simple_tree(world: &mut World) {
+    world.tree = Some(build_simple_tree(&world.paths));
+}
+
+/// Step `verify_no_duplicate_dirs`.
+pub fn verify_no_duplicate_dirs(world: &mut World) {
+    assert_eq!(names(world.tree()), ["docs", "src"]);
+}
+
+/// Step `and_verify_no_duplicate_files`.
+pub fn and_verify_no_duplicate_files(world: &mut World) {
+    assert_eq!(names(world.node("src")), ["lib.rs", "main.rs"]);
+    assert_eq!(names(world.node("docs")), ["a.md"]);
+}
+
+fn names(node: &TreeNode) -> Vec<&str> {
+    node.children.iter().map(|c| c.name.as_str()).collect()
+}
@@ -0,0 +1,47 @@
+// ============================================================================
+// BDD Glue — deep_nested_paths
+// Spec: resources/bdd/forge-fs/tree_builder.yaml
+// Step functions called in order by tests/scenario_deep_nested_paths.rs. Bodies are
+// hand-written: regenerating only appends stubs for new steps.
+// ============================================================================
+
+#[path = "../common/mod.rs"]
+mod common;
+
+pub use common::World;
+
+use forge_fs::{build_simple_tree, TreeNode};
+
+const DEPTH: usize = 64;
+
+/// Step `set_up_very_deep_path_chain`.
+pub fn set_up_very_deep_path_chain(world: &mut World) {
+    let dirs: Vec<String> = (0..DEPTH).map(|i| format!("d{i}")).collect();
+    world.paths = vec![format!("{}/leaf.txt", dirs.join("/"))];
+}
+
+/// Step `execute_and_return_build_build_simple_tree`.
+pub fn execute_and_return_build_build_simple_tree(world: &mut World) {
+    world.tree = Some(build_simple_tree(&world.paths));
+}
+
+/// Step `verify_depth_matches_segments`.
+pub fn verify_depth_matches_segments(world: &mut World) {
+    let leaf = world.node(&world.paths[0]);
+    assert_eq!(leaf.depth(), DEPTH + 1);
+    let mut node = world.tree();
+    for depth in 1..=DEPTH {
+        assert_eq!(node.children.len(), 1);
+        node = &node.children[0];
+        assert!(node.is_dir());
+        assert_eq!(node.depth(), depth);
+    }
+}
+
+/// Step `and_verify_single_leaf_file_present`.
+pub fn and_verify_single_leaf_file_present(world: &mut World) {
+    let files: Vec<&TreeNode> = world.tree().walk().into_iter().filter(|n| n.is_file()).collect();
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0].name, "leaf.txt");
+    assert_eq!(files[0].path, world.paths[0]);
+}
@@ -0,0 +1,36 @@
+// ============================================================================
+// BDD Glue — handle_nonexistent_file
+// Spec: resources/bdd/forge-fs/file_read.yaml
+// Step functions called in order by tests/scenario_handle_nonexistent_file.rs. Bodies are
+// hand-written: regenerating only appends stubs for new steps.
+// ============================================================================
+
+#[path = "../common/mod.rs"]
+mod common;
+
+pub use common::World;
+
+use forge_fs::{read_to_string, FsError};
+
+/// Step `set_up_nonexistent_path`.
+pub fn set_up_nonexistent_path(world: &mut World) {
+    let dir = tempfile::tempdir().unwrap();
+    world.path = dir.path().join("missing.txt");
+    world.dir = Some(dir);
+}
+
+/// Step `execute_read_file_contents`.
+pub fn execute_read_file_contents(world: &mut World) {
+    world.result = Some(read_to_string(&world.path));
+}
+
+/// Step `verify_error_is_returned`.
+pub fn verify_error_is_returned(world: &mut World) {
+    match world.result.as_ref().expect("file was read") {
+        Err(err @ FsError::NotFound { path }) => {
+            assert_eq!(path, &world.path);
+            assert!(err.to_string().contains("missing.txt"), "{err}");
+        }
+        other => panic!("expected NotFound, got {other:?}"),
+    }
+}
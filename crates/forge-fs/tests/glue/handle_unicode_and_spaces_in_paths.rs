@@ -0,0 +1,39 @@
+// ============================================================================
+// BDD Glue — handle_unicode_and_spaces_in_paths
+// Spec: resources/bdd/forge-fs/tree_builder.yaml
+// Step functions called in order by tests/scenario_handle_unicode_and_spaces_in_paths.rs. Bodies are
+// hand-written: regenerating only appends stubs for new steps.
+// ============================================================================
+
+#[path = "../common/mod.rs"]
+mod common;
+
+pub use common::World;
+
+use forge_fs::build_simple_tree;
+
+/// Step `set_up_paths_with_unicode_and_spaces`.
+pub fn set_up_paths_with_unicode_and_spaces(world: &mut World) {
+    world.set_paths(&["données/résumé.md", "my docs/read me.txt", "日本/ファイル.rs", "emoji 😀/x.txt"]);
+}
+
+/// Step `execute_and_return_build_build_simple_tree`.
+pub fn execute_and_return_build_build_simple_tree(world: &mut World) {
+    world.tree = Some(build_simple_tree(&world.paths));
+}
+
+/// Step `verify_unicode_nodes_exist`.
+pub fn verify_unicode_nodes_exist(world: &mut World) {
+    assert!(world.node("données").is_dir());
+    assert!(world.node("données/résumé.md").is_file());
+    assert!(world.node("日本/ファイル.rs").is_file());
+    assert_eq!(world.node("emoji 😀/x.txt").name, "x.txt");
+}
+
+/// Step `and_verify_spaces_nodes_exist`.
+pub fn and_verify_spaces_nodes_exist(world: &mut World) {
+    let dir = world.node("my docs");
+    assert!(dir.is_dir());
+    assert_eq!(dir.children[0].name, "read me.txt");
+    assert_eq!(dir.children[0].path, "my docs/read me.txt");
+}
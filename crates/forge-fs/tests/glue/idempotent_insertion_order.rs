@@ -0,0 +1,43 @@
+// ============================================================================
+// BDD Glue — idempotent_insertion_order
+// Spec: resources/bdd/forge-fs/tree_builder.yaml
+// Step functions called in order by tests/scenario_idempotent_insertion_order.rs. Bodies are
+// hand-written: regenerating only appends stubs for new steps.
+// ============================================================================
+
+#[path = "../common/mod.rs"]
+mod common;
+
+pub use common::World;
+
+use forge_fs::build_simple_tree;
+
+/// Step `set_up_two_permutations_of_same_paths`.
+pub fn set_up_two_permutations_of_same_paths(world: &mut World) {
+    let paths = vec!["src/b.rs", "README.md", "src/a/mod.rs", "Docs/x.md", "src/A.rs", "build.rs"];
+    let mut reversed = paths.clone();
+    reversed.reverse();
+    world.permutations = vec![paths, reversed];
+}
+
+/// Step `execute_build_tree_for_each_permutation`.
+pub fn execute_build_tree_for_each_permutation(world: &mut World) {
+    world.trees = world.permutations.iter().map(build_simple_tree).collect();
+}
+
+/// Step `verify_structural_equality_between_trees`.
+pub fn verify_structural_equality_between_trees(world: &mut World) {
+    assert_eq!(world.trees.len(), 2);
+    assert_eq!(world.trees[0], world.trees[1]);
+}
+
+/// Step `and_verify_sorted_order_in_each_tree`.
+pub fn and_verify_sorted_order_in_each_tree(world: &mut World) {
+    for tree in &world.trees {
+        let order: Vec<&str> = tree.walk().iter().map(|n| n.path.as_str()).collect();
+        assert_eq!(
+            order,
+            ["", "Docs", "Docs/x.md", "src", "src/a", "src/a/mod.rs", "src/A.rs", "src/b.rs", "build.rs", "README.md"]
+        );
+    }
+}
@@ -0,0 +1,41 @@
+// ============================================================================
+// BDD Glue — preserve_directory_hierarchy_depth
+// Spec: resources/bdd/forge-fs/tree_builder.yaml
+// Step functions called in order by tests/scenario_preserve_directory_hierarchy_depth.rs. Bodies are
+// hand-written: regenerating only appends stubs for new steps.
+// ============================================================================
+
+#[path = "../common/mod.rs"]
+mod common;
+
+pub use common::World;
+
+use forge_fs::build_simple_tree;
+
+/// Step `set_up_deep_nested_paths`.
+pub fn set_up_deep_nested_paths(world: &mut World) {
+    world.set_paths(&["a/b/c/d.rs", "a/b/e.rs", "a/f/g/h.rs"]);
+}
+
+/// Step `execute_and_return_build_build_simple_tree`.
+pub fn execute_and_return_build_build_simple_tree(world: &mut World) {
+    world.tree = Some(build_simple_tree(&world.paths));
+}
+
+/// Step `verify_each_segment_is_a_dir`.
+pub fn verify_each_segment_is_a_dir(world: &mut World) {
+    for (dir, depth) in [("a", 1), ("a/b", 2), ("a/b/c", 3), ("a/f", 2), ("a/f/g", 3)] {
+        let node = world.node(dir);
+        assert!(node.is_dir(), "{dir} should be a dir");
+        assert_eq!(node.depth(), depth, "{dir}");
+    }
+    assert_eq!(world.node("a/b").children.len(), 2);
+}
+
+/// Step `and_verify_leaf_nodes_are_files`.
+pub fn and_verify_leaf_nodes_are_files(world: &mut World) {
+    for leaf in ["a/b/c/d.rs", "a/b/e.rs", "a/f/g/h.rs"] {
+        let node = world.node(leaf);
+        assert!(node.is_file() && node.children.is_empty(), "{leaf} should be a leaf file");
+    }
+}
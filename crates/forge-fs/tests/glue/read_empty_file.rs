@@ -0,0 +1,29 @@
+// ============================================================================
+// BDD Glue — read_empty_file
+// Spec: resources/bdd/forge-fs/file_read.yaml
+// Step functions called in order by tests/scenario_read_empty_file.rs. Bodies are
+// hand-written: regenerating only appends stubs for new steps.
+// ============================================================================
+
+#[path = "../common/mod.rs"]
+mod common;
+
+pub use common::World;
+
+use forge_fs::read_to_string;
+
+/// Step `set_up_empty_file`.
+pub fn set_up_empty_file(world: &mut World) {
+    world.write("empty.txt", "");
+}
+
+/// Step `execute_read_file_contents`.
+pub fn execute_read_file_contents(world: &mut World) {
+    world.result = Some(read_to_string(&world.path));
+}
+
+/// Step `verify_empty_result`.
+pub fn verify_empty_result(world: &mut World) {
+    assert_eq!(world.contents(), "");
+    assert_eq!(forge_fs::read_stream(&world.path).unwrap().count(), 0);
+}
@@ -0,0 +1,28 @@
+// ============================================================================
+// BDD Glue — read_file_with_leading_trailing_whitespace
+// Spec: resources/bdd/forge-fs/file_read.yaml
+// Step functions called in order by tests/scenario_read_file_with_leading_trailing_whitespace.rs. Bodies are
+// hand-written: regenerating only appends stubs for new steps.
+// ============================================================================
+
+#[path = "../common/mod.rs"]
+mod common;
+
+pub use common::World;
+
+use forge_fs::read_to_string;
+
+/// Step `set_up_file_with_whitespace`.
+pub fn set_up_file_with_whitespace(world: &mut World) {
+    world.write("spaces.txt", "\n\t  leading and trailing  \r\n\n   ");
+}
+
+/// Step `execute_read_file_contents`.
+pub fn execute_read_file_contents(world: &mut World) {
+    world.result = Some(read_to_string(&world.path));
+}
+
+/// Step `verify_whitespace_preserved`.
+pub fn verify_whitespace_preserved(world: &mut World) {
+    assert_eq!(world.contents(), world.expected);
+}
@@ -0,0 +1,29 @@
+// ============================================================================
+// BDD Glue — read_file_with_unicode_characters
+// Spec: resources/bdd/forge-fs/file_read.yaml
+// Step functions called in order by tests/scenario_read_file_with_unicode_characters.rs. Bodies are
+// hand-written: regenerating only appends stubs for new steps.
+// ============================================================================
+
+#[path = "../common/mod.rs"]
+mod common;
+
+pub use common::World;
+
+use forge_fs::read_to_string;
+
+/// Step `set_up_file_with_unicode_content`.
+pub fn set_up_file_with_unicode_content(world: &mut World) {
+    world.write("unicode.txt", "emoji 😀🚀 · accents café naïve · 日本語 · Ελληνικά · עברית\n");
+}
+
+/// Step `execute_read_file_contents`.
+pub fn execute_read_file_contents(world: &mut World) {
+    world.result = Some(read_to_string(&world.path));
+}
+
+/// Step `verify_unicode_content_preserved`.
+pub fn verify_unicode_content_preserved(world: &mut World) {
+    assert_eq!(world.contents(), world.expected);
+    assert_eq!(world.contents().chars().count(), world.expected.chars().count());
+}
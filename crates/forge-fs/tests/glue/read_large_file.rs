@@ -0,0 +1,39 @@
+// ============================================================================
+// BDD Glue — read_large_file
+// Spec: resources/bdd/forge-fs/file_read.yaml
+// Step functions called in order by tests/scenario_read_large_file.rs. Bodies are
+// hand-written: regenerating only appends stubs for new steps.
+// ============================================================================
+
+#[path = "../common/mod.rs"]
+mod common;
+
+pub use common::World;
+
+use forge_fs::read_to_string;
+
+/// Step `set_up_large_temp_file`.
+pub fn set_up_large_temp_file(world: &mut World) {
+    // ~4 MiB, well past the 64 KiB stream chunk, with multi-byte characters
+    // straddling chunk boundaries
+    let line = "large file line — ünïcödé 😀 0123456789\n";
+    world.write("large.txt", &line.repeat(4 * 1024 * 1024 / line.len()));
+}
+
+/// Step `execute_read_file_contents`.
+pub fn execute_read_file_contents(world: &mut World) {
+    world.result = Some(read_to_string(&world.path));
+}
+
+/// Step `verify_full_large_content_read`.
+pub fn verify_full_large_content_read(world: &mut World) {
+    assert_eq!(world.contents().len(), world.expected.len());
+    assert!(world.contents() == world.expected);
+
+    let chunks: Vec<String> = forge_fs::read_stream(&world.path)
+        .unwrap()
+        .collect::<forge_fs::Result<_>>()
+        .unwrap();
+    assert!(chunks.len() > 1, "large file should stream in several chunks");
+    assert!(chunks.concat() == world.expected);
+}
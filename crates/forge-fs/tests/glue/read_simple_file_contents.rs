@@ -0,0 +1,28 @@
+// ============================================================================
+// BDD Glue — read_simple_file_contents
+// Spec: resources/bdd/forge-fs/file_read.yaml
+// Step functions called in order by tests/scenario_read_simple_file_contents.rs. Bodies are
+// hand-written: regenerating only appends stubs for new steps.
+// ============================================================================
+
+#[path = "../common/mod.rs"]
+mod common;
+
+pub use common::World;
+
+use forge_fs::read_to_string;
+
+/// Step `set_up_temp_file_with_known_text`.
+pub fn set_up_temp_file_with_known_text(world: &mut World) {
+    world.write("known.txt", "forge reads files\nline two\n");
+}
+
+/// Step `execute_read_file_contents`.
+pub fn execute_read_file_contents(world: &mut World) {
+    world.result = Some(read_to_string(&world.path));
+}
+
+/// Step `verify_contents_match_expected`.
+pub fn verify_contents_match_expected(world: &mut World) {
+    assert_eq!(world.contents(), world.expected);
+}
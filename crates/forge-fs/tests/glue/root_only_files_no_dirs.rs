@@ -0,0 +1,36 @@
+// ============================================================================
+// BDD Glue — root_only_files_no_dirs
+// Spec: resources/bdd/forge-fs/tree_builder.yaml
+// Step functions called in order by tests/scenario_root_only_files_no_dirs.rs. Bodies are
+// hand-written: regenerating only appends stubs for new steps.
+// ============================================================================
+
+#[path = "../common/mod.rs"]
+mod common;
+
+pub use common::World;
+
+use forge_fs::build_simple_tree;
+
+/// Step `set_up_root_only_files`.
+pub fn set_up_root_only_files(world: &mut World) {
+    world.set_paths(&["README.md", "Cargo.toml", "LICENSE", ".gitignore"]);
+}
+
+/// Step `execute_and_return_build_build_simple_tree`.
+pub fn execute_and_return_build_build_simple_tree(world: &mut World) {
+    world.tree = Some(build_simple_tree(&world.paths));
+}
+
+/// Step `verify_all_files_are_direct_children_of_root`.
+pub fn verify_all_files_are_direct_children_of_root(world: &mut World) {
+    let root = world.tree();
+    assert_eq!(root.children.len(), 4);
+    assert!(root.children.iter().all(|c| c.is_file() && c.depth() == 1));
+}
+
+/// Step `and_verify_no_dir_nodes_created`.
+pub fn and_verify_no_dir_nodes_created(world: &mut World) {
+    let dirs: Vec<&str> = world.tree().walk().iter().filter(|n| n.is_dir()).map(|n| n.path.as_str()).collect();
+    assert_eq!(dirs, [""], "only the root is a dir");
+}
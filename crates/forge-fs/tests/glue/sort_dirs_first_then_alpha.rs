@@ -0,0 +1,42 @@
+// ============================================================================
+// BDD Glue — sort_dirs_first_then_alpha
+// Spec: resources/bdd/forge-fs/tree_builder.yaml
+// Step functions called in order by tests/scenario_sort_dirs_first_then_alpha.rs. Bodies are
+// hand-written: regenerating only appends stubs for new steps.
+// ============================================================================
+
+#[path = "../common/mod.rs"]
+mod common;
+
+pub use common::World;
+
+use forge_fs::{build_simple_tree, TreeNode};
+
+/// Step `set_up_mixed_unsorted_children`.
+pub fn set_up_mixed_unsorted_children(world: &mut World) {
+    world.set_paths(&["zeta.txt", "Beta/x.rs", "alpha.txt", "gamma/y.rs", "Alpha/z.rs", "beta.txt", "Zulu/w.rs"]);
+}
+
+/// Step `execute_and_return_build_build_simple_tree`.
+pub fn execute_and_return_build_build_simple_tree(world: &mut World) {
+    world.tree = Some(build_simple_tree(&world.paths));
+}
+
+/// Step `verify_dirs_listed_before_files`.
+pub fn verify_dirs_listed_before_files(world: &mut World) {
+    let kinds: Vec<bool> = world.tree().children.iter().map(|c| c.is_dir()).collect();
+    let first_file = kinds.iter().position(|d| !d).unwrap();
+    assert!(kinds[..first_file].iter().all(|d| *d) && kinds[first_file..].iter().all(|d| !d), "{kinds:?}");
+}
+
+/// Step `and_verify_case_insensitive_alpha_order`.
+pub fn and_verify_case_insensitive_alpha_order(world: &mut World) {
+    assert_eq!(
+        names(world.tree()),
+        ["Alpha", "Beta", "gamma", "Zulu", "alpha.txt", "beta.txt", "zeta.txt"]
+    );
+}
+
+fn names(node: &TreeNode) -> Vec<&str> {
+    node.children.iter().map(|c| c.name.as_str()).collect()
+}
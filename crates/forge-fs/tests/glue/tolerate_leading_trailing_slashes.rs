@@ -0,0 +1,40 @@
+// ============================================================================
+// BDD Glue — tolerate_leading_trailing_slashes
+// Spec: resources/bdd/forge-fs/tree_builder.yaml
+// Step functions called in order by tests/scenario_tolerate_leading_trailing_slashes.rs. Bodies are
+// hand-written: regenerating only appends stubs for new steps.
+// ============================================================================
+
+#[path = "../common/mod.rs"]
+mod common;
+
+pub use common::World;
+
+use forge_fs::{build_simple_tree, TreeNode};
+
+/// Step `set_up_paths_with_leading_and_trailing_slashes`.
+pub fn set_up_paths_with_leading_and_trailing_slashes(world: &mut World) {
+    world.set_paths(&["/src/lib.rs", "src/lib.rs/", "src//main.rs", "/docs/guide.md/", "docs/guide.md"]);
+}
+
+/// Step `execute_and_return_build_build_simple_tree`.
+pub fn execute_and_return_build_build_simple_tree(world: &mut World) {
+    world.tree = Some(build_simple_tree(&world.paths));
+}
+
+/// Step `verify_normalized_structure`.
+pub fn verify_normalized_structure(world: &mut World) {
+    assert_eq!(world.tree(), &build_simple_tree(["src/lib.rs", "src/main.rs", "docs/guide.md"]));
+    assert_eq!(world.node("src/lib.rs").path, "src/lib.rs");
+}
+
+/// Step `and_verify_no_duplicate_nodes_from_slashes`.
+pub fn and_verify_no_duplicate_nodes_from_slashes(world: &mut World) {
+    assert_eq!(names(world.tree()), ["docs", "src"]);
+    assert_eq!(names(world.node("src")), ["lib.rs", "main.rs"]);
+    assert!(world.tree().walk().iter().all(|n| !n.name.is_empty() || n.path.is_empty()));
+}
+
+fn names(node: &TreeNode) -> Vec<&str> {
+    node.children.iter().map(|c| c.name.as_str()).collect()
+}
@@ -0,0 +1,19 @@
+// ============================================================================
+// BDD Scenario — build_a_tree_from_mixed_files_and_dirs
+// Spec: resources/bdd/forge-fs/tree_builder.yaml
+// Generated by `forge-template bdd`; do not edit. Step bodies live in
+// tests/glue/build_a_tree_from_mixed_files_and_dirs.rs.
+// ============================================================================
+
+#[path = "glue/build_a_tree_from_mixed_files_and_dirs.rs"]
+mod glue;
+
+/// Builds a tree from mixed files and directories and preserves nesting.
+#[test]
+fn build_a_tree_from_mixed_files_and_dirs() {
+    let mut world = glue::World::default();
+    glue::set_up_and_get_paths(&mut world);
+    glue::execute_and_return_build_build_simple_tree(&mut world);
+    glue::verify_tree_files(&mut world);
+    glue::and_verify_dirs(&mut world);
+}
@@ -0,0 +1,19 @@
+// ============================================================================
+// BDD Scenario — de_duplicate_repeated_paths
+// Spec: resources/bdd/forge-fs/tree_builder.yaml
+// Generated by `forge-template bdd`; do not edit. Step bodies live in
+// tests/glue/de_duplicate_repeated_paths.rs.
+// ============================================================================
+
+#[path = "glue/de_duplicate_repeated_paths.rs"]
+mod glue;
+
+/// Multiple mentions of the same file/dir do not produce duplicate nodes.
+#[test]
+fn de_duplicate_repeated_paths() {
+    let mut world = glue::World::default();
+    glue::set_up_and_get_paths_with_duplicates(&mut world);
+    glue::execute_and_return_build_build_simple_tree(&mut world);
+    glue::verify_no_duplicate_dirs(&mut world);
+    glue::and_verify_no_duplicate_files(&mut world);
+}
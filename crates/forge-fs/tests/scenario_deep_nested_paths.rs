@@ -0,0 +1,19 @@
+// ============================================================================
+// BDD Scenario — deep_nested_paths
+// Spec: resources/bdd/forge-fs/tree_builder.yaml
+// Generated by `forge-template bdd`; do not edit. Step bodies live in
+// tests/glue/deep_nested_paths.rs.
+// ============================================================================
+
+#[path = "glue/deep_nested_paths.rs"]
+mod glue;
+
+/// Correctly constructs very deep directory chains with a single leaf file.
+#[test]
+fn deep_nested_paths() {
+    let mut world = glue::World::default();
+    glue::set_up_very_deep_path_chain(&mut world);
+    glue::execute_and_return_build_build_simple_tree(&mut world);
+    glue::verify_depth_matches_segments(&mut world);
+    glue::and_verify_single_leaf_file_present(&mut world);
+}
@@ -0,0 +1,18 @@
+// ============================================================================
+// BDD Scenario — handle_nonexistent_file
+// Spec: resources/bdd/forge-fs/file_read.yaml
+// Generated by `forge-template bdd`; do not edit. Step bodies live in
+// tests/glue/handle_nonexistent_file.rs.
+// ============================================================================
+
+#[path = "glue/handle_nonexistent_file.rs"]
+mod glue;
+
+/// Returns an error or fails gracefully when the file does not exist.
+#[test]
+fn handle_nonexistent_file() {
+    let mut world = glue::World::default();
+    glue::set_up_nonexistent_path(&mut world);
+    glue::execute_read_file_contents(&mut world);
+    glue::verify_error_is_returned(&mut world);
+}
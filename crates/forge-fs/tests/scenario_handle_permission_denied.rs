@@ -0,0 +1,18 @@
+// ============================================================================
+// BDD Scenario — handle_permission_denied
+// Spec: resources/bdd/forge-fs/file_read.yaml
+// Generated by `forge-template bdd`; do not edit. Step bodies live in
+// tests/glue/handle_permission_denied.rs.
+// ============================================================================
+
+#[path = "glue/handle_permission_denied.rs"]
+mod glue;
+
+/// Returns an appropriate error when the file cannot be read due to permissions.
+#[test]
+fn handle_permission_denied() {
+    let mut world = glue::World::default();
+    glue::set_up_file_with_denied_permissions(&mut world);
+    glue::execute_read_file_contents(&mut world);
+    glue::verify_permission_error_returned(&mut world);
+}
@@ -0,0 +1,19 @@
+// ============================================================================
+// BDD Scenario — handle_unicode_and_spaces_in_paths
+// Spec: resources/bdd/forge-fs/tree_builder.yaml
+// Generated by `forge-template bdd`; do not edit. Step bodies live in
+// tests/glue/handle_unicode_and_spaces_in_paths.rs.
+// ============================================================================
+
+#[path = "glue/handle_unicode_and_spaces_in_paths.rs"]
+mod glue;
+
+/// Paths with unicode and spaces are accepted and placed correctly.
+#[test]
+fn handle_unicode_and_spaces_in_paths() {
+    let mut world = glue::World::default();
+    glue::set_up_paths_with_unicode_and_spaces(&mut world);
+    glue::execute_and_return_build_build_simple_tree(&mut world);
+    glue::verify_unicode_nodes_exist(&mut world);
+    glue::and_verify_spaces_nodes_exist(&mut world);
+}
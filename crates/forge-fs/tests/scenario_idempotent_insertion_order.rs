@@ -0,0 +1,19 @@
+// ============================================================================
+// BDD Scenario — idempotent_insertion_order
+// Spec: resources/bdd/forge-fs/tree_builder.yaml
+// Generated by `forge-template bdd`; do not edit. Step bodies live in
+// tests/glue/idempotent_insertion_order.rs.
+// ============================================================================
+
+#[path = "glue/idempotent_insertion_order.rs"]
+mod glue;
+
+/// Inserting the same set of paths in different orders yields identical trees.
+#[test]
+fn idempotent_insertion_order() {
+    let mut world = glue::World::default();
+    glue::set_up_two_permutations_of_same_paths(&mut world);
+    glue::execute_build_tree_for_each_permutation(&mut world);
+    glue::verify_structural_equality_between_trees(&mut world);
+    glue::and_verify_sorted_order_in_each_tree(&mut world);
+}
@@ -0,0 +1,19 @@
+// ============================================================================
+// BDD Scenario — preserve_directory_hierarchy_depth
+// Spec: resources/bdd/forge-fs/tree_builder.yaml
+// Generated by `forge-template bdd`; do not edit. Step bodies live in
+// tests/glue/preserve_directory_hierarchy_depth.rs.
+// ============================================================================
+
+#[path = "glue/preserve_directory_hierarchy_depth.rs"]
+mod glue;
+
+/// Deep nested paths create the correct depth and parent-child relations.
+#[test]
+fn preserve_directory_hierarchy_depth() {
+    let mut world = glue::World::default();
+    glue::set_up_deep_nested_paths(&mut world);
+    glue::execute_and_return_build_build_simple_tree(&mut world);
+    glue::verify_each_segment_is_a_dir(&mut world);
+    glue::and_verify_leaf_nodes_are_files(&mut world);
+}
@@ -0,0 +1,18 @@
+// ============================================================================
+// BDD Scenario — read_empty_file
+// Spec: resources/bdd/forge-fs/file_read.yaml
+// Generated by `forge-template bdd`; do not edit. Step bodies live in
+// tests/glue/read_empty_file.rs.
+// ============================================================================
+
+#[path = "glue/read_empty_file.rs"]
+mod glue;
+
+/// Handles empty files and returns an empty string without error.
+#[test]
+fn read_empty_file() {
+    let mut world = glue::World::default();
+    glue::set_up_empty_file(&mut world);
+    glue::execute_read_file_contents(&mut world);
+    glue::verify_empty_result(&mut world);
+}
@@ -0,0 +1,18 @@
+// ============================================================================
+// BDD Scenario — read_file_with_leading_trailing_whitespace
+// Spec: resources/bdd/forge-fs/file_read.yaml
+// Generated by `forge-template bdd`; do not edit. Step bodies live in
+// tests/glue/read_file_with_leading_trailing_whitespace.rs.
+// ============================================================================
+
+#[path = "glue/read_file_with_leading_trailing_whitespace.rs"]
+mod glue;
+
+/// Preserves whitespace including leading/trailing spaces and newlines in the file content.
+#[test]
+fn read_file_with_leading_trailing_whitespace() {
+    let mut world = glue::World::default();
+    glue::set_up_file_with_whitespace(&mut world);
+    glue::execute_read_file_contents(&mut world);
+    glue::verify_whitespace_preserved(&mut world);
+}
@@ -0,0 +1,18 @@
+// ============================================================================
+// BDD Scenario — read_file_with_unicode_characters
+// Spec: resources/bdd/forge-fs/file_read.yaml
+// Generated by `forge-template bdd`; do not edit. Step bodies live in
+// tests/glue/read_file_with_unicode_characters.rs.
+// ============================================================================
+
+#[path = "glue/read_file_with_unicode_characters.rs"]
+mod glue;
+
+/// Reads a file containing Unicode text (emojis, accented letters, non-Latin scripts).
+#[test]
+fn read_file_with_unicode_characters() {
+    let mut world = glue::World::default();
+    glue::set_up_file_with_unicode_content(&mut world);
+    glue::execute_read_file_contents(&mut world);
+    glue::verify_unicode_content_preserved(&mut world);
+}
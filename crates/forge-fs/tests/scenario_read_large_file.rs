@@ -0,0 +1,18 @@
+// ============================================================================
+// BDD Scenario — read_large_file
+// Spec: resources/bdd/forge-fs/file_read.yaml
+// Generated by `forge-template bdd`; do not edit. Step bodies live in
+// tests/glue/read_large_file.rs.
+// ============================================================================
+
+#[path = "glue/read_large_file.rs"]
+mod glue;
+
+/// Successfully reads a large file and returns complete contents.
+#[test]
+fn read_large_file() {
+    let mut world = glue::World::default();
+    glue::set_up_large_temp_file(&mut world);
+    glue::execute_read_file_contents(&mut world);
+    glue::verify_full_large_content_read(&mut world);
+}
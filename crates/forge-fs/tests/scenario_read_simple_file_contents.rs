@@ -0,0 +1,18 @@
+// ============================================================================
+// BDD Scenario — read_simple_file_contents
+// Spec: resources/bdd/forge-fs/file_read.yaml
+// Generated by `forge-template bdd`; do not edit. Step bodies live in
+// tests/glue/read_simple_file_contents.rs.
+// ============================================================================
+
+#[path = "glue/read_simple_file_contents.rs"]
+mod glue;
+
+/// Reads a small text file and returns its full contents as a string.
+#[test]
+fn read_simple_file_contents() {
+    let mut world = glue::World::default();
+    glue::set_up_temp_file_with_known_text(&mut world);
+    glue::execute_read_file_contents(&mut world);
+    glue::verify_contents_match_expected(&mut world);
+}
@@ -0,0 +1,19 @@
+// ============================================================================
+// BDD Scenario — root_only_files_no_dirs
+// Spec: resources/bdd/forge-fs/tree_builder.yaml
+// Generated by `forge-template bdd`; do not edit. Step bodies live in
+// tests/glue/root_only_files_no_dirs.rs.
+// ============================================================================
+
+#[path = "glue/root_only_files_no_dirs.rs"]
+mod glue;
+
+/// Handles a repo with only root-level files, no subdirectories.
+#[test]
+fn root_only_files_no_dirs() {
+    let mut world = glue::World::default();
+    glue::set_up_root_only_files(&mut world);
+    glue::execute_and_return_build_build_simple_tree(&mut world);
+    glue::verify_all_files_are_direct_children_of_root(&mut world);
+    glue::and_verify_no_dir_nodes_created(&mut world);
+}
@@ -0,0 +1,19 @@
+// ============================================================================
+// BDD Scenario — sort_dirs_first_then_alpha
+// Spec: resources/bdd/forge-fs/tree_builder.yaml
+// Generated by `forge-template bdd`; do not edit. Step bodies live in
+// tests/glue/sort_dirs_first_then_alpha.rs.
+// ============================================================================
+
+#[path = "glue/sort_dirs_first_then_alpha.rs"]
+mod glue;
+
+/// Children are sorted with directories first, then files, both case-insensitive alpha.
+#[test]
+fn sort_dirs_first_then_alpha() {
+    let mut world = glue::World::default();
+    glue::set_up_mixed_unsorted_children(&mut world);
+    glue::execute_and_return_build_build_simple_tree(&mut world);
+    glue::verify_dirs_listed_before_files(&mut world);
+    glue::and_verify_case_insensitive_alpha_order(&mut world);
+}
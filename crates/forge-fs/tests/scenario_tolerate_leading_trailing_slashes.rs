@@ -0,0 +1,19 @@
+// ============================================================================
+// BDD Scenario — tolerate_leading_trailing_slashes
+// Spec: resources/bdd/forge-fs/tree_builder.yaml
+// Generated by `forge-template bdd`; do not edit. Step bodies live in
+// tests/glue/tolerate_leading_trailing_slashes.rs.
+// ============================================================================
+
+#[path = "glue/tolerate_leading_trailing_slashes.rs"]
+mod glue;
+
+/// Leading or trailing slashes in rel paths are ignored during insertion.
+#[test]
+fn tolerate_leading_trailing_slashes() {
+    let mut world = glue::World::default();
+    glue::set_up_paths_with_leading_and_trailing_slashes(&mut world);
+    glue::execute_and_return_build_build_simple_tree(&mut world);
+    glue::verify_normalized_structure(&mut world);
+    glue::and_verify_no_duplicate_nodes_from_slashes(&mut world);
+}
@@ -0,0 +1,273 @@
+// ============================================================================
+// ⚙️  Forge IDE - libtest Output Parser
+// Description:
+//   Line-by-line parser for the human output of the default libtest harness
+//   (`test a::b ... ok`, `---- a::b stdout ----`, `test result: ...`).
+//   Produces one `TestCase` per reported test, with captured stdout and the
+//   panic location attached to failures. Durations come from `<0.125s>`
+//   suffixes (nightly `--report-time`) or, on stable, from the time between
+//   a test's `test a::b ... ` start and its result (only single-threaded,
+//   `timed` runs flush the start before the test runs).
+// ============================================================================
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Instant;
+
+/// Outcome of a single test
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum TestStatus {
+    Passed,
+    Failed,
+    Ignored,
+}
+
+/// Source position of a failing assertion
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TestLocation {
+    pub file: String,
+    pub line: usize,
+    pub column: usize,
+}
+
+/// One test as reported by libtest
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TestCase {
+    /// Full path as printed by libtest, e.g. `watch::tests::hub_drops_clients`
+    pub name: String,
+    /// Module part of `name` (empty for crate-root tests)
+    pub module: String,
+    pub status: TestStatus,
+    /// From `--report-time`, or measured between start and result lines;
+    /// unknown when libtest printed both at once (parallel runs)
+    pub duration_ms: Option<u64>,
+    /// Captured output, kept for failures only
+    pub stdout: Option<String>,
+    /// Where the test panicked, if it did
+    pub location: Option<TestLocation>,
+}
+
+/// Incremental libtest parser: feed lines, then `finish`
+#[derive(Debug, Default)]
+pub struct LibtestParser {
+    cases: Vec<TestCase>,
+    capturing: Option<(String, Vec<String>)>,
+    /// When each running test's start (`test a::b ... `) was seen
+    started: HashMap<String, Instant>,
+}
+
+impl LibtestParser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Consume one stdout line.
+    pub fn feed(&mut self, line: &str) {
+        self.feed_at(line, Instant::now());
+    }
+
+    /// Consume the unfinished end of stdout: a `test a::b ... ` start
+    /// seen here begins timing that test.
+    pub fn feed_partial(&mut self, text: &str, at: Instant) {
+        if self.capturing.is_some() {
+            return;
+        }
+        if let Some(name) = text.strip_prefix("test ").and_then(|t| t.strip_suffix(" ... ")) {
+            self.started.entry(name.to_string()).or_insert(at);
+        }
+    }
+
+    /// [`Self::feed`] for a line that arrived at `at`.
+    pub fn feed_at(&mut self, line: &str, at: Instant) {
+        if let Some(name) = line
+            .strip_prefix("---- ")
+            .and_then(|l| l.strip_suffix(" stdout ----"))
+        {
+            self.flush_capture();
+            self.capturing = Some((name.to_string(), Vec::new()));
+            return;
+        }
+
+        if self.capturing.is_some() {
+            // the capture ends at the summary list of failed names
+            if line == "failures:" || line.starts_with("test result:") {
+                self.flush_capture();
+            } else if let Some((_, buf)) = self.capturing.as_mut() {
+                buf.push(line.to_string());
+            }
+            return;
+        }
+
+        if let Some(mut case) = parse_test_line(line) {
+            if let Some(start) = self.started.remove(&case.name) {
+                let measured = at.saturating_duration_since(start).as_millis() as u64;
+                case.duration_ms = case.duration_ms.or(Some(measured));
+            }
+            self.cases.push(case);
+        }
+    }
+
+    /// Finish parsing and return every test seen, in report order.
+    pub fn finish(mut self) -> Vec<TestCase> {
+        self.flush_capture();
+        self.cases
+    }
+
+    fn flush_capture(&mut self) {
+        let Some((name, mut lines)) = self.capturing.take() else {
+            return;
+        };
+        while lines.last().is_some_and(|l| l.trim().is_empty()) {
+            lines.pop();
+        }
+        let location = lines.iter().find_map(|l| parse_panic_location(l));
+        let target = self
+            .cases
+            .iter_mut()
+            .rev()
+            .find(|c| c.name == name && c.status == TestStatus::Failed && c.stdout.is_none());
+        if let Some(case) = target {
+            case.stdout = Some(lines.join("\n"));
+            case.location = location;
+        }
+    }
+}
+
+/// `test a::b ... ok` / `... FAILED` / `... ignored[, reason]` with an optional `<0.01s>`.
+fn parse_test_line(line: &str) -> Option<TestCase> {
+    let rest = line.strip_prefix("test ")?;
+    let (name, outcome) = rest.split_once(" ... ")?;
+    let (outcome, duration_ms) = match outcome.rsplit_once(" <") {
+        Some((o, t)) => (o, parse_seconds(t.trim_end_matches('>'))),
+        None => (outcome, None),
+    };
+    let status = match outcome {
+        "ok" => TestStatus::Passed,
+        "FAILED" => TestStatus::Failed,
+        o if o.starts_with("ignored") => TestStatus::Ignored,
+        _ => return None,
+    };
+    let name = name.to_string();
+    let module = name
+        .rsplit_once("::")
+        .map(|(m, _)| m.to_string())
+        .unwrap_or_default();
+    Some(TestCase {
+        name,
+        module,
+        status,
+        duration_ms,
+        stdout: None,
+        location: None,
+    })
+}
+
+fn parse_seconds(text: &str) -> Option<u64> {
+    let secs: f64 = text.strip_suffix('s')?.parse().ok()?;
+    Some((secs * 1000.0).round() as u64)
+}
+
+/// Both `panicked at src/lib.rs:3:5:` (current) and
+/// `panicked at 'msg', src/lib.rs:3:5` (pre-1.73) forms.
+fn parse_panic_location(line: &str) -> Option<TestLocation> {
+    let (_, after) = line.split_once("panicked at ")?;
+    let loc = match after.strip_prefix('\'') {
+        Some(quoted) => quoted.rsplit_once("', ")?.1,
+        None => after.trim_end_matches(':'),
+    };
+    let mut parts = loc.rsplitn(3, ':');
+    let column = parts.next()?.parse().ok()?;
+    let line_no = parts.next()?.parse().ok()?;
+    let file = parts.next()?.to_string();
+    Some(TestLocation {
+        file,
+        line: line_no,
+        column,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OUTPUT: &str = "\
+running 3 tests
+test tree::tests::insert_order ... ok
+test report::tests::roundtrip ... FAILED
+test slow ... ignored, needs network
+
+failures:
+
+---- report::tests::roundtrip stdout ----
+thread 'report::tests::roundtrip' panicked at crates/forge-ide/src/report.rs:77:9:
+assertion `left == right` failed
+  left: 1
+ right: 2
+
+
+failures:
+    report::tests::roundtrip
+
+test result: FAILED. 1 passed; 1 failed; 1 ignored; 0 measured; 0 filtered out
+";
+
+    #[test]
+    fn parses_statuses_output_and_location() {
+        let mut parser = LibtestParser::new();
+        OUTPUT.lines().for_each(|l| parser.feed(l));
+        let cases = parser.finish();
+
+        assert_eq!(cases.len(), 3);
+        assert_eq!(cases[0].module, "tree::tests");
+        assert_eq!(cases[0].status, TestStatus::Passed);
+        assert_eq!(cases[2].status, TestStatus::Ignored);
+        assert_eq!(cases[2].module, "");
+
+        let failed = &cases[1];
+        assert_eq!(failed.status, TestStatus::Failed);
+        assert!(failed.stdout.as_deref().unwrap().ends_with(" right: 2"));
+        assert_eq!(
+            failed.location,
+            Some(TestLocation {
+                file: "crates/forge-ide/src/report.rs".into(),
+                line: 77,
+                column: 9
+            })
+        );
+    }
+
+    #[test]
+    fn times_tests_between_start_and_result() {
+        use std::time::Duration;
+
+        let t0 = Instant::now();
+        let mut parser = LibtestParser::new();
+        parser.feed_at("running 3 tests", t0);
+        parser.feed_partial("test a::slow ... ", t0);
+        parser.feed_partial("test a::slow ... ", t0 + Duration::from_millis(5));
+        parser.feed_at("test a::slow ... ok", t0 + Duration::from_millis(250));
+        parser.feed_partial("test a::quick ... ", t0 + Duration::from_millis(250));
+        parser.feed_at("test a::quick ... ok <0.002s>", t0 + Duration::from_millis(260));
+        // parallel runs print start and result together
+        parser.feed_at("test a::parallel ... ok", t0 + Duration::from_millis(300));
+        let cases = parser.finish();
+
+        assert_eq!(cases[0].duration_ms, Some(250));
+        assert_eq!(cases[1].duration_ms, Some(2), "reported timings win");
+        assert_eq!(cases[2].duration_ms, None);
+    }
+
+    #[test]
+    fn timings_and_legacy_panic_format() {
+        let case = parse_test_line("test a::b ... ok <0.125s>").unwrap();
+        assert_eq!(case.duration_ms, Some(125));
+        let loc = parse_panic_location("thread 'a::b' panicked at 'boom', src/lib.rs:3:5").unwrap();
+        assert_eq!(
+            (loc.file.as_str(), loc.line, loc.column),
+            ("src/lib.rs", 3, 5)
+        );
+    }
+}
@@ -0,0 +1,226 @@
+// ============================================================================
+// ⚙️  Forge IDE - Cargo Message Parser
+// Description:
+//   Parses the line-delimited output of `cargo ... --message-format=json`
+//   into typed messages. Lines that are not cargo JSON (e.g. libtest output
+//   interleaved on stdout) come back as `CargoMessage::Text`.
+// ============================================================================
+
+use serde::{Deserialize, Serialize};
+
+// ------------------------------------------------------------------------
+// TYPED DIAGNOSTICS
+// ------------------------------------------------------------------------
+
+/// Severity reported by rustc
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "kebab-case")]
+pub enum DiagnosticLevel {
+    Error,
+    Warning,
+    Note,
+    Help,
+    FailureNote,
+    /// Anything newer rustc may report (e.g. internal compiler errors)
+    #[serde(other)]
+    Other,
+}
+
+/// Source location attached to a diagnostic
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DiagnosticSpan {
+    /// Path as reported by rustc (relative to the workspace root)
+    pub file: String,
+    pub line_start: usize,
+    pub line_end: usize,
+    pub column_start: usize,
+    pub column_end: usize,
+    /// True for the span the diagnostic is about
+    pub is_primary: bool,
+    /// Inline label rustc prints under the span
+    pub label: Option<String>,
+}
+
+/// A single compiler diagnostic
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Diagnostic {
+    pub level: DiagnosticLevel,
+    /// Error / lint code, e.g. `E0308` or `unused_variables`
+    pub code: Option<String>,
+    /// One-line message
+    pub message: String,
+    /// Crate target that produced the diagnostic
+    pub target: Option<String>,
+    pub spans: Vec<DiagnosticSpan>,
+    /// Full human-readable text as rustc would print it
+    pub rendered: Option<String>,
+}
+
+impl Diagnostic {
+    /// The span the diagnostic points at, if any.
+    pub fn primary_span(&self) -> Option<&DiagnosticSpan> {
+        self.spans.iter().find(|s| s.is_primary)
+    }
+}
+
+/// One parsed output line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CargoMessage {
+    /// rustc diagnostic
+    Compiler(Diagnostic),
+    /// A target finished compiling (`fresh` if it was up to date)
+    Artifact { target: String, fresh: bool },
+    /// Final line of a cargo run
+    BuildFinished { success: bool },
+    /// Valid cargo JSON we do not care about (build scripts, ...)
+    Ignored,
+    /// Not cargo JSON at all
+    Text(String),
+}
+
+// ------------------------------------------------------------------------
+// RAW WIRE FORMAT
+// ------------------------------------------------------------------------
+
+#[derive(Deserialize)]
+struct RawLine {
+    reason: String,
+    #[serde(default)]
+    message: Option<RawDiagnostic>,
+    #[serde(default)]
+    target: Option<RawTarget>,
+    #[serde(default)]
+    fresh: bool,
+    #[serde(default)]
+    success: bool,
+}
+
+#[derive(Deserialize)]
+struct RawTarget {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct RawCode {
+    code: String,
+}
+
+#[derive(Deserialize)]
+struct RawDiagnostic {
+    message: String,
+    level: DiagnosticLevel,
+    #[serde(default)]
+    code: Option<RawCode>,
+    #[serde(default)]
+    spans: Vec<RawSpan>,
+    #[serde(default)]
+    rendered: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct RawSpan {
+    file_name: String,
+    line_start: usize,
+    line_end: usize,
+    column_start: usize,
+    column_end: usize,
+    is_primary: bool,
+    #[serde(default)]
+    label: Option<String>,
+}
+
+/// Parse one stdout line of a `--message-format=json` cargo run.
+pub fn parse_line(line: &str) -> CargoMessage {
+    let trimmed = line.trim_end();
+    if !trimmed.starts_with('{') {
+        return CargoMessage::Text(trimmed.to_string());
+    }
+    let Ok(raw) = serde_json::from_str::<RawLine>(trimmed) else {
+        return CargoMessage::Text(trimmed.to_string());
+    };
+
+    match raw.reason.as_str() {
+        "compiler-message" => match raw.message {
+            Some(msg) => CargoMessage::Compiler(Diagnostic {
+                level: msg.level,
+                code: msg.code.map(|c| c.code),
+                message: msg.message,
+                target: raw.target.map(|t| t.name),
+                spans: msg
+                    .spans
+                    .into_iter()
+                    .map(|s| DiagnosticSpan {
+                        file: s.file_name,
+                        line_start: s.line_start,
+                        line_end: s.line_end,
+                        column_start: s.column_start,
+                        column_end: s.column_end,
+                        is_primary: s.is_primary,
+                        label: s.label,
+                    })
+                    .collect(),
+                rendered: msg.rendered,
+            }),
+            None => CargoMessage::Ignored,
+        },
+        "compiler-artifact" => match raw.target {
+            Some(t) => CargoMessage::Artifact {
+                target: t.name,
+                fresh: raw.fresh,
+            },
+            None => CargoMessage::Ignored,
+        },
+        "build-finished" => CargoMessage::BuildFinished {
+            success: raw.success,
+        },
+        _ => CargoMessage::Ignored,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const E0308: &str = r#"{"reason":"compiler-message","package_id":"demo 0.1.0","manifest_path":"/ws/Cargo.toml","target":{"kind":["lib"],"name":"demo","src_path":"/ws/src/lib.rs"},"message":{"rendered":"error[E0308]: mismatched types\n","children":[],"code":{"code":"E0308","explanation":"..."},"level":"error","message":"mismatched types","spans":[{"byte_end":40,"byte_start":38,"column_end":20,"column_start":18,"expansion":null,"file_name":"src/lib.rs","is_primary":true,"label":"expected `u32`, found `&str`","line_end":2,"line_start":2,"suggested_replacement":null,"suggestion_applicability":null,"text":[]}]}}"#;
+
+    #[test]
+    fn compiler_message_becomes_diagnostic() {
+        let CargoMessage::Compiler(d) = parse_line(E0308) else {
+            panic!("expected a compiler message");
+        };
+        assert_eq!(d.level, DiagnosticLevel::Error);
+        assert_eq!(d.code.as_deref(), Some("E0308"));
+        assert_eq!(d.target.as_deref(), Some("demo"));
+        let span = d.primary_span().unwrap();
+        assert_eq!(
+            (span.file.as_str(), span.line_start, span.column_start),
+            ("src/lib.rs", 2, 18)
+        );
+        assert!(d.rendered.unwrap().starts_with("error[E0308]"));
+    }
+
+    #[test]
+    fn other_lines() {
+        assert_eq!(
+            parse_line(r#"{"reason":"build-finished","success":false}"#),
+            CargoMessage::BuildFinished { success: false }
+        );
+        assert_eq!(
+            parse_line(r#"{"reason":"compiler-artifact","target":{"name":"demo"},"fresh":true}"#),
+            CargoMessage::Artifact {
+                target: "demo".into(),
+                fresh: true
+            }
+        );
+        assert_eq!(
+            parse_line(r#"{"reason":"build-script-executed","package_id":"x"}"#),
+            CargoMessage::Ignored
+        );
+        assert_eq!(
+            parse_line("test tests::it_works ... ok\n"),
+            CargoMessage::Text("test tests::it_works ... ok".into())
+        );
+    }
+}
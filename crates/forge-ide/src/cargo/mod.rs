@@ -0,0 +1,15 @@
+// ============================================================================
+// ⚙️  Forge IDE - Cargo Integration
+// Description:
+//   Running cargo from the IDE backend and turning its machine-readable
+//   output (compiler messages, libtest results) into typed data for
+//   commands and reports.
+// ============================================================================
+
+mod libtest;
+mod messages;
+mod runner;
+
+pub use libtest::{LibtestParser, TestCase, TestLocation, TestStatus};
+pub use messages::{parse_line, CargoMessage, Diagnostic, DiagnosticLevel, DiagnosticSpan};
+pub use runner::{cargo_bin, run_cargo, run_cargo_streaming, StdoutChunk};
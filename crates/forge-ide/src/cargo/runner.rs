@@ -0,0 +1,106 @@
+// ============================================================================
+// ⚙️  Forge IDE - Cargo Process Runner
+// Description:
+//   Spawns cargo in the workspace root, forwards stderr (progress lines such
+//   as `Compiling ...`) to the terminal slice as it arrives, and hands each
+//   stdout line (and, on request, each unfinished line) to the caller.
+// ============================================================================
+
+use crate::terminal::{TerminalSlice, TerminalStream};
+use anyhow::{Context, Result};
+use std::ffi::OsString;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use std::process::{Command, ExitStatus, Stdio};
+use std::thread;
+
+/// Cargo binary: `$CARGO` when running under cargo, otherwise `cargo` on PATH.
+pub fn cargo_bin() -> OsString {
+    std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into())
+}
+
+/// Stdout as it arrives from cargo
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StdoutChunk<'a> {
+    /// A complete line, without its line ending
+    Line(&'a str),
+    /// Everything after the last complete line so far, e.g. libtest's
+    /// `test a::b ... ` before the test finishes
+    Partial(&'a str),
+}
+
+/// Run `cargo <args>` in `root`, streaming stderr to `terminal` and feeding
+/// every stdout line to `on_stdout`. Returns the process exit status.
+pub fn run_cargo<F>(
+    root: &Path,
+    args: &[String],
+    terminal: &TerminalSlice,
+    mut on_stdout: F,
+) -> Result<ExitStatus>
+where
+    F: FnMut(&str),
+{
+    run_cargo_streaming(root, args, terminal, |chunk| {
+        if let StdoutChunk::Line(line) = chunk {
+            on_stdout(line);
+        }
+    })
+}
+
+/// [`run_cargo`], also reporting unfinished stdout lines as they arrive.
+pub fn run_cargo_streaming<F>(
+    root: &Path,
+    args: &[String],
+    terminal: &TerminalSlice,
+    mut on_chunk: F,
+) -> Result<ExitStatus>
+where
+    F: FnMut(StdoutChunk<'_>),
+{
+    terminal.info(format!("$ cargo {}", args.join(" ")));
+
+    let mut child = Command::new(cargo_bin())
+        .args(args)
+        .current_dir(root)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("spawn cargo in {}", root.display()))?;
+
+    let stderr = child.stderr.take().expect("stderr is piped");
+    let progress = {
+        let terminal = terminal.clone();
+        thread::spawn(move || {
+            for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+                terminal.push(TerminalStream::Stderr, line);
+            }
+        })
+    };
+
+    let mut stdout = BufReader::new(child.stdout.take().expect("stdout is piped"));
+    let mut pending: Vec<u8> = Vec::new();
+    loop {
+        let read = stdout.fill_buf().context("read cargo stdout")?;
+        if read.is_empty() {
+            break;
+        }
+        pending.extend_from_slice(read);
+        let len = read.len();
+        stdout.consume(len);
+
+        while let Some(end) = pending.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = pending.drain(..=end).collect();
+            on_chunk(StdoutChunk::Line(String::from_utf8_lossy(&line[..end]).trim_end_matches('\r')));
+        }
+        if !pending.is_empty() {
+            on_chunk(StdoutChunk::Partial(&String::from_utf8_lossy(&pending)));
+        }
+    }
+    if !pending.is_empty() {
+        on_chunk(StdoutChunk::Line(&String::from_utf8_lossy(&pending)));
+    }
+
+    let _ = progress.join();
+    child.wait().context("wait for cargo")
+}
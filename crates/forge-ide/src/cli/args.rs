@@ -0,0 +1,151 @@
+use clap::{Args, Subcommand, ValueEnum};
+use std::path::PathBuf;
+
+// ============================================================================
+// BUILD COMMAND
+// ============================================================================
+#[derive(Args, Debug)]
+#[command(about = "Rebuild all Forge IDE modules from templates")]
+pub struct BuildArgs {}
+
+// ============================================================================
+// RENDER COMMAND
+// ============================================================================
+#[derive(Args, Debug)]
+#[command(about = "Render one generated module (schema, command, provider, router)")]
+pub struct RenderArgs {
+    /// Module name as declared in templates/forge_ide/core.yaml
+    pub module: String,
+
+    /// Write into crates/forge-ide/src/<module>.rs instead of stdout
+    #[arg(short, long)]
+    pub write: bool,
+}
+
+// ============================================================================
+// LIST COMMAND
+// ============================================================================
+#[derive(Args, Debug)]
+#[command(about = "List the modules declared in core.yaml")]
+pub struct ListArgs {
+    /// Include modules of inactive groups
+    #[arg(long)]
+    pub all: bool,
+}
+
+// ============================================================================
+// SERVE / REQUEST COMMANDS
+// ============================================================================
+#[derive(Args, Debug)]
+#[command(about = "Answer ForgeRequest JSON lines from stdin with ForgeResponse lines")]
+pub struct ServeArgs {}
+
+#[derive(Args, Debug)]
+#[command(about = "Dispatch one ForgeRequest (JSON) and print the ForgeResponse")]
+pub struct RequestArgs {
+    /// Request JSON, e.g. '{"BuildCrate":{"crate_name":"forge-ide"}}'; `-` reads stdin
+    pub request: String,
+}
+
+// ============================================================================
+// WATCH COMMAND
+// ============================================================================
+#[derive(Args, Debug)]
+#[command(about = "Stream live workspace tree events")]
+pub struct WatchArgs {
+    /// Directory to watch (default: the Forge root)
+    pub path: Option<PathBuf>,
+}
+
+// ============================================================================
+// CRATE VIEW COMMANDS
+// ============================================================================
+#[derive(Args, Debug)]
+#[command(about = "Show, set or clear the current crate")]
+pub struct ViewArgs {
+    /// Crate to select
+    #[arg(conflicts_with = "clear")]
+    pub crate_name: Option<String>,
+
+    /// Forget the current crate
+    #[arg(long)]
+    pub clear: bool,
+}
+
+#[derive(Args, Debug)]
+#[command(about = "New crate under crates/, added to workspace members")]
+pub struct CreateArgs {
+    /// Crate name
+    pub crate_name: String,
+
+    /// Crate flavour
+    #[arg(long, value_enum, default_value_t = CrateFlavour::Lib)]
+    pub kind: CrateFlavour,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum CrateFlavour {
+    Lib,
+    Bin,
+}
+
+#[derive(Args, Debug)]
+#[command(about = "New module directory (mod.rs) in the current crate")]
+pub struct ModDirArgs {
+    /// Module name
+    pub name: String,
+}
+
+#[derive(Args, Debug)]
+#[command(about = "New module file inside <dir> of the current crate")]
+pub struct ModFileArgs {
+    /// Parent module directory (may be nested: button/parts)
+    pub dir: String,
+
+    /// Module name
+    pub name: String,
+}
+
+#[derive(Args, Debug)]
+#[command(about = "Directory tree of a crate")]
+pub struct TreeArgs {
+    /// Crate to show (default: current)
+    pub crate_name: Option<String>,
+}
+
+// ============================================================================
+// COMPONENT COMMANDS
+// ============================================================================
+#[derive(Args, Debug)]
+#[command(about = "Generate UI components from templates/ui_component")]
+pub struct ComponentArgs {
+    #[command(subcommand)]
+    pub command: ComponentCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ComponentCommand {
+    /// Render <name>/mod.rs and its submodules, declared in the crate root
+    New(ComponentNewArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct ComponentNewArgs {
+    /// Component (module) name
+    pub name: String,
+
+    /// Target crate
+    #[arg(long = "crate", default_value = crate::workspace::COMPONENT_CRATE)]
+    pub crate_name: String,
+}
+
+// ============================================================================
+// WORKBENCH COMMAND
+// ============================================================================
+#[derive(Args, Debug)]
+#[command(about = "Render the workbench HTML skeleton from its ui_tree")]
+pub struct WorkbenchArgs {
+    /// Workbench spec (default: resources/forge_ide_workbench.yaml under the root)
+    #[arg(long)]
+    pub spec: Option<PathBuf>,
+}
@@ -0,0 +1,49 @@
+use super::args::BuildArgs;
+use super::output::{print_json, rel, Format};
+use super::CliContext;
+use crate::generate::FileChange;
+use anyhow::Result;
+use serde_json::json;
+
+pub fn run(ctx: &CliContext, _args: BuildArgs) -> Result<()> {
+    let ws = ctx.workspace()?;
+    if ctx.format == Format::Text {
+        println!("⚙️  [Forge IDE] Building all modules...");
+    }
+    let report = ws.build_modules()?;
+    let root = ws.root();
+
+    match ctx.format {
+        Format::Json => {
+            let files: Vec<_> = report
+                .files
+                .iter()
+                .map(|f| json!({ "path": rel(&f.path, root), "change": f.change, "formatted": f.formatted }))
+                .collect();
+            let changed: Vec<_> = report.changed().map(|f| rel(&f.path, root)).collect();
+            print_json(&json!({
+                "manifest": rel(&report.manifest, root),
+                "files": files,
+                "changed": changed,
+            }))?
+        }
+        Format::Text => {
+            for file in &report.files {
+                let icon = match file.change {
+                    FileChange::Created => "✅ Created",
+                    FileChange::Updated => "✏️  Updated",
+                    FileChange::Unchanged => "ℹ️  Unchanged",
+                };
+                println!("{icon}: {}", rel(&file.path, root));
+                if !file.formatted {
+                    println!("   ⚠️  rustfmt unavailable or failed; left unformatted");
+                }
+            }
+            match report.changed().count() {
+                0 => println!("✅ All modules up to date."),
+                n => println!("✅ Rebuilt {n} generated file(s)."),
+            }
+        }
+    }
+    Ok(())
+}
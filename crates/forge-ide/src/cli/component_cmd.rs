@@ -0,0 +1,15 @@
+use super::args::{ComponentArgs, ComponentCommand};
+use super::crate_cmd::print_scaffolded;
+use super::CliContext;
+use anyhow::Result;
+
+/// `component new <name>`
+pub fn run(ctx: &CliContext, args: ComponentArgs) -> Result<()> {
+    let ws = ctx.workspace()?;
+    match args.command {
+        ComponentCommand::New(args) => {
+            let report = ws.create_component(&args.crate_name, &args.name)?;
+            print_scaffolded(ctx.format, &report, &ws)
+        }
+    }
+}
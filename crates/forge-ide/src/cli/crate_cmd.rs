@@ -0,0 +1,109 @@
+use super::args::{CrateFlavour, CreateArgs, ModDirArgs, ModFileArgs, TreeArgs, ViewArgs};
+use super::output::{print_json, rel, rel_all, Format};
+use super::CliContext;
+use crate::tree::TreeNode;
+use crate::watch::IgnoreRules;
+use crate::workspace::{render_tree, CrateKind, ForgeWorkspace, Scaffolded};
+use anyhow::Result;
+use serde_json::json;
+
+/// `forge_view`: show, set or clear the current crate.
+pub fn view(ctx: &CliContext, args: ViewArgs) -> Result<()> {
+    let ws = ctx.workspace()?;
+    if args.clear {
+        ws.clear_current_crate()?;
+    } else if let Some(name) = &args.crate_name {
+        ws.set_current_crate(name)?;
+    }
+    let current = ws.current_crate();
+    let path = current.as_deref().map(|c| rel(&ws.crate_dir(c), ws.root()));
+
+    match ctx.format {
+        Format::Json => print_json(&json!({ "crate": current, "path": path }))?,
+        Format::Text => match (&current, args.clear || args.crate_name.is_some()) {
+            (None, true) => println!("✅ Cleared current crate view."),
+            (None, false) => println!("⚠️  No current crate view is set."),
+            (Some(name), changed) => {
+                if changed {
+                    println!("✅ Current crate view: {name}");
+                } else {
+                    println!("👁️  Current Forge crate view: {name}");
+                }
+                println!("📦 Path: {}", ws.crate_dir(name).display());
+            }
+        },
+    }
+    Ok(())
+}
+
+/// `forge_create`
+pub fn create(ctx: &CliContext, args: CreateArgs) -> Result<()> {
+    let ws = ctx.workspace()?;
+    let kind = match args.kind {
+        CrateFlavour::Lib => CrateKind::Lib,
+        CrateFlavour::Bin => CrateKind::Bin,
+    };
+    let report = ws.create_crate(&args.crate_name, kind)?;
+    print_scaffolded(ctx.format, &report, &ws)
+}
+
+/// `create_mod_dir`
+pub fn mod_dir(ctx: &CliContext, args: ModDirArgs) -> Result<()> {
+    let ws = ctx.workspace()?;
+    let krate = ws.require_current_crate()?;
+    let report = ws.create_mod_dir(&krate, &args.name)?;
+    print_scaffolded(ctx.format, &report, &ws)
+}
+
+/// `create_mod_file`
+pub fn mod_file(ctx: &CliContext, args: ModFileArgs) -> Result<()> {
+    let ws = ctx.workspace()?;
+    let krate = ws.require_current_crate()?;
+    let report = ws.create_mod_file(&krate, &args.dir, &args.name)?;
+    print_scaffolded(ctx.format, &report, &ws)
+}
+
+/// `tree_view`
+pub fn tree(ctx: &CliContext, args: TreeArgs) -> Result<()> {
+    let ws = ctx.workspace()?;
+    let krate = match args.crate_name {
+        Some(name) => name,
+        None => ws.require_current_crate()?,
+    };
+    let dir = ws.crate_dir(&krate);
+    let rules = IgnoreRules::load(ws.root());
+    let node = TreeNode::scan(&dir, "", &rules)?;
+
+    match ctx.format {
+        Format::Json => print_json(&node)?,
+        Format::Text => {
+            println!("🌳 Tree view for crate: {krate}");
+            println!("📁 Location: {}", dir.display());
+            print!("{}", render_tree(&node));
+        }
+    }
+    Ok(())
+}
+
+pub(super) fn print_scaffolded(format: Format, report: &Scaffolded, ws: &ForgeWorkspace) -> Result<()> {
+    let root = ws.root();
+    match format {
+        Format::Json => print_json(&json!({
+            "created": rel_all(&report.created, root),
+            "updated": rel_all(&report.updated, root),
+            "unchanged": rel_all(&report.unchanged, root),
+        }))?,
+        Format::Text => {
+            rel_all(&report.created, root)
+                .iter()
+                .for_each(|p| println!("✅ Created: {p}"));
+            rel_all(&report.updated, root)
+                .iter()
+                .for_each(|p| println!("✏️  Updated: {p}"));
+            rel_all(&report.unchanged, root)
+                .iter()
+                .for_each(|p| println!("ℹ️  Unchanged: {p}"));
+        }
+    }
+    Ok(())
+}
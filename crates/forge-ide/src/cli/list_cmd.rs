@@ -0,0 +1,57 @@
+use super::args::ListArgs;
+use super::output::{print_json, Format};
+use super::CliContext;
+use anyhow::Result;
+use serde::Serialize;
+
+/// One row of `forge-ide list`
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct ModuleRow<'a> {
+    group: &'a str,
+    active: bool,
+    name: &'a str,
+    kind: Option<&'static str>,
+    public: bool,
+    desc: Option<&'a str>,
+}
+
+pub fn run(ctx: &CliContext, args: ListArgs) -> Result<()> {
+    let ws = ctx.workspace()?;
+    let manifest = ws.core_manifest()?;
+
+    let rows: Vec<ModuleRow> = manifest
+        .payload
+        .mods
+        .iter()
+        .filter(|group| args.all || group.active)
+        .flat_map(|group| {
+            group.modules.iter().map(move |m| ModuleRow {
+                group: &group.name,
+                active: group.active,
+                name: &m.name,
+                kind: m.kind(),
+                public: m.is_pub,
+                desc: m.desc.as_deref(),
+            })
+        })
+        .collect();
+
+    match ctx.format {
+        Format::Json => print_json(&rows)?,
+        Format::Text => {
+            println!("📜 Modules of {}:", manifest.payload.crate_name);
+            for row in &rows {
+                let kind = row.kind.map(|k| format!(" [{k}]")).unwrap_or_default();
+                let inactive = if row.active { "" } else { " (inactive)" };
+                println!(
+                    " - {}::{}{kind}{inactive}  {}",
+                    row.group,
+                    row.name,
+                    row.desc.unwrap_or_default()
+                );
+            }
+        }
+    }
+    Ok(())
+}
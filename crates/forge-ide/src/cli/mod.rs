@@ -0,0 +1,150 @@
+// ============================================================================
+// ⚙️  Forge IDE - Command Line
+// Description:
+//   clap command tree behind the `forge-ide` binary. Global `--root` picks
+//   the Forge repo (otherwise discovered from the cwd / $FORGE_ROOT) and
+//   `--format json` switches every command to machine-readable output.
+// ============================================================================
+
+pub mod args;
+pub mod build_cmd;
+pub mod component_cmd;
+pub mod crate_cmd;
+pub mod list_cmd;
+pub mod output;
+pub mod render_cmd;
+pub mod request_cmd;
+pub mod serve_cmd;
+pub mod watch_cmd;
+pub mod workbench_cmd;
+
+use crate::workspace::ForgeWorkspace;
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+use output::Format;
+use std::path::PathBuf;
+
+#[derive(Parser, Debug)]
+#[command(name = "forge-ide", author, version, about = "🔥 Forge IDE CLI", long_about = None)]
+pub struct ForgeIdeCli {
+    /// Forge repo root (default: $FORGE_ROOT, then discovered from the cwd)
+    #[arg(long, global = true)]
+    pub root: Option<PathBuf>,
+
+    /// Output format
+    #[arg(long, global = true, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+
+    #[command(subcommand)]
+    pub command: Commands,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Commands {
+    /// Rebuild all Forge IDE modules from templates
+    Build(args::BuildArgs),
+
+    /// Render one generated module (stdout, or in place with --write)
+    Render(args::RenderArgs),
+
+    /// List the modules declared in templates/forge_ide/core.yaml
+    List(args::ListArgs),
+
+    /// Serve ForgeRequest JSON lines on stdin, answering on stdout
+    Serve(args::ServeArgs),
+
+    /// Dispatch a single ForgeRequest and print the response
+    Request(args::RequestArgs),
+
+    /// Stream live workspace tree events
+    Watch(args::WatchArgs),
+
+    /// Show, set or clear the current crate
+    View(args::ViewArgs),
+
+    /// New crate under crates/, added to workspace members
+    Create(args::CreateArgs),
+
+    /// New module directory (mod.rs) in the current crate
+    ModDir(args::ModDirArgs),
+
+    /// New module file inside <dir> of the current crate
+    ModFile(args::ModFileArgs),
+
+    /// Directory tree of a crate (default: current)
+    Tree(args::TreeArgs),
+
+    /// Generate UI components (forge-web-ui-components)
+    Component(args::ComponentArgs),
+
+    /// Render the workbench HTML skeleton from its ui_tree
+    Workbench(args::WorkbenchArgs),
+}
+
+/// Global options shared by every command
+#[derive(Debug, Clone)]
+pub struct CliContext {
+    pub root: Option<PathBuf>,
+    pub format: Format,
+}
+
+impl CliContext {
+    /// `--root` as given, otherwise `ForgeWorkspace::discover` from the cwd.
+    pub fn workspace(&self) -> Result<ForgeWorkspace> {
+        match &self.root {
+            Some(root) => Ok(ForgeWorkspace::new(root)),
+            None => ForgeWorkspace::discover(&std::env::current_dir()?),
+        }
+    }
+}
+
+pub fn run() -> Result<()> {
+    let cli = ForgeIdeCli::parse();
+    let ctx = CliContext {
+        root: cli.root,
+        format: cli.format,
+    };
+
+    match cli.command {
+        Commands::Build(args) => build_cmd::run(&ctx, args),
+        Commands::Render(args) => render_cmd::run(&ctx, args),
+        Commands::List(args) => list_cmd::run(&ctx, args),
+        Commands::Serve(args) => serve_cmd::run(&ctx, args),
+        Commands::Request(args) => request_cmd::run(&ctx, args),
+        Commands::Watch(args) => watch_cmd::run(&ctx, args),
+        Commands::View(args) => crate_cmd::view(&ctx, args),
+        Commands::Create(args) => crate_cmd::create(&ctx, args),
+        Commands::ModDir(args) => crate_cmd::mod_dir(&ctx, args),
+        Commands::ModFile(args) => crate_cmd::mod_file(&ctx, args),
+        Commands::Tree(args) => crate_cmd::tree(&ctx, args),
+        Commands::Component(args) => component_cmd::run(&ctx, args),
+        Commands::Workbench(args) => workbench_cmd::run(&ctx, args),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn command_tree_is_consistent() {
+        ForgeIdeCli::command().debug_assert();
+    }
+
+    #[test]
+    fn global_flags_parse_after_subcommand() {
+        let cli = ForgeIdeCli::try_parse_from([
+            "forge-ide",
+            "list",
+            "--format",
+            "json",
+            "--root",
+            "/tmp/x",
+        ])
+        .unwrap();
+        assert_eq!(cli.format, Format::Json);
+        assert_eq!(cli.root, Some(PathBuf::from("/tmp/x")));
+        assert!(matches!(cli.command, Commands::List(_)));
+    }
+}
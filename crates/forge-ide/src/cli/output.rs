@@ -0,0 +1,39 @@
+use anyhow::Result;
+use clap::ValueEnum;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+/// `--format`
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Human-readable output
+    #[default]
+    Text,
+    /// One JSON document (or JSON lines for streaming commands)
+    Json,
+}
+
+/// Pretty JSON document on stdout.
+pub fn print_json<T: Serialize>(value: &T) -> Result<()> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
+
+/// Single-line JSON on stdout (streaming commands).
+pub fn print_json_line<T: Serialize>(value: &T) -> Result<()> {
+    println!("{}", serde_json::to_string(value)?);
+    Ok(())
+}
+
+/// `path` relative to `root` for display.
+pub fn rel(path: &Path, root: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
+/// `rel` over a list of paths.
+pub fn rel_all(paths: &[PathBuf], root: &Path) -> Vec<String> {
+    paths.iter().map(|p| rel(p, root)).collect()
+}
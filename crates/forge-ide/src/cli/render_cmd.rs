@@ -0,0 +1,49 @@
+use super::args::RenderArgs;
+use super::output::{print_json, rel, Format};
+use super::CliContext;
+use anyhow::{Context, Result};
+use serde_json::json;
+use std::fs;
+
+pub fn run(ctx: &CliContext, args: RenderArgs) -> Result<()> {
+    let ws = ctx.workspace()?;
+    let manifest = ws.core_manifest()?;
+    let job = ws.module_job(&manifest, &args.module)?;
+    let rendered = ws.render_module(&job)?;
+    if !rendered.formatted {
+        eprintln!("⚠️  rustfmt unavailable or failed; output left unformatted");
+    }
+
+    let changed = if args.write {
+        let previous = fs::read_to_string(&job.output).ok();
+        let changed = previous.as_deref() != Some(rendered.code.as_str());
+        if changed {
+            fs::write(&job.output, &rendered.code)
+                .with_context(|| format!("write {}", job.output.display()))?;
+        }
+        Some(changed)
+    } else {
+        None
+    };
+
+    match (ctx.format, changed) {
+        (Format::Json, _) => print_json(&json!({
+            "module": job.name,
+            "kind": job.kind,
+            "template": rel(&job.template, ws.root()),
+            "output": rel(&job.output, ws.root()),
+            "formatted": rendered.formatted,
+            "written": args.write,
+            "changed": changed,
+            "code": if args.write { None } else { Some(&rendered.code) },
+        }))?,
+        (Format::Text, None) => print!("{}", rendered.code),
+        (Format::Text, Some(true)) => {
+            println!("✅ Rendered {}", rel(&job.output, ws.root()))
+        }
+        (Format::Text, Some(false)) => {
+            println!("ℹ️  Unchanged: {}", rel(&job.output, ws.root()))
+        }
+    }
+    Ok(())
+}
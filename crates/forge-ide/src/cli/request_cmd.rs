@@ -0,0 +1,44 @@
+use super::args::RequestArgs;
+use super::output::{print_json, Format};
+use super::serve_cmd::{forward_terminal, parse_request, router};
+use super::CliContext;
+use crate::schema::ForgeStatus;
+use anyhow::{Context, Result};
+use std::io::Read;
+
+pub fn run(ctx: &CliContext, args: RequestArgs) -> Result<()> {
+    let text = if args.request == "-" {
+        let mut buf = String::new();
+        std::io::stdin()
+            .read_to_string(&mut buf)
+            .context("read request from stdin")?;
+        buf
+    } else {
+        args.request
+    };
+    let request = parse_request(&text)?;
+
+    let ws = ctx.workspace()?;
+    let (router, terminal) = router(&ws);
+    forward_terminal(&terminal, ctx.format);
+    let response = futures::executor::block_on(router.handle(&request));
+
+    match ctx.format {
+        Format::Json => print_json(&response)?,
+        Format::Text => {
+            let icon = match response.status {
+                ForgeStatus::Ok => "✅",
+                ForgeStatus::Error => "❌",
+                ForgeStatus::Skipped => "⏭️ ",
+            };
+            println!("{icon} {}", response.message);
+            if let Some(data) = &response.data {
+                println!("{}", serde_json::to_string_pretty(data)?);
+            }
+        }
+    }
+    if response.status == ForgeStatus::Error {
+        std::process::exit(1);
+    }
+    Ok(())
+}
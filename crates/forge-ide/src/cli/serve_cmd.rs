@@ -0,0 +1,69 @@
+use super::args::ServeArgs;
+use super::output::Format;
+use super::CliContext;
+use crate::commands::default_registry;
+use crate::router::ForgeRouter;
+use crate::schema::{ForgeRequest, ForgeResponse};
+use crate::terminal::{TerminalSlice, TerminalStream};
+use crate::workspace::ForgeWorkspace;
+use anyhow::{Context, Result};
+use std::io::{BufRead, Write};
+use std::sync::Arc;
+
+pub fn run(ctx: &CliContext, _args: ServeArgs) -> Result<()> {
+    let ws = ctx.workspace()?;
+    let (router, terminal) = router(&ws);
+    forward_terminal(&terminal, ctx.format);
+    eprintln!(
+        "🚀 forge-ide serving {} ({})",
+        ws.root().display(),
+        router.info().join(", ")
+    );
+
+    let stdin = std::io::stdin();
+    let mut stdout = std::io::stdout();
+    for line in stdin.lock().lines() {
+        let line = line.context("read stdin")?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let response = match parse_request(&line) {
+            Ok(request) => futures::executor::block_on(router.handle(&request)),
+            Err(e) => ForgeResponse::error(format!("{e:#}")),
+        };
+        writeln!(stdout, "{}", serde_json::to_string(&response)?)?;
+        stdout.flush()?;
+    }
+    Ok(())
+}
+
+/// Router over the built-in commands, bound to the workspace root.
+pub(super) fn router(ws: &ForgeWorkspace) -> (ForgeRouter, TerminalSlice) {
+    let terminal = TerminalSlice::default();
+    let registry = default_registry(ws.root(), &terminal);
+    (ForgeRouter::new(Arc::new(registry)), terminal)
+}
+
+/// Echo terminal lines to stderr so stdout stays reserved for responses.
+pub(super) fn forward_terminal(terminal: &TerminalSlice, format: Format) {
+    let lines = terminal.subscribe();
+    std::thread::spawn(move || {
+        for line in lines {
+            match format {
+                Format::Json => {
+                    if let Ok(json) = serde_json::to_string(&line) {
+                        eprintln!("{json}");
+                    }
+                }
+                Format::Text => match line.stream {
+                    TerminalStream::System => eprintln!("⚙️  {}", line.text),
+                    _ => eprintln!("{}", line.text),
+                },
+            }
+        }
+    });
+}
+
+pub(super) fn parse_request(text: &str) -> Result<ForgeRequest> {
+    serde_json::from_str(text.trim()).context("invalid ForgeRequest JSON")
+}
@@ -0,0 +1,40 @@
+use super::args::WatchArgs;
+use super::output::{print_json_line, Format};
+use super::CliContext;
+use crate::tree::{join_rel, TreeEvent};
+use crate::watch::{WatchOptions, WorkspaceWatcher};
+use anyhow::Result;
+
+/// Snapshot first, then one line per event batch.
+pub fn run(ctx: &CliContext, args: WatchArgs) -> Result<()> {
+    let root = match args.path {
+        Some(path) => path,
+        None => ctx.workspace()?.root().to_path_buf(),
+    };
+    let watcher = WorkspaceWatcher::start(&root, WatchOptions::default())?;
+    let events = watcher.subscribe();
+    let snapshot = watcher.tree().lock().expect("tree poisoned").snapshot();
+
+    match ctx.format {
+        Format::Json => print_json_line(&snapshot)?,
+        Format::Text => println!("👀 Watching {}", root.display()),
+    }
+    for batch in events {
+        match ctx.format {
+            Format::Json => print_json_line(&batch)?,
+            Format::Text => batch.iter().for_each(|e| println!("{}", describe(e))),
+        }
+    }
+    Ok(())
+}
+
+fn describe(event: &TreeEvent) -> String {
+    match event {
+        TreeEvent::NodeInserted { parent, node, .. } => {
+            format!("➕ {}", join_rel(parent, &node.name))
+        }
+        TreeEvent::NodeRemoved { path } => format!("➖ {path}"),
+        TreeEvent::MetadataChanged { path, .. } => format!("✏️  {path}"),
+        TreeEvent::NodeMoved { from, to, .. } => format!("🔀 {from} → {to}"),
+    }
+}
@@ -0,0 +1,23 @@
+use super::args::WorkbenchArgs;
+use super::output::{print_json, rel, Format};
+use super::CliContext;
+use anyhow::{Context, Result};
+use forge_web_ui_components::workbench;
+use serde_json::json;
+use std::fs;
+
+/// Workbench spec, relative to the Forge root
+pub const WORKBENCH_SPEC: &str = "resources/forge_ide_workbench.yaml";
+
+pub fn run(ctx: &CliContext, args: WorkbenchArgs) -> Result<()> {
+    let ws = ctx.workspace()?;
+    let spec = args.spec.unwrap_or_else(|| ws.root().join(WORKBENCH_SPEC));
+    let yaml = fs::read_to_string(&spec).with_context(|| format!("read {}", spec.display()))?;
+    let html = workbench::render_html(&yaml).with_context(|| format!("render {}", spec.display()))?;
+
+    match ctx.format {
+        Format::Json => print_json(&json!({ "spec": rel(&spec, ws.root()), "html": html }))?,
+        Format::Text => println!("{html}"),
+    }
+    Ok(())
+}
@@ -1,7 +1,7 @@
 // ============================================================================
 // ⚙️  Auto-generated Command Module
 // Description: Defines the ForgeIdeCommand trait and dispatch enums
-// File: templates/forge_ide/generated/command.yaml
+// File: templates/forge_ide/core.yaml
 // ============================================================================
 
 use crate::schema::{ForgeRequest, ForgeResponse};
@@ -28,6 +28,7 @@ pub trait ForgeIdeCommand: Send + Sync {
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ForgeCommandKind {
     Build,
+    Test,
     Render,
     Env,
     Custom,
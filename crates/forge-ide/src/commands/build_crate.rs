@@ -0,0 +1,220 @@
+// ============================================================================
+// ⚙️  Forge IDE - BuildCrate Command
+// Description:
+//   Handles `ForgeRequest::BuildCrate`: runs `cargo build
+//   --message-format=json`, streams progress to the terminal slice, collects
+//   structured diagnostics and returns a `BuildSummary` as response data.
+//   Optionally writes the same summary as a JSON / YAML report.
+// ============================================================================
+
+use crate::cargo::{parse_line, run_cargo, CargoMessage, Diagnostic, DiagnosticLevel};
+use crate::command::ForgeIdeCommand;
+use crate::report::write_report;
+use crate::schema::{ForgeRequest, ForgeResponse};
+use crate::terminal::{TerminalSlice, TerminalStream};
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+// ------------------------------------------------------------------------
+// SUMMARY
+// ------------------------------------------------------------------------
+
+/// Result of one cargo build
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BuildSummary {
+    /// Package built with `-p`; `None` for the whole workspace
+    pub crate_name: Option<String>,
+    pub release: bool,
+    pub success: bool,
+    pub duration_ms: u64,
+    pub error_count: usize,
+    pub warning_count: usize,
+    /// Targets cargo reported as built (fresh or recompiled)
+    pub artifacts: Vec<String>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl BuildSummary {
+    /// Fold one cargo message into the summary.
+    pub fn absorb(&mut self, message: CargoMessage) {
+        match message {
+            CargoMessage::Compiler(diag) => {
+                match diag.level {
+                    DiagnosticLevel::Error => self.error_count += 1,
+                    DiagnosticLevel::Warning => self.warning_count += 1,
+                    _ => {}
+                }
+                self.diagnostics.push(diag);
+            }
+            CargoMessage::Artifact { target, .. } => {
+                if !self.artifacts.contains(&target) {
+                    self.artifacts.push(target);
+                }
+            }
+            CargoMessage::BuildFinished { success } => self.success = success,
+            CargoMessage::Ignored | CargoMessage::Text(_) => {}
+        }
+    }
+
+    /// One-line human summary used as the response message.
+    pub fn headline(&self) -> String {
+        let what = self.crate_name.as_deref().unwrap_or("workspace");
+        let verdict = if self.success { "succeeded" } else { "failed" };
+        format!(
+            "Build of {what} {verdict}: {} error(s), {} warning(s) in {} ms",
+            self.error_count, self.warning_count, self.duration_ms
+        )
+    }
+}
+
+// ------------------------------------------------------------------------
+// COMMAND
+// ------------------------------------------------------------------------
+
+/// `BuildCrate` provider bound to a workspace root
+pub struct BuildCrateCommand {
+    root: PathBuf,
+    terminal: TerminalSlice,
+}
+
+impl BuildCrateCommand {
+    pub fn new(root: impl Into<PathBuf>, terminal: TerminalSlice) -> Self {
+        Self {
+            root: root.into(),
+            terminal,
+        }
+    }
+
+    /// Cargo arguments for a build.
+    pub fn cargo_args(crate_name: Option<&str>, release: bool) -> Vec<String> {
+        let mut args = vec!["build".to_string(), "--message-format=json".to_string()];
+        if let Some(name) = crate_name {
+            args.extend(["-p".to_string(), name.to_string()]);
+        }
+        if release {
+            args.push("--release".to_string());
+        }
+        args
+    }
+
+    /// Run the build synchronously and collect its summary.
+    pub fn run(&self, crate_name: Option<&str>, release: bool) -> Result<BuildSummary> {
+        let mut summary = BuildSummary {
+            crate_name: crate_name.map(str::to_string),
+            release,
+            ..Default::default()
+        };
+        let started = Instant::now();
+        let terminal = &self.terminal;
+
+        let status = run_cargo(
+            &self.root,
+            &Self::cargo_args(crate_name, release),
+            terminal,
+            |line| {
+                let message = parse_line(line);
+                match &message {
+                    CargoMessage::Compiler(d) => {
+                        if let Some(rendered) = &d.rendered {
+                            for l in rendered.lines() {
+                                terminal.push(TerminalStream::Stdout, l);
+                            }
+                        }
+                    }
+                    CargoMessage::Text(t) if !t.is_empty() => {
+                        terminal.push(TerminalStream::Stdout, t.clone())
+                    }
+                    _ => {}
+                }
+                summary.absorb(message);
+            },
+        )?;
+
+        // `build-finished` is missing if cargo bailed out before compiling
+        summary.success = summary.success && status.success();
+        summary.duration_ms = started.elapsed().as_millis() as u64;
+        terminal.info(summary.headline());
+        Ok(summary)
+    }
+
+    fn respond(&self, summary: &BuildSummary, report: Option<&str>) -> ForgeResponse {
+        let mut headline = summary.headline();
+        if let Some(path) = report {
+            let path = self.root.join(Path::new(path));
+            match write_report(summary, &path) {
+                Ok(_) => {
+                    self.terminal
+                        .info(format!("Report written to: {}", path.display()));
+                    headline.push_str(&format!(" (report: {})", path.display()));
+                }
+                Err(e) => return ForgeResponse::error_with_data(format!("{e:#}"), summary),
+            }
+        }
+        if summary.success {
+            ForgeResponse::with_data(headline, summary)
+        } else {
+            ForgeResponse::error_with_data(headline, summary)
+        }
+    }
+}
+
+#[async_trait]
+impl ForgeIdeCommand for BuildCrateCommand {
+    async fn execute(&self, request: &ForgeRequest) -> ForgeResponse {
+        let ForgeRequest::BuildCrate {
+            crate_name,
+            release,
+            report,
+        } = request
+        else {
+            return ForgeResponse::error("BuildCrate provider received a different request");
+        };
+
+        match self.run(crate_name.as_deref(), *release) {
+            Ok(summary) => self.respond(&summary, report.as_deref()),
+            Err(e) => ForgeResponse::error(format!("cargo build failed to run: {e:#}")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summary_counts_levels_and_dedups_artifacts() {
+        let mut summary = BuildSummary::default();
+        let lines = [
+            r#"{"reason":"compiler-artifact","target":{"name":"serde"},"fresh":true}"#,
+            r#"{"reason":"compiler-message","target":{"name":"demo"},"message":{"message":"unused variable: `x`","level":"warning","code":{"code":"unused_variables"},"spans":[],"rendered":"warning: unused variable"}}"#,
+            r#"{"reason":"compiler-message","target":{"name":"demo"},"message":{"message":"mismatched types","level":"error","code":null,"spans":[]}}"#,
+            r#"{"reason":"compiler-artifact","target":{"name":"serde"},"fresh":true}"#,
+            r#"{"reason":"build-finished","success":false}"#,
+        ];
+        for line in lines {
+            summary.absorb(parse_line(line));
+        }
+
+        assert_eq!((summary.error_count, summary.warning_count), (1, 1));
+        assert_eq!(summary.artifacts, ["serde"]);
+        assert!(!summary.success);
+        assert_eq!(
+            summary.diagnostics[0].code.as_deref(),
+            Some("unused_variables")
+        );
+        assert_eq!(
+            BuildCrateCommand::cargo_args(Some("forge-ide"), true),
+            [
+                "build",
+                "--message-format=json",
+                "-p",
+                "forge-ide",
+                "--release"
+            ]
+        );
+    }
+}
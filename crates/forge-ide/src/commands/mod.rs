@@ -0,0 +1,32 @@
+// ============================================================================
+// ⚙️  Forge IDE - Built-in Commands
+// Description:
+//   Concrete `ForgeIdeCommand` providers shipped with forge-ide and a helper
+//   that registers them on a `ForgeProviderRegistry`.
+// ============================================================================
+
+mod build_crate;
+mod run_tests;
+
+pub use build_crate::{BuildCrateCommand, BuildSummary};
+pub use run_tests::{RunTestsCommand, TestRunSummary};
+
+use crate::command::ForgeCommandKind;
+use crate::provider::ForgeProviderRegistry;
+use crate::terminal::TerminalSlice;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Registry with every built-in command bound to `root`, streaming to `terminal`.
+pub fn default_registry(root: &Path, terminal: &TerminalSlice) -> ForgeProviderRegistry {
+    let mut registry = ForgeProviderRegistry::new();
+    registry.register(
+        ForgeCommandKind::Build,
+        Arc::new(BuildCrateCommand::new(root, terminal.clone())),
+    );
+    registry.register(
+        ForgeCommandKind::Test,
+        Arc::new(RunTestsCommand::new(root, terminal.clone())),
+    );
+    registry
+}
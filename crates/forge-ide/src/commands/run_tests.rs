@@ -0,0 +1,289 @@
+// ============================================================================
+// ⚙️  Forge IDE - RunTests Command
+// Description:
+//   Handles `ForgeRequest::RunTests`: runs `cargo test` for a crate / filter,
+//   streams output to the terminal slice and returns per-test records as
+//   `TestRunSummary` data. Failures are remembered per crate so a follow-up
+//   request with `failed_only` re-runs exactly those tests.
+// ============================================================================
+
+use crate::cargo::{
+    parse_line, run_cargo_streaming, CargoMessage, Diagnostic, DiagnosticLevel, LibtestParser,
+    StdoutChunk, TestCase, TestStatus,
+};
+use crate::command::ForgeIdeCommand;
+use crate::schema::{ForgeRequest, ForgeResponse};
+use crate::terminal::{TerminalSlice, TerminalStream};
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Instant;
+
+// ------------------------------------------------------------------------
+// SUMMARY
+// ------------------------------------------------------------------------
+
+/// Result of one `cargo test` run
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TestRunSummary {
+    pub crate_name: Option<String>,
+    pub filter: Option<String>,
+    pub failed_only: bool,
+    pub timed: bool,
+    pub success: bool,
+    pub passed: usize,
+    pub failed: usize,
+    pub ignored: usize,
+    pub duration_ms: u64,
+    /// Compile errors that kept the tests from running
+    pub build_errors: Vec<Diagnostic>,
+    pub tests: Vec<TestCase>,
+}
+
+impl TestRunSummary {
+    fn count(&mut self) {
+        let by = |s| self.tests.iter().filter(|t| t.status == s).count();
+        (self.passed, self.failed, self.ignored) = (
+            by(TestStatus::Passed),
+            by(TestStatus::Failed),
+            by(TestStatus::Ignored),
+        );
+    }
+
+    /// One-line human summary used as the response message.
+    pub fn headline(&self) -> String {
+        let what = self.crate_name.as_deref().unwrap_or("workspace");
+        if !self.build_errors.is_empty() {
+            return format!(
+                "Tests for {what} did not compile: {} error(s)",
+                self.build_errors.len()
+            );
+        }
+        format!(
+            "Tests for {what}: {} passed, {} failed, {} ignored in {} ms",
+            self.passed, self.failed, self.ignored, self.duration_ms
+        )
+    }
+}
+
+// ------------------------------------------------------------------------
+// COMMAND
+// ------------------------------------------------------------------------
+
+/// `RunTests` provider bound to a workspace root
+pub struct RunTestsCommand {
+    root: PathBuf,
+    terminal: TerminalSlice,
+    /// Names of currently failing tests, keyed by crate (`None` = workspace)
+    failing: Mutex<HashMap<Option<String>, BTreeSet<String>>>,
+}
+
+impl RunTestsCommand {
+    pub fn new(root: impl Into<PathBuf>, terminal: TerminalSlice) -> Self {
+        Self {
+            root: root.into(),
+            terminal,
+            failing: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Tests that failed last time for `crate_name`, narrowed by `filter`.
+    pub fn failing_tests(&self, crate_name: Option<&str>, filter: Option<&str>) -> Vec<String> {
+        let failing = self.failing.lock().expect("failing set poisoned");
+        failing
+            .get(&crate_name.map(str::to_string))
+            .into_iter()
+            .flatten()
+            .filter(|name| filter.is_none_or(|f| name.contains(f)))
+            .cloned()
+            .collect()
+    }
+
+    /// Cargo arguments for a run; `exact` names are matched with `--exact`.
+    /// `timed` runs tests one at a time so libtest prints each start line
+    /// before the test runs, which is what per-test durations are measured
+    /// from; parallel runs leave `duration_ms` unset.
+    pub fn cargo_args(
+        crate_name: Option<&str>,
+        filter: Option<&str>,
+        exact: &[String],
+        timed: bool,
+    ) -> Vec<String> {
+        let mut args = vec!["test".to_string(), "--message-format=json".to_string()];
+        if let Some(name) = crate_name {
+            args.extend(["-p".to_string(), name.to_string()]);
+        }
+        args.push("--".to_string());
+        if timed {
+            args.push("--test-threads=1".to_string());
+        }
+        if exact.is_empty() {
+            args.extend(filter.map(str::to_string));
+        } else {
+            args.extend(exact.iter().cloned());
+            args.push("--exact".to_string());
+        }
+        args
+    }
+
+    /// Run the tests synchronously and collect per-test results.
+    pub fn run(
+        &self,
+        crate_name: Option<&str>,
+        filter: Option<&str>,
+        failed_only: bool,
+        timed: bool,
+    ) -> Result<TestRunSummary> {
+        let mut summary = TestRunSummary {
+            crate_name: crate_name.map(str::to_string),
+            filter: filter.map(str::to_string),
+            failed_only,
+            timed,
+            ..Default::default()
+        };
+
+        let exact = if failed_only {
+            let names = self.failing_tests(crate_name, filter);
+            if names.is_empty() {
+                summary.success = true;
+                self.terminal.info("No failed tests to re-run");
+                return Ok(summary);
+            }
+            names
+        } else {
+            Vec::new()
+        };
+
+        let started = Instant::now();
+        let terminal = &self.terminal;
+        let mut parser = LibtestParser::new();
+        let args = Self::cargo_args(crate_name, filter, &exact, timed);
+
+        let status = run_cargo_streaming(&self.root, &args, terminal, |chunk| {
+            let line = match chunk {
+                StdoutChunk::Partial(text) => return parser.feed_partial(text, Instant::now()),
+                StdoutChunk::Line(line) => line,
+            };
+            match parse_line(line) {
+                CargoMessage::Compiler(d) if d.level == DiagnosticLevel::Error => {
+                    if let Some(rendered) = &d.rendered {
+                        for l in rendered.lines() {
+                            terminal.push(TerminalStream::Stdout, l);
+                        }
+                    }
+                    summary.build_errors.push(d);
+                }
+                CargoMessage::Text(text) => {
+                    terminal.push(TerminalStream::Stdout, text.clone());
+                    parser.feed(&text);
+                }
+                _ => {}
+            }
+        })?;
+
+        summary.tests = parser.finish();
+        summary.count();
+        summary.success = status.success() && summary.failed == 0;
+        summary.duration_ms = started.elapsed().as_millis() as u64;
+        self.remember(crate_name, &summary.tests);
+        terminal.info(summary.headline());
+        Ok(summary)
+    }
+
+    /// Update the failing set with the outcome of every test that just ran.
+    fn remember(&self, crate_name: Option<&str>, tests: &[TestCase]) {
+        let mut failing = self.failing.lock().expect("failing set poisoned");
+        let set = failing.entry(crate_name.map(str::to_string)).or_default();
+        for case in tests {
+            match case.status {
+                TestStatus::Failed => set.insert(case.name.clone()),
+                _ => set.remove(&case.name),
+            };
+        }
+    }
+}
+
+#[async_trait]
+impl ForgeIdeCommand for RunTestsCommand {
+    async fn execute(&self, request: &ForgeRequest) -> ForgeResponse {
+        let ForgeRequest::RunTests {
+            crate_name,
+            filter,
+            failed_only,
+            timed,
+        } = request
+        else {
+            return ForgeResponse::error("RunTests provider received a different request");
+        };
+
+        match self.run(crate_name.as_deref(), filter.as_deref(), *failed_only, *timed) {
+            Ok(summary) if summary.success => {
+                ForgeResponse::with_data(summary.headline(), &summary)
+            }
+            Ok(summary) => ForgeResponse::error_with_data(summary.headline(), &summary),
+            Err(e) => ForgeResponse::error(format!("cargo test failed to run: {e:#}")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn case(name: &str, status: TestStatus) -> TestCase {
+        TestCase {
+            name: name.into(),
+            module: String::new(),
+            status,
+            duration_ms: None,
+            stdout: None,
+            location: None,
+        }
+    }
+
+    #[test]
+    fn failures_are_remembered_until_they_pass() {
+        let cmd = RunTestsCommand::new("/ws", TerminalSlice::default());
+        cmd.remember(
+            Some("forge-ide"),
+            &[
+                case("a::one", TestStatus::Failed),
+                case("b::two", TestStatus::Failed),
+                case("c::three", TestStatus::Passed),
+            ],
+        );
+        assert_eq!(
+            cmd.failing_tests(Some("forge-ide"), None),
+            ["a::one", "b::two"]
+        );
+        assert_eq!(
+            cmd.failing_tests(Some("forge-ide"), Some("b::")),
+            ["b::two"]
+        );
+        assert!(cmd.failing_tests(None, None).is_empty());
+
+        cmd.remember(Some("forge-ide"), &[case("a::one", TestStatus::Passed)]);
+        assert_eq!(cmd.failing_tests(Some("forge-ide"), None), ["b::two"]);
+
+        assert_eq!(
+            RunTestsCommand::cargo_args(Some("forge-ide"), None, &["b::two".into()], false),
+            [
+                "test",
+                "--message-format=json",
+                "-p",
+                "forge-ide",
+                "--",
+                "b::two",
+                "--exact"
+            ]
+        );
+        assert_eq!(
+            RunTestsCommand::cargo_args(None, Some("tree::"), &[], true),
+            ["test", "--message-format=json", "--", "--test-threads=1", "tree::"]
+        );
+    }
+}
@@ -0,0 +1,304 @@
+// ============================================================================
+// ⚙️  Forge IDE - Module Generation
+// Description:
+//   Self-hosting generator: reads `templates/forge_ide/core.yaml`, renders
+//   every generated module through the forge-template library, formats it
+//   with rustfmt and splices the `mod_root.mustache` declarations into the
+//   crate root. Replaces `scripts/gen_forge_modules.zsh`.
+// ============================================================================
+
+use crate::manifest::{CoreManifest, ManifestModule};
+use crate::workspace::ForgeWorkspace;
+use anyhow::{bail, Context, Result};
+use serde::Serialize;
+use serde_json::json;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// Crate manifest, relative to `templates/`
+pub const CORE_MANIFEST: &str = "forge_ide/core.yaml";
+/// Module declarations template, relative to `templates/`
+pub const MOD_ROOT_TEMPLATE: &str = "forge_ide/mod_root.mustache";
+
+const MOD_ROOT_BEGIN: &str = "// @forge:begin mod_root";
+const MOD_ROOT_END: &str = "// @forge:end mod_root";
+
+/// One generated module of the manifest
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleJob {
+    pub name: String,
+    pub kind: &'static str,
+    pub template: PathBuf,
+    pub output: PathBuf,
+    #[serde(skip)]
+    module: ManifestModule,
+}
+
+/// Rendered module source
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RenderedModule {
+    pub job: ModuleJob,
+    pub code: String,
+    /// False if rustfmt was unavailable or rejected the output
+    pub formatted: bool,
+}
+
+/// What writing a generated file did
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum FileChange {
+    Created,
+    Updated,
+    Unchanged,
+}
+
+/// One file touched by `build`
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct GeneratedFile {
+    pub path: PathBuf,
+    pub change: FileChange,
+    pub formatted: bool,
+}
+
+/// Result of regenerating the crate from its manifest
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BuildReport {
+    pub manifest: PathBuf,
+    pub files: Vec<GeneratedFile>,
+}
+
+impl BuildReport {
+    /// Files that were created or rewritten.
+    pub fn changed(&self) -> impl Iterator<Item = &GeneratedFile> {
+        self.files
+            .iter()
+            .filter(|f| f.change != FileChange::Unchanged)
+    }
+}
+
+impl ForgeWorkspace {
+    /// `templates/forge_ide/core.yaml`
+    pub fn core_manifest_path(&self) -> PathBuf {
+        self.template(CORE_MANIFEST)
+    }
+
+    /// Parsed `core.yaml`.
+    pub fn core_manifest(&self) -> Result<CoreManifest> {
+        CoreManifest::load(&self.core_manifest_path())
+    }
+
+    /// Generated modules of `manifest` (active groups, modules with an `is*` kind).
+    pub fn module_jobs(&self, manifest: &CoreManifest) -> Vec<ModuleJob> {
+        let src = self.crate_dir(&manifest.payload.crate_name).join("src");
+        manifest
+            .active_modules()
+            .filter_map(|(_, module)| {
+                let kind = module.kind()?;
+                Some(ModuleJob {
+                    name: module.name.clone(),
+                    kind,
+                    template: self.template(&format!("forge_ide/{kind}.mustache")),
+                    output: src.join(format!("{}.rs", module.name)),
+                    module: module.clone(),
+                })
+            })
+            .collect()
+    }
+
+    /// Job for the generated module called `name`.
+    pub fn module_job(&self, manifest: &CoreManifest, name: &str) -> Result<ModuleJob> {
+        let jobs = self.module_jobs(manifest);
+        let known: Vec<&str> = jobs.iter().map(|j| j.name.as_str()).collect();
+        match jobs.iter().find(|j| j.name == name) {
+            Some(job) => Ok(job.clone()),
+            None => bail!(
+                "no generated module '{name}' in {CORE_MANIFEST} (known: {})",
+                known.join(", ")
+            ),
+        }
+    }
+
+    /// Render one module with its `header` / `payload` context.
+    pub fn render_module(&self, job: &ModuleJob) -> Result<RenderedModule> {
+        if !job.template.is_file() {
+            bail!(
+                "missing template for module {}: {}",
+                job.name,
+                job.template.display()
+            );
+        }
+        let ctx = json!({
+            "header": {
+                "file": format!("templates/{CORE_MANIFEST}"),
+                "template": format!("templates/forge_ide/{}.mustache", job.kind),
+                "type": format!("{}_module", job.kind),
+                "ast": forge_template::ast::RUST_AST,
+            },
+            "payload": job.module,
+        });
+        let raw = forge_template::render_value_from_abs(&ctx, &job.template)
+            .with_context(|| format!("render module {}", job.name))?;
+        let (code, formatted) = match rustfmt(&raw) {
+            Ok(code) => (code, true),
+            Err(_) => (raw, false),
+        };
+        Ok(RenderedModule {
+            job: job.clone(),
+            code,
+            formatted,
+        })
+    }
+
+    /// `mod_root.mustache` output: the declarations of every active module.
+    pub fn render_mod_root(&self, manifest: &CoreManifest) -> Result<String> {
+        let ctx = serde_json::to_value(manifest)?;
+        let raw = forge_template::render_value_from_abs(&ctx, self.template(MOD_ROOT_TEMPLATE))
+            .context("render mod_root")?;
+        Ok(raw
+            .lines()
+            .map(str::trim_end)
+            .filter(|l| !l.is_empty())
+            .map(|l| format!("{l}\n"))
+            .collect())
+    }
+
+    /// Regenerate every module of `core.yaml` and the crate-root declarations.
+    /// Files are only rewritten when their content changes.
+    pub fn build_modules(&self) -> Result<BuildReport> {
+        let manifest_path = self.core_manifest_path();
+        let manifest = CoreManifest::load(&manifest_path)?;
+        let mut files = Vec::new();
+
+        for job in self.module_jobs(&manifest) {
+            let rendered = self.render_module(&job)?;
+            let change = write_if_changed(&job.output, &rendered.code)?;
+            files.push(GeneratedFile {
+                path: job.output,
+                change,
+                formatted: rendered.formatted,
+            });
+        }
+
+        let root_file = self
+            .crate_dir(&manifest.payload.crate_name)
+            .join("src/lib.rs");
+        let current = fs::read_to_string(&root_file)
+            .with_context(|| format!("read {}", root_file.display()))?;
+        let block = self.render_mod_root(&manifest)?;
+        let spliced = splice_mod_root(&current, &block)
+            .with_context(|| format!("update {}", root_file.display()))?;
+        files.push(GeneratedFile {
+            change: write_if_changed(&root_file, &spliced)?,
+            path: root_file,
+            formatted: true,
+        });
+
+        Ok(BuildReport {
+            manifest: manifest_path,
+            files,
+        })
+    }
+}
+
+/// Replace the lines between the `mod_root` markers of `source` with `block`.
+pub fn splice_mod_root(source: &str, block: &str) -> Result<String> {
+    let begin = source
+        .find(MOD_ROOT_BEGIN)
+        .with_context(|| format!("missing `{MOD_ROOT_BEGIN}` marker"))?;
+    let body = begin
+        + source[begin..]
+            .find('\n')
+            .map_or(source.len() - begin, |i| i + 1);
+    let end = source[body..]
+        .find(MOD_ROOT_END)
+        .map(|i| body + i)
+        .with_context(|| format!("missing `{MOD_ROOT_END}` marker"))?;
+    Ok(format!("{}{block}{}", &source[..body], &source[end..]))
+}
+
+pub(crate) fn write_if_changed(path: &Path, content: &str) -> Result<FileChange> {
+    let change = match fs::read_to_string(path) {
+        Ok(current) if current == content => return Ok(FileChange::Unchanged),
+        Ok(_) => FileChange::Updated,
+        Err(_) => FileChange::Created,
+    };
+    fs::write(path, content).with_context(|| format!("write {}", path.display()))?;
+    Ok(change)
+}
+
+/// Format Rust source with `rustfmt --edition 2021`.
+pub fn rustfmt(code: &str) -> Result<String> {
+    let mut child = Command::new("rustfmt")
+        .args(["--edition", "2021", "--emit", "stdout", "--quiet"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("spawn rustfmt")?;
+    child
+        .stdin
+        .take()
+        .expect("stdin is piped")
+        .write_all(code.as_bytes())
+        .context("write to rustfmt")?;
+    let out = child.wait_with_output().context("wait for rustfmt")?;
+    if !out.status.success() {
+        bail!(
+            "rustfmt failed: {}",
+            String::from_utf8_lossy(&out.stderr).trim()
+        );
+    }
+    String::from_utf8(out.stdout).context("rustfmt output is not UTF-8")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn repo() -> ForgeWorkspace {
+        ForgeWorkspace::new(Path::new(env!("CARGO_MANIFEST_DIR")).join("../.."))
+    }
+
+    #[test]
+    fn splice_replaces_only_the_marked_block() {
+        let src = "//! crate\n\n// @forge:begin mod_root (generated)\npub mod old;\n// @forge:end mod_root\npub mod manual;\n";
+        let out = splice_mod_root(src, "pub mod a;\npub mod b;\n").unwrap();
+        assert_eq!(
+            out,
+            "//! crate\n\n// @forge:begin mod_root (generated)\npub mod a;\npub mod b;\n// @forge:end mod_root\npub mod manual;\n"
+        );
+        assert_eq!(
+            splice_mod_root(&out, "pub mod a;\npub mod b;\n").unwrap(),
+            out
+        );
+        assert!(splice_mod_root("pub mod x;\n", "").is_err());
+    }
+
+    #[test]
+    fn generated_modules_match_core_yaml() {
+        let ws = repo();
+        let manifest = ws.core_manifest().unwrap();
+        for job in ws.module_jobs(&manifest) {
+            let rendered = ws.render_module(&job).unwrap();
+            if !rendered.formatted {
+                continue; // no rustfmt in this environment
+            }
+            let on_disk = fs::read_to_string(&job.output).unwrap();
+            assert!(
+                on_disk == rendered.code,
+                "{} is stale; run `forge-ide build`",
+                job.output.display()
+            );
+        }
+
+        let lib = fs::read_to_string(ws.crate_dir("forge-ide").join("src/lib.rs")).unwrap();
+        let block = ws.render_mod_root(&manifest).unwrap();
+        assert_eq!(splice_mod_root(&lib, &block).unwrap(), lib);
+    }
+}
@@ -0,0 +1,29 @@
+//! forge-ide
+//!
+//! Backend library for the Forge IDE workbench.
+//! Provides the request/response schema, the command registry and router,
+//! the live workspace tree that is pushed to connected clients, and the
+//! built-in cargo-backed commands, crate scaffolding and the `forge-ide`
+//! command line.
+
+// @forge:begin mod_root (generated from templates/forge_ide/core.yaml by `forge-ide build`)
+// core: Core entrypoint for the Forge IDE backend
+pub mod schema;
+pub mod command;
+pub mod provider;
+pub mod router;
+pub use schema::*;
+pub use command::*;
+pub use provider::*;
+pub use router::*;
+// @forge:end mod_root
+pub mod cargo;
+pub mod cli;
+pub mod commands;
+pub mod generate;
+pub mod manifest;
+pub mod report;
+pub mod terminal;
+pub mod tree;
+pub mod watch;
+pub mod workspace;
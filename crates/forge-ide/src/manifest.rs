@@ -0,0 +1,122 @@
+// ============================================================================
+// ⚙️  Forge IDE - Crate Manifest (core.yaml)
+// Description:
+//   Typed view of `templates/forge_ide/core.yaml`, the manifest describing
+//   every module of the forge-ide crate. Only the fields the CLI needs are
+//   modelled; everything else (the `ast` bodies) is kept verbatim in `extra`
+//   and handed to the module templates.
+// ============================================================================
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// Root of `core.yaml`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CoreManifest {
+    #[serde(default)]
+    pub header: ManifestHeader,
+    pub payload: ManifestPayload,
+}
+
+/// `header:` section
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct ManifestHeader {
+    pub description: Option<String>,
+    pub version: Option<f64>,
+    pub kind: Option<String>,
+    pub namespace: Option<String>,
+}
+
+/// `payload:` section
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ManifestPayload {
+    #[serde(rename = "crate")]
+    pub crate_name: String,
+    #[serde(default)]
+    pub mods: Vec<ManifestMod>,
+}
+
+/// A group of modules (`mods[]`)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ManifestMod {
+    pub name: String,
+    pub desc: Option<String>,
+    #[serde(default)]
+    pub active: bool,
+    #[serde(default)]
+    pub modules: Vec<ManifestModule>,
+}
+
+/// One Rust module (`mods[].modules[]`)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestModule {
+    pub name: String,
+    pub desc: Option<String>,
+    #[serde(rename = "pub", default)]
+    pub is_pub: bool,
+    #[serde(rename = "use", default)]
+    pub is_use: bool,
+    #[serde(default)]
+    pub is_schema: bool,
+    #[serde(default)]
+    pub is_command: bool,
+    #[serde(default)]
+    pub is_provider: bool,
+    #[serde(default)]
+    pub is_router: bool,
+    /// Remaining keys (`ast`, ...) passed through to the templates
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl ManifestModule {
+    /// Generator family selected by the `is*` flags; `None` for hand-written modules.
+    pub fn kind(&self) -> Option<&'static str> {
+        [
+            (self.is_schema, "schema"),
+            (self.is_command, "command"),
+            (self.is_provider, "provider"),
+            (self.is_router, "router"),
+        ]
+        .into_iter()
+        .find_map(|(flag, kind)| flag.then_some(kind))
+    }
+}
+
+impl CoreManifest {
+    /// Parse `core.yaml` at `path`.
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path).with_context(|| format!("read {}", path.display()))?;
+        serde_yaml::from_str(&text).with_context(|| format!("parse {}", path.display()))
+    }
+
+    /// Modules of every active group, in manifest order.
+    pub fn active_modules(&self) -> impl Iterator<Item = (&ManifestMod, &ManifestModule)> {
+        self.payload
+            .mods
+            .iter()
+            .filter(|m| m.active)
+            .flat_map(|m| m.modules.iter().map(move |module| (m, module)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repo_core_yaml_parses() {
+        let path =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("../../templates/forge_ide/core.yaml");
+        let manifest = CoreManifest::load(&path).unwrap();
+        assert_eq!(manifest.payload.crate_name, "forge-ide");
+        let kinds: Vec<_> = manifest
+            .active_modules()
+            .filter_map(|(_, m)| m.kind())
+            .collect();
+        assert_eq!(kinds, ["schema", "command", "provider", "router"]);
+    }
+}
@@ -1,7 +1,7 @@
 // ============================================================================
 // ⚙️  Auto-generated Provider Module
 // Description : Forge provider registry — manages and dispatches ForgeIdeCommand instances
-// Source      : templates/forge_ide/generated/provider.yaml
+// Source      : templates/forge_ide/core.yaml
 // Template    : templates/forge_ide/provider.mustache
 // ============================================================================
 
@@ -23,7 +23,6 @@ impl ForgeProviderRegistry {
     // ----------------------------------------------------------------
     // METHOD: new
     // ----------------------------------------------------------------
-
     pub fn new() -> Self {
         Self {
             providers: HashMap::new(),
@@ -33,8 +32,7 @@ impl ForgeProviderRegistry {
     // ----------------------------------------------------------------
     // METHOD: register
     // ----------------------------------------------------------------
-
-    pub fn register(&mut self, kind: ForgeCommandKind, command: Arc<dyn ForgeIdeCommand>) -> () {
+    pub fn register(&mut self, kind: ForgeCommandKind, command: Arc<dyn ForgeIdeCommand>) {
         self.providers.insert(kind, command);
     }
 
@@ -44,7 +42,8 @@ impl ForgeProviderRegistry {
     pub async fn dispatch(&self, request: &ForgeRequest) -> ForgeResponse {
         match request {
             ForgeRequest::RenderManifest => self.dispatch_render(request).await,
-            ForgeRequest::BuildCrate => self.dispatch_build(request).await,
+            ForgeRequest::BuildCrate { .. } => self.dispatch_build(request).await,
+            ForgeRequest::RunTests { .. } => self.dispatch_test(request).await,
             ForgeRequest::GetEnv => self.dispatch_env(request).await,
             ForgeRequest::Custom => self.dispatch_custom(request).await,
         }
@@ -53,7 +52,6 @@ impl ForgeProviderRegistry {
     // ----------------------------------------------------------------
     // METHOD: list_registered
     // ----------------------------------------------------------------
-
     pub fn list_registered(&self) -> Vec<&'static str> {
         self.providers
             .values()
@@ -63,22 +61,41 @@ impl ForgeProviderRegistry {
 }
 
 // ------------------------------------------------------------------------
-// DISPATCH HANDLERS (auto-generated stubs)
+// DISPATCH HANDLERS (forward to the registered command)
 // ------------------------------------------------------------------------
 impl ForgeProviderRegistry {
-    pub async fn dispatch_render(&self, _request: &ForgeRequest) -> ForgeResponse {
-        todo!("Handle Render request variant");
+    pub async fn dispatch_render(&self, request: &ForgeRequest) -> ForgeResponse {
+        match self.providers.get(&ForgeCommandKind::Render) {
+            Some(command) => command.execute(request).await,
+            None => ForgeResponse::error("No provider registered for Render"),
+        }
+    }
+
+    pub async fn dispatch_build(&self, request: &ForgeRequest) -> ForgeResponse {
+        match self.providers.get(&ForgeCommandKind::Build) {
+            Some(command) => command.execute(request).await,
+            None => ForgeResponse::error("No provider registered for Build"),
+        }
     }
 
-    pub async fn dispatch_build(&self, _request: &ForgeRequest) -> ForgeResponse {
-        todo!("Handle Build request variant");
+    pub async fn dispatch_test(&self, request: &ForgeRequest) -> ForgeResponse {
+        match self.providers.get(&ForgeCommandKind::Test) {
+            Some(command) => command.execute(request).await,
+            None => ForgeResponse::error("No provider registered for Test"),
+        }
     }
 
-    pub async fn dispatch_env(&self, _request: &ForgeRequest) -> ForgeResponse {
-        todo!("Handle Env request variant");
+    pub async fn dispatch_env(&self, request: &ForgeRequest) -> ForgeResponse {
+        match self.providers.get(&ForgeCommandKind::Env) {
+            Some(command) => command.execute(request).await,
+            None => ForgeResponse::error("No provider registered for Env"),
+        }
     }
 
-    pub async fn dispatch_custom(&self, _request: &ForgeRequest) -> ForgeResponse {
-        todo!("Handle Custom request variant");
+    pub async fn dispatch_custom(&self, request: &ForgeRequest) -> ForgeResponse {
+        match self.providers.get(&ForgeCommandKind::Custom) {
+            Some(command) => command.execute(request).await,
+            None => ForgeResponse::error("No provider registered for Custom"),
+        }
     }
 }
@@ -0,0 +1,81 @@
+// ============================================================================
+// ⚙️  Forge IDE - Report Writer
+// Description:
+//   Writes command results (build / test summaries) to disk as JSON or YAML,
+//   replacing the hand-assembled `--report json|yaml` heredocs of the zsh CLI.
+// ============================================================================
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// Supported on-disk report formats
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ReportFormat {
+    Json,
+    Yaml,
+}
+
+impl ReportFormat {
+    /// Pick the format from a file extension (`.json`, `.yaml`, `.yml`).
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "json" => Some(Self::Json),
+            "yaml" | "yml" => Some(Self::Yaml),
+            _ => None,
+        }
+    }
+
+    /// Serialize `data` in this format.
+    pub fn to_string<T: Serialize>(self, data: &T) -> Result<String> {
+        Ok(match self {
+            Self::Json => serde_json::to_string_pretty(data)? + "\n",
+            Self::Yaml => serde_yaml::to_string(data)?,
+        })
+    }
+}
+
+/// Write `data` to `path`, creating parent directories. The format follows
+/// the file extension.
+pub fn write_report<T: Serialize>(data: &T, path: &Path) -> Result<ReportFormat> {
+    let Some(format) = ReportFormat::from_path(path) else {
+        bail!(
+            "unknown report format for {} (use .json, .yaml or .yml)",
+            path.display()
+        );
+    };
+    if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+        fs::create_dir_all(dir).with_context(|| format!("create {}", dir.display()))?;
+    }
+    fs::write(path, format.to_string(data)?)
+        .with_context(|| format!("write report {}", path.display()))?;
+    Ok(format)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn format_follows_extension() {
+        let dir = tempfile::tempdir().unwrap();
+        let data = json!({ "crate": "forge-ide", "success": true });
+
+        let yaml = dir.path().join("reports/build.yml");
+        assert_eq!(write_report(&data, &yaml).unwrap(), ReportFormat::Yaml);
+        assert!(fs::read_to_string(&yaml)
+            .unwrap()
+            .contains("crate: forge-ide"));
+
+        let json_path = dir.path().join("build.json");
+        write_report(&data, &json_path).unwrap();
+        let back: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&json_path).unwrap()).unwrap();
+        assert_eq!(back, data);
+
+        assert!(write_report(&data, &dir.path().join("build.txt")).is_err());
+    }
+}
@@ -1,7 +1,7 @@
 // ============================================================================
 // ⚙️  Auto-generated Router Module
 // Description : ForgeRouter — entrypoint for dispatching requests via ForgeProviderRegistry
-// Source      : templates/forge_ide/generated/router.yaml
+// Source      : templates/forge_ide/core.yaml
 // Template    : templates/forge_ide/router.mustache
 // ============================================================================
 
@@ -22,9 +22,8 @@ impl ForgeRouter {
     // ----------------------------------------------------------------
     // METHOD: new
     // ----------------------------------------------------------------
-
     pub fn new(registry: Arc<ForgeProviderRegistry>) -> Self {
-        Self { registry: registry }
+        Self { registry }
     }
 
     // ----------------------------------------------------------------
@@ -37,7 +36,6 @@ impl ForgeRouter {
     // ----------------------------------------------------------------
     // METHOD: info
     // ----------------------------------------------------------------
-
     pub fn info(&self) -> Vec<&'static str> {
         self.registry.list_registered()
     }
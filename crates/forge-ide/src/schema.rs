@@ -1,7 +1,7 @@
 // ============================================================================
 // ⚙️  Auto-generated Schema Module
 // Description: Data types: ForgeRequest / ForgeResponse
-// File: templates/forge_ide/modules/schema.yaml
+// File: templates/forge_ide/core.yaml
 // ============================================================================
 
 use serde::{Deserialize, Serialize};
@@ -11,13 +11,37 @@ use serde::{Deserialize, Serialize};
 // ------------------------------------------------------------------------
 
 /// Canonical request definition for Forge IDE backends
-
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub enum ForgeRequest {
     /// Render a manifest file
     RenderManifest,
     /// Build a crate (debug or release)
-    BuildCrate,
+    BuildCrate {
+        /// Package to build (`-p`); the whole workspace when absent
+        #[serde(default)]
+        crate_name: Option<String>,
+        /// Build with `--release`
+        #[serde(default)]
+        release: bool,
+        /// Optional report path; `.json`, `.yaml` or `.yml` picks the format
+        #[serde(default)]
+        report: Option<String>,
+    },
+    /// Run cargo tests and report per-test results
+    RunTests {
+        /// Package to test (`-p`); the whole workspace when absent
+        #[serde(default)]
+        crate_name: Option<String>,
+        /// libtest name filter
+        #[serde(default)]
+        filter: Option<String>,
+        /// Re-run only the tests that failed in the previous run
+        #[serde(default)]
+        failed_only: bool,
+        /// Run tests one at a time so each gets a duration on stable toolchains
+        #[serde(default)]
+        timed: bool,
+    },
     /// Return environment info
     GetEnv,
     /// Custom user command
@@ -25,15 +49,14 @@ pub enum ForgeRequest {
 }
 
 /// Canonical response envelope
-
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ForgeResponse {
-    /// Field: status
+    /// Outcome of the request
     pub status: ForgeStatus,
-    /// Field: message
+    /// Human-readable summary
     pub message: String,
-    /// Field: data
+    /// Command-specific payload
     pub data: Option<serde_json::Value>,
 }
 
@@ -64,10 +87,18 @@ impl ForgeResponse {
             data: Some(serde_json::to_value(data).unwrap_or_default()),
         }
     }
+
+    /// Construct an error response with attached data
+    pub fn error_with_data<T: Serialize>(msg: impl Into<String>, data: &T) -> Self {
+        Self {
+            status: ForgeStatus::Error,
+            message: msg.into(),
+            data: Some(serde_json::to_value(data).unwrap_or_default()),
+        }
+    }
 }
 
 /// Result status codes for ForgeResponse
-
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub enum ForgeStatus {
     /// Operation succeeded
@@ -0,0 +1,131 @@
+// ============================================================================
+// ⚙️  Forge IDE - Terminal Slice
+// Description:
+//   Server side of the workbench `vm_terminal` slice: a bounded scrollback of
+//   output lines plus live fan-out to subscribed clients. Long-running
+//   commands (cargo build / test) stream their progress here.
+// ============================================================================
+
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+
+/// Lines kept for clients that connect mid-run
+const DEFAULT_SCROLLBACK: usize = 2000;
+
+/// Origin of a terminal line
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum TerminalStream {
+    /// Child process stdout
+    Stdout,
+    /// Child process stderr (cargo progress lives here)
+    Stderr,
+    /// Messages emitted by forge-ide itself
+    System,
+}
+
+/// One line of terminal output
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TerminalLine {
+    pub stream: TerminalStream,
+    pub text: String,
+}
+
+#[derive(Debug)]
+struct TerminalInner {
+    capacity: usize,
+    lines: VecDeque<TerminalLine>,
+    clients: Vec<Sender<TerminalLine>>,
+}
+
+/// Shared handle to the terminal slice (cheap to clone)
+#[derive(Debug, Clone)]
+pub struct TerminalSlice {
+    inner: Arc<Mutex<TerminalInner>>,
+}
+
+impl Default for TerminalSlice {
+    fn default() -> Self {
+        Self::with_capacity(DEFAULT_SCROLLBACK)
+    }
+}
+
+impl TerminalSlice {
+    /// Terminal keeping at most `capacity` lines of scrollback.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(TerminalInner {
+                capacity,
+                lines: VecDeque::new(),
+                clients: Vec::new(),
+            })),
+        }
+    }
+
+    /// Append a line and forward it to every live client.
+    pub fn push(&self, stream: TerminalStream, text: impl Into<String>) {
+        let line = TerminalLine {
+            stream,
+            text: text.into(),
+        };
+        let mut inner = self.inner.lock().expect("terminal poisoned");
+        inner.clients.retain(|tx| tx.send(line.clone()).is_ok());
+        if inner.lines.len() == inner.capacity {
+            inner.lines.pop_front();
+        }
+        if inner.capacity > 0 {
+            inner.lines.push_back(line);
+        }
+    }
+
+    /// Shortcut for a `System` line.
+    pub fn info(&self, text: impl Into<String>) {
+        self.push(TerminalStream::System, text);
+    }
+
+    /// Current scrollback (initial payload for new clients).
+    pub fn lines(&self) -> Vec<TerminalLine> {
+        let inner = self.inner.lock().expect("terminal poisoned");
+        inner.lines.iter().cloned().collect()
+    }
+
+    /// Drop the scrollback (`ClearLogs` intent).
+    pub fn clear(&self) {
+        self.inner.lock().expect("terminal poisoned").lines.clear();
+    }
+
+    /// Receive every line pushed from now on; dropping the receiver unsubscribes.
+    pub fn subscribe(&self) -> Receiver<TerminalLine> {
+        let (tx, rx) = mpsc::channel();
+        self.inner
+            .lock()
+            .expect("terminal poisoned")
+            .clients
+            .push(tx);
+        rx
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scrollback_is_bounded_and_streamed() {
+        let term = TerminalSlice::with_capacity(2);
+        let rx = term.subscribe();
+        term.info("one");
+        term.push(TerminalStream::Stderr, "two");
+        term.push(TerminalStream::Stdout, "three");
+
+        let texts: Vec<_> = term.lines().into_iter().map(|l| l.text).collect();
+        assert_eq!(texts, ["two", "three"]);
+        assert_eq!(rx.try_iter().count(), 3);
+
+        term.clear();
+        assert!(term.lines().is_empty());
+    }
+}
@@ -0,0 +1,463 @@
+// ============================================================================
+// ⚙️  Forge IDE - Workspace Tree Model
+// Description:
+//   Server-owned file tree (names, structure, sizes, mtimes) plus the UI
+//   state that rides along with it (expanded directories, selection).
+//   Paths are repo-relative, `/`-separated, and the root node has path "".
+// ============================================================================
+
+use crate::watch::IgnoreRules;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+// ------------------------------------------------------------------------
+// NODES
+// ------------------------------------------------------------------------
+
+/// Lightweight file metadata shown next to tree rows
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct TreeMeta {
+    /// Size in bytes (files only)
+    pub size: Option<u64>,
+    /// Last modification time in epoch seconds (files only)
+    pub mtime: Option<u64>,
+}
+
+impl TreeMeta {
+    /// Read metadata for a file system entry. Directories carry no metadata.
+    pub fn from_fs(md: &fs::Metadata) -> Self {
+        if md.is_dir() {
+            return Self::default();
+        }
+        let mtime = md
+            .modified()
+            .ok()
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .map(|d| d.as_secs());
+        Self {
+            size: Some(md.len()),
+            mtime,
+        }
+    }
+}
+
+/// A single file or directory in the workspace tree
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TreeNode {
+    /// Repo-relative canonical path (stable id)
+    pub path: String,
+    /// Last path segment
+    pub name: String,
+    /// True for directories
+    pub is_dir: bool,
+    /// True if a directory has at least one visible child
+    pub has_children: bool,
+    /// Size / mtime for files
+    pub meta: TreeMeta,
+    /// Children, sorted directories first then case-insensitive by name
+    pub children: Vec<TreeNode>,
+}
+
+impl TreeNode {
+    /// Scan `abs` (which lives at repo-relative `rel`) into a node, recursing
+    /// into directories and skipping anything matched by `rules`.
+    pub fn scan(abs: &Path, rel: &str, rules: &IgnoreRules) -> io::Result<Self> {
+        let md = fs::metadata(abs)?;
+        let mut node = TreeNode {
+            path: rel.to_string(),
+            name: file_name_of(rel).to_string(),
+            is_dir: md.is_dir(),
+            has_children: false,
+            meta: TreeMeta::from_fs(&md),
+            children: Vec::new(),
+        };
+
+        if node.is_dir {
+            for entry in fs::read_dir(abs)? {
+                let entry = entry?;
+                let child_abs = entry.path();
+                let is_dir = entry.file_type().map(|t| t.is_dir()).unwrap_or(false);
+                if rules.is_ignored(&child_abs, is_dir) {
+                    continue;
+                }
+                let name = entry.file_name().to_string_lossy().into_owned();
+                let child_rel = join_rel(rel, &name);
+                // entries can vanish between read_dir and metadata; skip them
+                if let Ok(child) = TreeNode::scan(&child_abs, &child_rel, rules) {
+                    node.children.push(child);
+                }
+            }
+            sort_children(&mut node.children);
+            node.has_children = !node.children.is_empty();
+        }
+
+        Ok(node)
+    }
+
+    /// Find a node by repo-relative path.
+    pub fn find(&self, path: &str) -> Option<&TreeNode> {
+        if self.path == path {
+            return Some(self);
+        }
+        self.children
+            .iter()
+            .find(|c| is_same_or_ancestor(&c.path, path))
+            .and_then(|c| c.find(path))
+    }
+
+    /// Find a node by repo-relative path (mutable).
+    pub fn find_mut(&mut self, path: &str) -> Option<&mut TreeNode> {
+        if self.path == path {
+            return Some(self);
+        }
+        self.children
+            .iter_mut()
+            .find(|c| is_same_or_ancestor(&c.path, path))
+            .and_then(|c| c.find_mut(path))
+    }
+
+    /// Insert `node` under its parent directory, keeping sort order.
+    /// Returns `(parent_path, index)` or `None` if the parent is not in the tree.
+    pub fn insert(&mut self, node: TreeNode) -> Option<(String, usize)> {
+        let parent_path = parent_of(&node.path).to_string();
+        let parent = self.find_mut(&parent_path)?;
+        if !parent.is_dir {
+            return None;
+        }
+        parent.children.retain(|c| c.path != node.path);
+        let index = parent
+            .children
+            .iter()
+            .position(|c| child_order(c, &node).is_gt())
+            .unwrap_or(parent.children.len());
+        parent.children.insert(index, node);
+        parent.has_children = true;
+        Some((parent_path, index))
+    }
+
+    /// Remove and return the node at `path` (never the root).
+    pub fn remove(&mut self, path: &str) -> Option<TreeNode> {
+        let parent = self.find_mut(parent_of(path))?;
+        let pos = parent.children.iter().position(|c| c.path == path)?;
+        let removed = parent.children.remove(pos);
+        parent.has_children = !parent.children.is_empty();
+        Some(removed)
+    }
+
+    /// Re-root this node (and all descendants) from its current path onto `to`.
+    pub fn rebase(&mut self, to: &str) {
+        let from = std::mem::replace(&mut self.path, to.to_string());
+        self.name = file_name_of(to).to_string();
+        for child in &mut self.children {
+            let suffix = &child.path[from.len()..];
+            child.rebase(&format!("{to}{suffix}"));
+        }
+    }
+}
+
+// ------------------------------------------------------------------------
+// UI STATE
+// ------------------------------------------------------------------------
+
+/// UI-only state attached to tree paths (client prefs, mirrored server-side)
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct TreeState {
+    /// Paths of expanded directories
+    pub expanded: BTreeSet<String>,
+    /// Selected path, if any
+    pub selected: Option<String>,
+}
+
+impl TreeState {
+    /// Move every path at or below `from` onto `to` (keeps state attached on rename).
+    pub fn rebase(&mut self, from: &str, to: &str) {
+        let moved: Vec<String> = self
+            .expanded
+            .iter()
+            .filter(|p| is_same_or_ancestor(from, p))
+            .cloned()
+            .collect();
+        for p in moved {
+            self.expanded.remove(&p);
+            self.expanded.insert(format!("{to}{}", &p[from.len()..]));
+        }
+        if let Some(sel) = self.selected.as_mut() {
+            if is_same_or_ancestor(from, sel) {
+                *sel = format!("{to}{}", &sel[from.len()..]);
+            }
+        }
+    }
+
+    /// Drop every reference to `path` and its descendants.
+    pub fn forget(&mut self, path: &str) {
+        self.expanded.retain(|p| !is_same_or_ancestor(path, p));
+        if self
+            .selected
+            .as_deref()
+            .is_some_and(|sel| is_same_or_ancestor(path, sel))
+        {
+            self.selected = None;
+        }
+    }
+}
+
+// ------------------------------------------------------------------------
+// EVENTS
+// ------------------------------------------------------------------------
+
+/// Incremental tree change pushed to connected clients
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "event", rename_all = "camelCase")]
+pub enum TreeEvent {
+    /// A node (with its full subtree) appeared under `parent` at `index`
+    NodeInserted {
+        parent: String,
+        index: usize,
+        node: TreeNode,
+    },
+    /// A node (and its subtree) disappeared
+    NodeRemoved { path: String },
+    /// File size / mtime changed
+    MetadataChanged { path: String, meta: TreeMeta },
+    /// A node was renamed or moved; UI state follows it
+    NodeMoved {
+        from: String,
+        to: String,
+        parent: String,
+        index: usize,
+    },
+}
+
+/// Tree + UI state for one workspace root
+#[derive(Debug, Clone)]
+pub struct WorkspaceTree {
+    root: PathBuf,
+    pub nodes: TreeNode,
+    pub state: TreeState,
+}
+
+/// Serializable full view of the tree (initial payload for new clients)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TreeSnapshot {
+    pub root: TreeNode,
+    pub state: TreeState,
+}
+
+impl WorkspaceTree {
+    /// Scan the workspace rooted at `root`.
+    pub fn scan(root: impl Into<PathBuf>, rules: &IgnoreRules) -> io::Result<Self> {
+        let root = root.into();
+        let nodes = TreeNode::scan(&root, "", rules)?;
+        Ok(Self {
+            root,
+            nodes,
+            state: TreeState::default(),
+        })
+    }
+
+    /// Absolute workspace root.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Convert an absolute path into a repo-relative tree path.
+    pub fn rel_path(&self, abs: &Path) -> Option<String> {
+        let rel = abs.strip_prefix(&self.root).ok()?;
+        let parts: Vec<String> = rel
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+            .collect();
+        Some(parts.join("/"))
+    }
+
+    /// Absolute path for a repo-relative tree path.
+    pub fn abs_path(&self, rel: &str) -> PathBuf {
+        rel.split('/')
+            .filter(|s| !s.is_empty())
+            .fold(self.root.clone(), |acc, seg| acc.join(seg))
+    }
+
+    /// Full snapshot for newly connected clients.
+    pub fn snapshot(&self) -> TreeSnapshot {
+        TreeSnapshot {
+            root: self.nodes.clone(),
+            state: self.state.clone(),
+        }
+    }
+
+    /// Apply an event to the tree and keep UI state consistent.
+    pub fn apply(&mut self, event: &TreeEvent) {
+        match event {
+            TreeEvent::NodeInserted { node, .. } => {
+                self.nodes.insert(node.clone());
+            }
+            TreeEvent::NodeRemoved { path } => {
+                self.nodes.remove(path);
+                self.state.forget(path);
+            }
+            TreeEvent::MetadataChanged { path, meta } => {
+                if let Some(node) = self.nodes.find_mut(path) {
+                    node.meta = meta.clone();
+                }
+            }
+            TreeEvent::NodeMoved { from, to, .. } => {
+                self.move_node(from, to);
+            }
+        }
+    }
+
+    /// Move the node at `from` to `to`, carrying UI state along.
+    /// Returns the new `(parent_path, index)` or `None` if either end is invalid.
+    pub fn move_node(&mut self, from: &str, to: &str) -> Option<(String, usize)> {
+        let mut node = self.nodes.remove(from)?;
+        let original = node.clone();
+        node.rebase(to);
+        match self.nodes.insert(node) {
+            Some(landed) => {
+                self.state.rebase(from, to);
+                Some(landed)
+            }
+            None => {
+                // destination parent unknown: put the node back untouched
+                self.nodes.insert(original);
+                None
+            }
+        }
+    }
+}
+
+// ------------------------------------------------------------------------
+// PATH HELPERS
+// ------------------------------------------------------------------------
+
+/// Parent of a repo-relative path ("" for top-level entries).
+pub fn parent_of(path: &str) -> &str {
+    path.rsplit_once('/').map(|(p, _)| p).unwrap_or("")
+}
+
+/// Last segment of a repo-relative path.
+pub fn file_name_of(path: &str) -> &str {
+    path.rsplit_once('/').map(|(_, n)| n).unwrap_or(path)
+}
+
+/// Join a child name onto a repo-relative parent path.
+pub fn join_rel(parent: &str, name: &str) -> String {
+    if parent.is_empty() {
+        name.to_string()
+    } else {
+        format!("{parent}/{name}")
+    }
+}
+
+/// True if `ancestor` is `path` itself or one of its parent directories.
+fn is_same_or_ancestor(ancestor: &str, path: &str) -> bool {
+    ancestor.is_empty()
+        || path == ancestor
+        || (path.starts_with(ancestor) && path.as_bytes().get(ancestor.len()) == Some(&b'/'))
+}
+
+/// Directories first, then case-insensitive alphabetical.
+fn child_order(a: &TreeNode, b: &TreeNode) -> std::cmp::Ordering {
+    b.is_dir
+        .cmp(&a.is_dir)
+        .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+        .then_with(|| a.name.cmp(&b.name))
+}
+
+fn sort_children(children: &mut [TreeNode]) {
+    children.sort_by(child_order);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file(path: &str) -> TreeNode {
+        TreeNode {
+            path: path.into(),
+            name: file_name_of(path).into(),
+            is_dir: false,
+            has_children: false,
+            meta: TreeMeta::default(),
+            children: vec![],
+        }
+    }
+
+    fn dir(path: &str, children: Vec<TreeNode>) -> TreeNode {
+        TreeNode {
+            path: path.into(),
+            name: file_name_of(path).into(),
+            is_dir: true,
+            has_children: !children.is_empty(),
+            meta: TreeMeta::default(),
+            children,
+        }
+    }
+
+    #[test]
+    fn insert_keeps_dirs_first_and_alpha_order() {
+        let mut root = dir("", vec![dir("src", vec![]), file("b.rs")]);
+        let (parent, idx) = root.insert(file("A.toml")).unwrap();
+        assert_eq!(parent, "");
+        assert_eq!(idx, 1);
+        let (_, idx) = root.insert(dir("docs", vec![])).unwrap();
+        assert_eq!(idx, 0);
+        let names: Vec<_> = root.children.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["docs", "src", "A.toml", "b.rs"]);
+    }
+
+    #[test]
+    fn move_rebases_subtree_and_ui_state() {
+        let mut tree = WorkspaceTree {
+            root: PathBuf::from("/ws"),
+            nodes: dir(
+                "",
+                vec![dir(
+                    "old",
+                    vec![dir("old/inner", vec![file("old/inner/x.rs")])],
+                )],
+            ),
+            state: TreeState::default(),
+        };
+        tree.state.expanded.insert("old".into());
+        tree.state.expanded.insert("old/inner".into());
+        tree.state.expanded.insert("older".into());
+        tree.state.selected = Some("old/inner/x.rs".into());
+
+        tree.apply(&TreeEvent::NodeMoved {
+            from: "old".into(),
+            to: "new".into(),
+            parent: "".into(),
+            index: 0,
+        });
+
+        assert!(tree.nodes.find("old").is_none());
+        assert_eq!(tree.nodes.find("new/inner/x.rs").unwrap().name, "x.rs");
+        let expanded: Vec<_> = tree.state.expanded.iter().map(String::as_str).collect();
+        assert_eq!(expanded, ["new", "new/inner", "older"]);
+        assert_eq!(tree.state.selected.as_deref(), Some("new/inner/x.rs"));
+    }
+
+    #[test]
+    fn remove_forgets_ui_state() {
+        let mut tree = WorkspaceTree {
+            root: PathBuf::from("/ws"),
+            nodes: dir("", vec![dir("a", vec![file("a/b.rs")])]),
+            state: TreeState::default(),
+        };
+        tree.state.expanded.insert("a".into());
+        tree.state.selected = Some("a/b.rs".into());
+
+        tree.apply(&TreeEvent::NodeRemoved { path: "a".into() });
+
+        assert!(tree.nodes.children.is_empty());
+        assert!(!tree.nodes.has_children);
+        assert!(tree.state.expanded.is_empty());
+        assert_eq!(tree.state.selected, None);
+    }
+}
@@ -0,0 +1,149 @@
+// ============================================================================
+// ⚙️  Forge IDE - Watch Event Coalescer
+// Description:
+//   Folds bursts of raw file system notifications (cargo builds, template
+//   renders, editor save dances) into a single batch of touched paths.
+//   A batch is flushed once the stream has been quiet for `quiet`, or at the
+//   latest `max_latency` after its first event.
+// ============================================================================
+
+use notify::event::{EventKind, ModifyKind, RenameMode};
+use notify::Event;
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// One flushed group of changes
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Batch {
+    /// Every absolute path mentioned by a create/modify/remove event
+    pub touched: BTreeSet<PathBuf>,
+    /// Renames reported with both ends known: `(from, to)`
+    pub renames: Vec<(PathBuf, PathBuf)>,
+}
+
+/// Debouncing accumulator for raw notify events
+#[derive(Debug)]
+pub struct Coalescer {
+    quiet: Duration,
+    max_latency: Duration,
+    pending: Batch,
+    first_at: Option<Instant>,
+    last_at: Option<Instant>,
+}
+
+impl Coalescer {
+    pub fn new(quiet: Duration, max_latency: Duration) -> Self {
+        Self {
+            quiet,
+            max_latency,
+            pending: Batch::default(),
+            first_at: None,
+            last_at: None,
+        }
+    }
+
+    /// Record a raw event observed at `now`. Access events are dropped.
+    pub fn push(&mut self, event: &Event, now: Instant) {
+        match &event.kind {
+            EventKind::Access(_) => return,
+            EventKind::Modify(ModifyKind::Name(RenameMode::Both)) if event.paths.len() == 2 => {
+                self.pending
+                    .renames
+                    .push((event.paths[0].clone(), event.paths[1].clone()));
+            }
+            _ => self.pending.touched.extend(event.paths.iter().cloned()),
+        }
+        self.first_at.get_or_insert(now);
+        self.last_at = Some(now);
+    }
+
+    /// True if nothing is pending.
+    pub fn is_empty(&self) -> bool {
+        self.first_at.is_none()
+    }
+
+    /// How long the caller may block before the pending batch must be flushed.
+    pub fn time_until_ready(&self, now: Instant) -> Option<Duration> {
+        let (first, last) = (self.first_at?, self.last_at?);
+        let quiet_deadline = last + self.quiet;
+        let hard_deadline = first + self.max_latency;
+        Some(
+            quiet_deadline
+                .min(hard_deadline)
+                .saturating_duration_since(now),
+        )
+    }
+
+    /// True if the pending batch should be flushed at `now`.
+    pub fn is_ready(&self, now: Instant) -> bool {
+        self.time_until_ready(now).is_some_and(|d| d.is_zero())
+    }
+
+    /// Take the pending batch, if any.
+    pub fn take(&mut self) -> Option<Batch> {
+        if self.is_empty() {
+            return None;
+        }
+        self.first_at = None;
+        self.last_at = None;
+        Some(std::mem::take(&mut self.pending))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use notify::event::{CreateKind, DataChange};
+
+    fn ev(kind: EventKind, paths: &[&str]) -> Event {
+        let mut e = Event::new(kind);
+        for p in paths {
+            e = e.add_path(PathBuf::from(p));
+        }
+        e
+    }
+
+    #[test]
+    fn burst_collapses_into_one_batch() {
+        let t0 = Instant::now();
+        let mut c = Coalescer::new(Duration::from_millis(50), Duration::from_millis(500));
+        c.push(&ev(EventKind::Create(CreateKind::File), &["/ws/a.rs"]), t0);
+        c.push(
+            &ev(
+                EventKind::Modify(ModifyKind::Data(DataChange::Any)),
+                &["/ws/a.rs"],
+            ),
+            t0 + Duration::from_millis(10),
+        );
+        c.push(
+            &ev(
+                EventKind::Modify(ModifyKind::Name(RenameMode::Both)),
+                &["/ws/b", "/ws/c"],
+            ),
+            t0 + Duration::from_millis(20),
+        );
+
+        assert!(!c.is_ready(t0 + Duration::from_millis(40)));
+        assert!(c.is_ready(t0 + Duration::from_millis(70)));
+
+        let batch = c.take().unwrap();
+        assert_eq!(batch.touched.len(), 1);
+        assert_eq!(
+            batch.renames,
+            vec![(PathBuf::from("/ws/b"), PathBuf::from("/ws/c"))]
+        );
+        assert!(c.take().is_none());
+    }
+
+    #[test]
+    fn steady_stream_is_flushed_at_max_latency() {
+        let t0 = Instant::now();
+        let mut c = Coalescer::new(Duration::from_millis(50), Duration::from_millis(200));
+        for i in 0..10 {
+            let at = t0 + Duration::from_millis(i * 30);
+            c.push(&ev(EventKind::Create(CreateKind::File), &["/ws/x"]), at);
+        }
+        assert!(c.is_ready(t0 + Duration::from_millis(270)));
+    }
+}
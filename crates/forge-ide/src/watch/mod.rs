@@ -0,0 +1,208 @@
+// ============================================================================
+// ⚙️  Forge IDE - Workspace Watcher
+// Description:
+//   Watches the workspace root and keeps a shared `WorkspaceTree` in sync.
+//   Raw notify events are filtered (ignore rules, `target/`), coalesced into
+//   batches, reconciled against the tree, and the resulting `TreeEvent`s are
+//   broadcast to every subscribed client.
+// ============================================================================
+
+mod coalesce;
+mod reconcile;
+mod rules;
+
+pub use coalesce::{Batch, Coalescer};
+pub use reconcile::reconcile;
+pub use rules::IgnoreRules;
+
+use crate::tree::{TreeEvent, WorkspaceTree};
+use anyhow::Context;
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+// ------------------------------------------------------------------------
+// CLIENT FAN-OUT
+// ------------------------------------------------------------------------
+
+/// Broadcasts batches of tree events to every live subscriber
+#[derive(Debug, Clone, Default)]
+pub struct TreeEventHub {
+    clients: Arc<Mutex<Vec<Sender<Vec<TreeEvent>>>>>,
+}
+
+impl TreeEventHub {
+    /// Register a new client; dropping the receiver unsubscribes it.
+    pub fn subscribe(&self) -> Receiver<Vec<TreeEvent>> {
+        let (tx, rx) = mpsc::channel();
+        self.clients.lock().expect("hub poisoned").push(tx);
+        rx
+    }
+
+    /// Send one batch to every client, pruning disconnected ones.
+    pub fn publish(&self, events: Vec<TreeEvent>) {
+        self.clients
+            .lock()
+            .expect("hub poisoned")
+            .retain(|tx| tx.send(events.clone()).is_ok());
+    }
+
+    /// Number of currently connected clients.
+    pub fn client_count(&self) -> usize {
+        self.clients.lock().expect("hub poisoned").len()
+    }
+}
+
+// ------------------------------------------------------------------------
+// WATCHER
+// ------------------------------------------------------------------------
+
+/// Coalescing windows for the watcher
+#[derive(Debug, Clone, Copy)]
+pub struct WatchOptions {
+    /// Flush once no event arrived for this long
+    pub quiet: Duration,
+    /// Never hold a batch longer than this
+    pub max_latency: Duration,
+}
+
+impl Default for WatchOptions {
+    fn default() -> Self {
+        Self {
+            quiet: Duration::from_millis(75),
+            max_latency: Duration::from_millis(500),
+        }
+    }
+}
+
+/// Running watcher: owns the notify handle and the reconcile thread
+pub struct WorkspaceWatcher {
+    tree: Arc<Mutex<WorkspaceTree>>,
+    hub: TreeEventHub,
+    watcher: Option<RecommendedWatcher>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl WorkspaceWatcher {
+    /// Scan `root` and start watching it recursively.
+    pub fn start(root: &Path, opts: WatchOptions) -> anyhow::Result<Self> {
+        let root = root
+            .canonicalize()
+            .with_context(|| format!("resolve workspace root {}", root.display()))?;
+        let rules = IgnoreRules::load(&root);
+        let tree = WorkspaceTree::scan(root.clone(), &rules)
+            .with_context(|| format!("scan workspace {}", root.display()))?;
+        let tree = Arc::new(Mutex::new(tree));
+        let hub = TreeEventHub::default();
+
+        let (tx, rx) = mpsc::channel::<notify::Result<Event>>();
+        let mut watcher = notify::recommended_watcher(tx).context("create file watcher")?;
+        watcher
+            .watch(&root, RecursiveMode::Recursive)
+            .with_context(|| format!("watch {}", root.display()))?;
+
+        let worker = {
+            let tree = Arc::clone(&tree);
+            let hub = hub.clone();
+            thread::Builder::new()
+                .name("forge-ide-watch".into())
+                .spawn(move || run_worker(root, rules, opts, rx, tree, hub))
+                .context("spawn watch thread")?
+        };
+
+        Ok(Self {
+            tree,
+            hub,
+            watcher: Some(watcher),
+            worker: Some(worker),
+        })
+    }
+
+    /// Shared tree (lock it to take a snapshot for a new client).
+    pub fn tree(&self) -> Arc<Mutex<WorkspaceTree>> {
+        Arc::clone(&self.tree)
+    }
+
+    /// Event hub used to reach connected clients.
+    pub fn hub(&self) -> &TreeEventHub {
+        &self.hub
+    }
+
+    /// Shortcut for `hub().subscribe()`.
+    pub fn subscribe(&self) -> Receiver<Vec<TreeEvent>> {
+        self.hub.subscribe()
+    }
+}
+
+impl Drop for WorkspaceWatcher {
+    fn drop(&mut self) {
+        // dropping the watcher closes the event channel, which ends the worker
+        self.watcher.take();
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+fn run_worker(
+    root: PathBuf,
+    mut rules: IgnoreRules,
+    opts: WatchOptions,
+    rx: Receiver<notify::Result<Event>>,
+    tree: Arc<Mutex<WorkspaceTree>>,
+    hub: TreeEventHub,
+) {
+    let mut coalescer = Coalescer::new(opts.quiet, opts.max_latency);
+    loop {
+        let received = match coalescer.time_until_ready(Instant::now()) {
+            Some(wait) => rx.recv_timeout(wait),
+            None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+
+        match received {
+            Ok(Ok(event)) => {
+                if event.paths.iter().any(|p| rules.is_rules_file(p)) {
+                    // new rules apply to every event from here on
+                    rules = IgnoreRules::load(&root);
+                }
+                let visible = event.paths.iter().any(|p| !rules.is_ignored(p, p.is_dir()));
+                if visible {
+                    coalescer.push(&event, Instant::now());
+                }
+            }
+            // notify backend errors are transient (e.g. a vanished subdir)
+            Ok(Err(_)) | Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+
+        if coalescer.is_ready(Instant::now()) {
+            if let Some(batch) = coalescer.take() {
+                let events = {
+                    let mut tree = tree.lock().expect("tree poisoned");
+                    reconcile(&mut tree, &rules, batch)
+                };
+                if !events.is_empty() {
+                    hub.publish(events);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hub_drops_disconnected_clients() {
+        let hub = TreeEventHub::default();
+        let keep = hub.subscribe();
+        drop(hub.subscribe());
+        hub.publish(vec![TreeEvent::NodeRemoved { path: "a".into() }]);
+        assert_eq!(hub.client_count(), 1);
+        assert_eq!(keep.try_recv().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,338 @@
+// ============================================================================
+// ⚙️  Forge IDE - Watch Reconciler
+// Description:
+//   Turns a coalesced batch into tree events by comparing what the file
+//   system says *now* with what the tree believes. Raw event order is never
+//   trusted; only the final state of each touched path matters.
+//
+//   Order of emitted events: moves, removals, inserts, metadata changes.
+//   Every event is applied to the tree as it is produced, so later events
+//   always refer to the updated structure.
+// ============================================================================
+
+use super::coalesce::Batch;
+use super::rules::IgnoreRules;
+use crate::tree::{parent_of, TreeEvent, TreeMeta, TreeNode, WorkspaceTree};
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+/// What the file system currently holds at a path
+struct OnDisk {
+    is_dir: bool,
+    meta: TreeMeta,
+}
+
+fn on_disk(abs: &Path, rules: &IgnoreRules) -> Option<OnDisk> {
+    let md = fs::metadata(abs).ok()?;
+    if rules.is_ignored(abs, md.is_dir()) {
+        return None;
+    }
+    Some(OnDisk {
+        is_dir: md.is_dir(),
+        meta: TreeMeta::from_fs(&md),
+    })
+}
+
+/// Reconcile `batch` into `tree`, returning the events that were applied.
+pub fn reconcile(tree: &mut WorkspaceTree, rules: &IgnoreRules, batch: Batch) -> Vec<TreeEvent> {
+    let mut events = Vec::new();
+    let mut touched: BTreeSet<String> = BTreeSet::new();
+
+    // --------------------------------------------------------------------
+    // 1) Renames reported with both ends
+    // --------------------------------------------------------------------
+    for (from_abs, to_abs) in &batch.renames {
+        let (Some(from), Some(to)) = (tree.rel_path(from_abs), tree.rel_path(to_abs)) else {
+            continue;
+        };
+        if tree.nodes.find(&from).is_some() && on_disk(to_abs, rules).is_some() {
+            push_move(tree, &mut events, &from, &to);
+        }
+        // whatever happened, both ends get a final-state check below
+        touched.insert(from);
+        touched.insert(to);
+    }
+
+    for abs in &batch.touched {
+        if let Some(rel) = tree.rel_path(abs) {
+            if !rel.is_empty() {
+                touched.insert(rel);
+            }
+        }
+    }
+
+    // --------------------------------------------------------------------
+    // 2) Classify by final state
+    // --------------------------------------------------------------------
+    let mut removed = Vec::new();
+    let mut created = Vec::new();
+    let mut existing = Vec::new();
+    for rel in &touched {
+        let disk = on_disk(&tree.abs_path(rel), rules);
+        match (tree.nodes.find(rel).is_some(), disk) {
+            (true, None) => removed.push(rel.clone()),
+            (false, Some(d)) => created.push((rel.clone(), d)),
+            (true, Some(d)) => existing.push((rel.clone(), d)),
+            (false, None) => {}
+        }
+    }
+
+    // --------------------------------------------------------------------
+    // 3) Pair remove + create into a move when unambiguous
+    // --------------------------------------------------------------------
+    let mut paired = BTreeSet::new();
+    let mut moves = Vec::new();
+    for from in &removed {
+        let Some(old) = tree.nodes.find(from) else {
+            continue;
+        };
+        let candidates: Vec<&String> = created
+            .iter()
+            .filter(|(to, d)| !paired.contains(to) && looks_like_move(old, from, to, d))
+            .map(|(to, _)| to)
+            .collect();
+        if let [to] = candidates.as_slice() {
+            paired.insert((*to).clone());
+            paired.insert(from.clone());
+            moves.push((from.clone(), (*to).clone()));
+        }
+    }
+    for (from, to) in &moves {
+        push_move(tree, &mut events, from, to);
+    }
+
+    // --------------------------------------------------------------------
+    // 4) Removals (shallow first; descendants go with their ancestor)
+    // --------------------------------------------------------------------
+    for path in removed.iter().filter(|p| !paired.contains(*p)) {
+        if tree.nodes.find(path).is_some() {
+            let ev = TreeEvent::NodeRemoved { path: path.clone() };
+            tree.apply(&ev);
+            events.push(ev);
+        }
+    }
+
+    // --------------------------------------------------------------------
+    // 5) Inserts — insert the highest missing ancestor with its full subtree
+    // --------------------------------------------------------------------
+    for (path, _) in created.iter().filter(|(p, _)| !paired.contains(p)) {
+        let mut top = path.as_str();
+        while !parent_of(top).is_empty() && tree.nodes.find(parent_of(top)).is_none() {
+            top = parent_of(top);
+        }
+        if tree.nodes.find(top).is_some() {
+            continue;
+        }
+        let Ok(node) = TreeNode::scan(&tree.abs_path(top), top, rules) else {
+            continue;
+        };
+        let Some((parent, index)) = tree.nodes.insert(node.clone()) else {
+            continue;
+        };
+        events.push(TreeEvent::NodeInserted {
+            parent,
+            index,
+            node,
+        });
+    }
+
+    // --------------------------------------------------------------------
+    // 6) Metadata changes on files that stayed in place
+    // --------------------------------------------------------------------
+    for (path, disk) in &existing {
+        let Some(node) = tree.nodes.find(path) else {
+            continue;
+        };
+        if disk.is_dir != node.is_dir {
+            // file replaced by a directory (or vice versa)
+            let removed = TreeEvent::NodeRemoved { path: path.clone() };
+            tree.apply(&removed);
+            events.push(removed);
+            if let Ok(node) = TreeNode::scan(&tree.abs_path(path), path, rules) {
+                if let Some((parent, index)) = tree.nodes.insert(node.clone()) {
+                    events.push(TreeEvent::NodeInserted {
+                        parent,
+                        index,
+                        node,
+                    });
+                }
+            }
+        } else if !disk.is_dir && node.meta != disk.meta {
+            let ev = TreeEvent::MetadataChanged {
+                path: path.clone(),
+                meta: disk.meta.clone(),
+            };
+            tree.apply(&ev);
+            events.push(ev);
+        }
+    }
+
+    events
+}
+
+/// A vanished node and a new path are the same entry if kind and size match
+/// and the entry either kept its name or stayed in the same directory.
+fn looks_like_move(old: &TreeNode, from: &str, to: &str, disk: &OnDisk) -> bool {
+    if old.is_dir != disk.is_dir {
+        return false;
+    }
+    if !old.is_dir && old.meta.size != disk.meta.size {
+        return false;
+    }
+    let same_name = crate::tree::file_name_of(from) == crate::tree::file_name_of(to);
+    let same_parent = parent_of(from) == parent_of(to);
+    same_name || same_parent
+}
+
+fn push_move(tree: &mut WorkspaceTree, events: &mut Vec<TreeEvent>, from: &str, to: &str) {
+    if from == to || tree.nodes.find(to).is_some() {
+        return;
+    }
+    if let Some((parent, index)) = tree.move_node(from, to) {
+        events.push(TreeEvent::NodeMoved {
+            from: from.to_string(),
+            to: to.to_string(),
+            parent,
+            index,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn workspace() -> (tempfile::TempDir, WorkspaceTree, IgnoreRules) {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("src/nested")).unwrap();
+        fs::create_dir_all(root.join("target/debug")).unwrap();
+        fs::write(root.join("src/lib.rs"), "pub mod nested;\n").unwrap();
+        fs::write(root.join("src/nested/mod.rs"), "").unwrap();
+        let rules = IgnoreRules::load(root);
+        let tree = WorkspaceTree::scan(root, &rules).unwrap();
+        (dir, tree, rules)
+    }
+
+    fn touched(paths: &[PathBuf]) -> Batch {
+        Batch {
+            touched: paths.iter().cloned().collect(),
+            renames: vec![],
+        }
+    }
+
+    #[test]
+    fn scan_skips_target() {
+        let (_dir, tree, _) = workspace();
+        assert!(tree.nodes.find("target").is_none());
+        assert!(tree.nodes.find("src/nested/mod.rs").is_some());
+    }
+
+    #[test]
+    fn new_directory_is_inserted_with_its_subtree() {
+        let (dir, mut tree, rules) = workspace();
+        let gen = dir.path().join("src/gen/deep");
+        fs::create_dir_all(&gen).unwrap();
+        fs::write(gen.join("out.rs"), "// generated").unwrap();
+        fs::write(dir.path().join("target/debug/noise.o"), "x").unwrap();
+
+        let events = reconcile(
+            &mut tree,
+            &rules,
+            touched(&[
+                gen.join("out.rs"),
+                gen.clone(),
+                dir.path().join("target/debug/noise.o"),
+            ]),
+        );
+
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            TreeEvent::NodeInserted { parent, node, .. } => {
+                assert_eq!(parent, "src");
+                assert_eq!(node.path, "src/gen");
+                assert!(node.find("src/gen/deep/out.rs").is_some());
+            }
+            other => panic!("unexpected event {other:?}"),
+        }
+    }
+
+    #[test]
+    fn removal_and_metadata_change() {
+        let (dir, mut tree, rules) = workspace();
+        fs::remove_dir_all(dir.path().join("src/nested")).unwrap();
+        fs::write(dir.path().join("src/lib.rs"), "pub fn grown() {}\n").unwrap();
+
+        let events = reconcile(
+            &mut tree,
+            &rules,
+            touched(&[
+                dir.path().join("src/nested/mod.rs"),
+                dir.path().join("src/nested"),
+                dir.path().join("src/lib.rs"),
+            ]),
+        );
+
+        assert_eq!(
+            events[0],
+            TreeEvent::NodeRemoved {
+                path: "src/nested".into()
+            }
+        );
+        assert!(
+            matches!(&events[1], TreeEvent::MetadataChanged { path, meta }
+            if path == "src/lib.rs" && meta.size == Some(18))
+        );
+        assert_eq!(events.len(), 2);
+    }
+
+    #[test]
+    fn rename_keeps_expanded_state_and_selection() {
+        let (dir, mut tree, rules) = workspace();
+        tree.state.expanded.insert("src/nested".into());
+        tree.state.selected = Some("src/nested/mod.rs".into());
+        fs::rename(dir.path().join("src/nested"), dir.path().join("src/moved")).unwrap();
+
+        // no explicit rename pair: the reconciler must pair remove + create
+        let events = reconcile(
+            &mut tree,
+            &rules,
+            touched(&[dir.path().join("src/nested"), dir.path().join("src/moved")]),
+        );
+
+        assert!(
+            matches!(&events[..], [TreeEvent::NodeMoved { from, to, .. }]
+            if from == "src/nested" && to == "src/moved")
+        );
+        assert!(tree.state.expanded.contains("src/moved"));
+        assert_eq!(tree.state.selected.as_deref(), Some("src/moved/mod.rs"));
+    }
+
+    #[test]
+    fn explicit_rename_pair_moves_across_directories() {
+        let (dir, mut tree, rules) = workspace();
+        tree.state.selected = Some("src/lib.rs".into());
+        fs::rename(
+            dir.path().join("src/lib.rs"),
+            dir.path().join("src/nested/lib2.rs"),
+        )
+        .unwrap();
+
+        let batch = Batch {
+            touched: BTreeSet::new(),
+            renames: vec![(
+                dir.path().join("src/lib.rs"),
+                dir.path().join("src/nested/lib2.rs"),
+            )],
+        };
+        let events = reconcile(&mut tree, &rules, batch);
+
+        assert!(
+            matches!(&events[0], TreeEvent::NodeMoved { to, parent, index, .. }
+            if to == "src/nested/lib2.rs" && parent == "src/nested" && *index == 0)
+        );
+        assert_eq!(tree.state.selected.as_deref(), Some("src/nested/lib2.rs"));
+    }
+}
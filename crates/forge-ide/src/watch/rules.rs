@@ -0,0 +1,93 @@
+// ============================================================================
+// ⚙️  Forge IDE - Watch Ignore Rules
+// Description:
+//   Decides which workspace paths are visible to the tree and the watcher.
+//   Build output and VCS internals are always hidden; everything else follows
+//   the root `.gitignore` / `.ignore` files.
+// ============================================================================
+
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use std::path::{Component, Path, PathBuf};
+
+/// Directory names that are never shown, regardless of ignore files.
+const ALWAYS_IGNORED: &[&str] = &["target", ".git"];
+
+/// Ignore files read from the workspace root.
+const IGNORE_FILES: &[&str] = &[".gitignore", ".ignore"];
+
+/// Path filter shared by the tree scanner and the watcher
+#[derive(Debug, Clone)]
+pub struct IgnoreRules {
+    root: PathBuf,
+    matcher: Gitignore,
+}
+
+impl IgnoreRules {
+    /// Load ignore files from `root`. Missing or malformed files are skipped.
+    pub fn load(root: &Path) -> Self {
+        let mut builder = GitignoreBuilder::new(root);
+        for name in IGNORE_FILES {
+            let file = root.join(name);
+            if file.is_file() {
+                // a partial error still yields the valid globs
+                let _ = builder.add(file);
+            }
+        }
+        let matcher = builder.build().unwrap_or_else(|_| Gitignore::empty());
+        Self {
+            root: root.to_path_buf(),
+            matcher,
+        }
+    }
+
+    /// True if the file name is one of the ignore files this filter reads.
+    pub fn is_rules_file(&self, abs: &Path) -> bool {
+        abs.parent() == Some(self.root.as_path())
+            && abs
+                .file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| IGNORE_FILES.contains(&n))
+    }
+
+    /// True if `abs` must not appear in the tree. Paths outside the root are ignored.
+    pub fn is_ignored(&self, abs: &Path, is_dir: bool) -> bool {
+        let Ok(rel) = abs.strip_prefix(&self.root) else {
+            return true;
+        };
+        if rel.as_os_str().is_empty() {
+            return false;
+        }
+        let hard_ignored = rel.components().any(|c| match c {
+            Component::Normal(name) => name.to_str().is_some_and(|n| ALWAYS_IGNORED.contains(&n)),
+            _ => false,
+        });
+        hard_ignored
+            || self
+                .matcher
+                .matched_path_or_any_parents(rel, is_dir)
+                .is_ignore()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn target_git_and_gitignore_entries_are_hidden() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::write(root.join(".gitignore"), "*.log\n/build_reports/\n").unwrap();
+        let rules = IgnoreRules::load(root);
+
+        assert!(rules.is_ignored(&root.join("target"), true));
+        assert!(rules.is_ignored(&root.join("crates/x/target/debug/foo"), false));
+        assert!(rules.is_ignored(&root.join(".git/HEAD"), false));
+        assert!(rules.is_ignored(&root.join("logs/run.log"), false));
+        assert!(rules.is_ignored(&root.join("build_reports/a.json"), false));
+        assert!(!rules.is_ignored(&root.join("src/main.rs"), false));
+        assert!(!rules.is_ignored(&root.join("targets.rs"), false));
+        assert!(rules.is_rules_file(&root.join(".gitignore")));
+    }
+}
@@ -0,0 +1,158 @@
+// ============================================================================
+// ⚙️  Forge IDE - UI Components
+// Description:
+//   `component new <name>`: renders a forge-web-ui-components component from
+//   `templates/ui_component/`. `context.yaml` (with `name` replaced) drives
+//   `mod.mustache` → `<name>/mod.rs`, which is regenerated on every run, and
+//   one `<mod>.mustache` per declared submodule → `<name>/<mod>.rs`, which is
+//   only written when missing. The crate root gets `pub mod <name>;`.
+// ============================================================================
+
+use super::scaffold::{crate_root_file, is_rust_ident};
+use super::{ensure_mod_decl, ForgeWorkspace, Scaffolded};
+use crate::generate::{rustfmt, write_if_changed, FileChange};
+use anyhow::{bail, Context, Result};
+use serde_json::Value;
+use std::fs;
+
+/// Component context, relative to `templates/`
+pub const COMPONENT_CONTEXT: &str = "ui_component/context.yaml";
+/// Component `mod.rs` template, relative to `templates/`
+pub const COMPONENT_MOD_TEMPLATE: &str = "ui_component/mod.mustache";
+/// Crate components are generated into by default
+pub const COMPONENT_CRATE: &str = "forge-web-ui-components";
+
+impl ForgeWorkspace {
+    /// `component new`: generate component `name` into `crate_name`.
+    pub fn create_component(&self, crate_name: &str, name: &str) -> Result<Scaffolded> {
+        if !is_rust_ident(name) {
+            bail!("'{name}' is not a valid module name");
+        }
+        let src = self.crate_dir(crate_name).join("src");
+        let root_file = crate_root_file(&src)?;
+        let ctx = self.component_context(name)?;
+        let dir = src.join(name);
+        let mut report = Scaffolded::default();
+
+        let mod_rs = dir.join("mod.rs");
+        let raw = forge_template::render_value_from_abs(&ctx, self.template(COMPONENT_MOD_TEMPLATE))
+            .with_context(|| format!("render {COMPONENT_MOD_TEMPLATE}"))?;
+        fs::create_dir_all(&dir).with_context(|| format!("create {}", dir.display()))?;
+        let change = write_if_changed(&mod_rs, &rustfmt(&raw).unwrap_or(raw))?;
+        report.record(&mod_rs, change != FileChange::Unchanged, change == FileChange::Updated);
+
+        for module in submodules(&ctx)? {
+            let template = format!("ui_component/{module}.mustache");
+            if !self.template(&template).is_file() {
+                bail!("missing template for submodule {module}: {}", self.template(&template).display());
+            }
+            let mut ctx = ctx.clone();
+            ctx["payload"]["module"] = Value::String(module.clone());
+            let file = dir.join(format!("{module}.rs"));
+            let created = self.write_rendered(&file, &template, &ctx)?;
+            report.record(&file, created, !created);
+        }
+
+        let changed = ensure_mod_decl(&root_file, name)?;
+        report.record(&root_file, changed, true);
+        Ok(report)
+    }
+
+    /// `context.yaml` with `header.name` set to `name`.
+    fn component_context(&self, name: &str) -> Result<Value> {
+        let path = self.template(COMPONENT_CONTEXT);
+        let text = fs::read_to_string(&path).with_context(|| format!("read {}", path.display()))?;
+        let mut ctx: Value =
+            serde_yaml::from_str(&text).with_context(|| format!("parse {}", path.display()))?;
+        match ctx.get_mut("header").and_then(Value::as_object_mut) {
+            Some(header) => header.insert("name".into(), Value::String(name.to_string())),
+            None => bail!("{} has no `header` mapping", path.display()),
+        };
+        Ok(ctx)
+    }
+}
+
+/// `payload.mods`, each a valid module name.
+fn submodules(ctx: &Value) -> Result<Vec<String>> {
+    let mods = ctx
+        .pointer("/payload/mods")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    mods.iter()
+        .map(|m| match m.as_str() {
+            Some(m) if is_rust_ident(m) => Ok(m.to_string()),
+            _ => bail!("`payload.mods` entry {m} is not a valid module name"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn workspace() -> (tempfile::TempDir, ForgeWorkspace) {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("crates/ui/src")).unwrap();
+        fs::write(root.join("crates/ui/src/lib.rs"), "//! ui\n").unwrap();
+        let tpl = root.join("templates/ui_component");
+        fs::create_dir_all(&tpl).unwrap();
+        fs::write(
+            tpl.join("context.yaml"),
+            "header:\n  name: button\npayload:\n  mods: [model, style]\n  exports:\n    - { mod: model, suffix: \"\" }\n",
+        )
+        .unwrap();
+        fs::write(
+            tpl.join("mod.mustache"),
+            "{{#payload.mods}}pub mod {{value}};\n{{/payload.mods}}{{#payload.exports}}pub use {{mod}}::{{../name_PascalCase}}{{suffix}};\n{{/payload.exports}}",
+        )
+        .unwrap();
+        fs::write(tpl.join("model.mustache"), "pub struct {{payload.name_PascalCase}};\n").unwrap();
+        fs::write(tpl.join("style.mustache"), "// {{payload.module}} of {{payload.name}}\n").unwrap();
+        let ws = ForgeWorkspace::new(root);
+        (dir, ws)
+    }
+
+    #[test]
+    fn component_is_generated_and_wired_idempotently() {
+        let (dir, ws) = workspace();
+        let src = dir.path().join("crates/ui/src");
+
+        let first = ws.create_component("ui", "text_input").unwrap();
+        assert_eq!(
+            first.created,
+            ["mod.rs", "model.rs", "style.rs"].map(|f| src.join("text_input").join(f))
+        );
+        assert_eq!(first.updated, [src.join("lib.rs")]);
+        assert_eq!(
+            fs::read_to_string(src.join("text_input/mod.rs")).unwrap(),
+            "pub mod model;\npub mod style;\npub use model::TextInput;\n"
+        );
+        assert_eq!(
+            fs::read_to_string(src.join("text_input/style.rs")).unwrap(),
+            "// style of text_input\n"
+        );
+
+        // hand edits to submodules survive; mod.rs is regenerated
+        fs::write(src.join("text_input/model.rs"), "pub struct TextInput { pub value: String }\n").unwrap();
+        fs::write(src.join("text_input/mod.rs"), "stale").unwrap();
+        let again = ws.create_component("ui", "text_input").unwrap();
+        assert!(again.created.is_empty());
+        assert_eq!(again.updated, [src.join("text_input/mod.rs")]);
+        assert!(fs::read_to_string(src.join("text_input/model.rs")).unwrap().contains("value"));
+
+        let lib = fs::read_to_string(src.join("lib.rs")).unwrap();
+        assert_eq!(lib.matches("pub mod text_input;").count(), 1);
+        assert!(ws.create_component("ui", "text-input").is_err());
+        assert!(ws.create_component("ui", "type").is_err());
+        assert!(!src.join("type").exists());
+    }
+
+    #[test]
+    fn keyword_submodules_are_rejected() {
+        let ctx = serde_json::json!({ "payload": { "mods": ["model", "impl"] } });
+        let err = submodules(&ctx).unwrap_err().to_string();
+        assert!(err.contains("impl"), "{err}");
+    }
+}
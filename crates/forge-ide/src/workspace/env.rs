@@ -0,0 +1,36 @@
+// ============================================================================
+// ⚙️  Forge IDE - .forge_env
+// Description:
+//   Reads and rewrites the `export FORGE_CURRENT_CRATE="..."` line of the
+//   shell env file shared with the zsh scripts. Other lines are preserved.
+// ============================================================================
+
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::Path;
+
+const EXPORT_PREFIX: &str = "export FORGE_CURRENT_CRATE=";
+
+/// Current crate recorded in `env_file`, if any.
+pub fn read_current_crate(env_file: &Path) -> Option<String> {
+    let text = fs::read_to_string(env_file).ok()?;
+    text.lines()
+        .rev()
+        .find_map(|l| l.trim().strip_prefix(EXPORT_PREFIX))
+        .map(|v| v.trim().trim_matches(|c| c == '"' || c == '\'').to_string())
+        .filter(|v| !v.is_empty())
+}
+
+/// Replace (or with `None`, remove) the current crate line in `env_file`.
+pub fn write_current_crate(env_file: &Path, name: Option<&str>) -> Result<()> {
+    let existing = fs::read_to_string(env_file).unwrap_or_default();
+    let mut out: String = existing
+        .lines()
+        .filter(|l| !l.trim().starts_with(EXPORT_PREFIX))
+        .flat_map(|l| [l, "\n"])
+        .collect();
+    if let Some(name) = name {
+        out.push_str(&format!("{EXPORT_PREFIX}\"{name}\"\n"));
+    }
+    fs::write(env_file, out).with_context(|| format!("write {}", env_file.display()))
+}
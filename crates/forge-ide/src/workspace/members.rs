@@ -0,0 +1,102 @@
+// ============================================================================
+// ⚙️  Forge IDE - Workspace Members
+// Description:
+//   Adds crates to `[workspace].members` of the root Cargo.toml, keeping the
+//   existing formatting and comments (toml_edit round-trip).
+// ============================================================================
+
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::path::Path;
+use toml_edit::{Array, DocumentMut, Item, Value};
+
+/// Add `member` (e.g. `crates/forge-net`) to `source` unless present.
+pub fn insert_workspace_member(source: &str, member: &str) -> Result<Option<String>> {
+    let mut doc: DocumentMut = source.parse().context("parse workspace Cargo.toml")?;
+    let Some(workspace) = doc.get_mut("workspace").and_then(Item::as_table_like_mut) else {
+        bail!("Cargo.toml has no [workspace] table");
+    };
+    if workspace.get("members").is_none() {
+        workspace.insert("members", Item::Value(Value::Array(Array::new())));
+    }
+    let Some(members) = workspace.get_mut("members").and_then(Item::as_array_mut) else {
+        bail!("[workspace].members is not an array");
+    };
+
+    let normalized = member.trim_end_matches('/');
+    if members.iter().any(|m| {
+        m.as_str()
+            .is_some_and(|s| s.trim_end_matches('/') == normalized)
+    }) {
+        return Ok(None);
+    }
+
+    // follow the layout of the existing entries (one per line or inline)
+    let prefix = members
+        .iter()
+        .next()
+        .and_then(|first| first.decor().prefix())
+        .and_then(|p| p.as_str())
+        .filter(|p| p.contains('\n'))
+        .unwrap_or(" ")
+        .to_string();
+    let trailing_comma = members.trailing_comma();
+    let trailing = members.trailing().as_str().unwrap_or("").to_string();
+    if let Some(last) = members.iter_mut().last() {
+        // the closing bracket hugged the last entry: move it onto its own line
+        last.decor_mut().set_suffix("");
+    }
+    members.push_formatted(Value::from(normalized).decorated(prefix.as_str(), ""));
+    members.set_trailing_comma(trailing_comma || prefix.contains('\n'));
+    let closing = if prefix.contains('\n') && !trailing.contains('\n') {
+        "\n".to_string()
+    } else {
+        trailing
+    };
+    members.set_trailing(closing);
+
+    Ok(Some(doc.to_string()))
+}
+
+/// Ensure the workspace manifest at `cargo_toml` lists `member`.
+/// Returns `true` if the file changed.
+pub fn ensure_workspace_member(cargo_toml: &Path, member: &str) -> Result<bool> {
+    let source =
+        fs::read_to_string(cargo_toml).with_context(|| format!("read {}", cargo_toml.display()))?;
+    match insert_workspace_member(&source, member)? {
+        Some(updated) => {
+            fs::write(cargo_toml, updated)
+                .with_context(|| format!("write {}", cargo_toml.display()))?;
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn appends_member_in_existing_style_once() {
+        let src = "[workspace]\nmembers = [\n    \"crates/forge-template\",\n    \"crates/forge-ide\"]\nresolver = \"2\"\n";
+        let out = insert_workspace_member(src, "crates/forge-fs")
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            out,
+            "[workspace]\nmembers = [\n    \"crates/forge-template\",\n    \"crates/forge-ide\",\n    \"crates/forge-fs\",\n]\nresolver = \"2\"\n"
+        );
+        assert!(insert_workspace_member(&out, "crates/forge-fs/")
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn inline_arrays_stay_inline() {
+        let src = "[workspace]\nmembers = [\"a\"]\n";
+        let out = insert_workspace_member(src, "b").unwrap().unwrap();
+        assert_eq!(out, "[workspace]\nmembers = [\"a\", \"b\"]\n");
+        assert!(insert_workspace_member("[package]\nname = \"x\"\n", "b").is_err());
+    }
+}
@@ -0,0 +1,155 @@
+// ============================================================================
+// ⚙️  Forge IDE - Workspace Operations
+// Description:
+//   Rust port of the zsh crate-view workflow (forge_view, forge_create,
+//   create_mod_dir, create_mod_file, tree_view). A `ForgeWorkspace` is the
+//   repo root; the "current crate" is persisted in `.forge_env`.
+// ============================================================================
+
+mod component;
+mod env;
+mod members;
+mod module_decl;
+mod scaffold;
+mod tree_view;
+
+pub use component::{COMPONENT_CONTEXT, COMPONENT_CRATE, COMPONENT_MOD_TEMPLATE};
+pub use env::{read_current_crate, write_current_crate};
+pub use members::{ensure_workspace_member, insert_workspace_member};
+pub use module_decl::{ensure_mod_decl, has_mod_decl, insert_mod_decl};
+pub use scaffold::{CrateKind, Scaffolded};
+pub use tree_view::render_tree;
+
+use anyhow::{bail, Result};
+use std::path::{Path, PathBuf};
+
+/// Marker file placed at the Forge repo root
+pub const ROOT_MARKER: &str = ".forge-root";
+
+/// The Forge repository the IDE operates on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForgeWorkspace {
+    root: PathBuf,
+}
+
+impl ForgeWorkspace {
+    /// Use `root` as-is.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// `$FORGE_ROOT` if set, otherwise the nearest ancestor of `start` holding
+    /// `.forge-root` (or, failing that, a `[workspace]` Cargo.toml).
+    pub fn discover(start: &Path) -> Result<Self> {
+        if let Some(root) = std::env::var_os("FORGE_ROOT").filter(|r| !r.is_empty()) {
+            return Ok(Self::new(root));
+        }
+        let start = start.canonicalize().unwrap_or_else(|_| start.to_path_buf());
+        if let Some(dir) = start.ancestors().find(|d| d.join(ROOT_MARKER).is_file()) {
+            return Ok(Self::new(dir));
+        }
+        if let Some(dir) = start
+            .ancestors()
+            .find(|d| is_workspace_manifest(&d.join("Cargo.toml")))
+        {
+            return Ok(Self::new(dir));
+        }
+        bail!(
+            "no Forge root found from {} (set FORGE_ROOT or add {ROOT_MARKER})",
+            start.display()
+        )
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// `<root>/crates/<name>`
+    pub fn crate_dir(&self, name: &str) -> PathBuf {
+        self.root.join("crates").join(name)
+    }
+
+    /// `<root>/templates/<rel>`
+    pub fn template(&self, rel: &str) -> PathBuf {
+        self.root.join("templates").join(rel)
+    }
+
+    // --------------------------------------------------------------------
+    // CURRENT CRATE (forge_view)
+    // --------------------------------------------------------------------
+
+    /// Crate selected with `view`, falling back to `$FORGE_CURRENT_CRATE`.
+    pub fn current_crate(&self) -> Option<String> {
+        read_current_crate(&self.env_file()).or_else(|| {
+            std::env::var("FORGE_CURRENT_CRATE")
+                .ok()
+                .filter(|c| !c.is_empty())
+        })
+    }
+
+    /// Current crate or an error telling the user how to pick one.
+    pub fn require_current_crate(&self) -> Result<String> {
+        match self.current_crate() {
+            Some(name) => Ok(name),
+            None => bail!("no current crate view is set; run `forge-ide view <crate>` first"),
+        }
+    }
+
+    /// Persist `name` as the current crate after checking it exists.
+    pub fn set_current_crate(&self, name: &str) -> Result<()> {
+        if !self.crate_dir(name).is_dir() {
+            bail!(
+                "crate '{name}' not found under {}",
+                self.root.join("crates").display()
+            );
+        }
+        write_current_crate(&self.env_file(), Some(name))
+    }
+
+    /// Forget the current crate.
+    pub fn clear_current_crate(&self) -> Result<()> {
+        write_current_crate(&self.env_file(), None)
+    }
+
+    fn env_file(&self) -> PathBuf {
+        self.root.join(".forge_env")
+    }
+}
+
+/// True if `path` is a Cargo.toml with a `[workspace]` table.
+pub(crate) fn is_workspace_manifest(path: &Path) -> bool {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|s| s.parse::<toml_edit::DocumentMut>().ok())
+        .is_some_and(|doc| doc.get("workspace").is_some())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn current_crate_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("crates/forge-ide")).unwrap();
+        fs::write(dir.path().join(".forge_env"), "export OTHER=1\n").unwrap();
+        let ws = ForgeWorkspace::new(dir.path());
+
+        assert!(ws.set_current_crate("missing").is_err());
+        ws.set_current_crate("forge-ide").unwrap();
+        ws.set_current_crate("forge-ide").unwrap();
+        assert_eq!(
+            read_current_crate(&dir.path().join(".forge_env")).as_deref(),
+            Some("forge-ide")
+        );
+        let env = fs::read_to_string(dir.path().join(".forge_env")).unwrap();
+        assert_eq!(
+            env,
+            "export OTHER=1\nexport FORGE_CURRENT_CRATE=\"forge-ide\"\n"
+        );
+
+        ws.clear_current_crate().unwrap();
+        assert_eq!(read_current_crate(&dir.path().join(".forge_env")), None);
+    }
+}
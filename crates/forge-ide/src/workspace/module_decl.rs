@@ -0,0 +1,141 @@
+// ============================================================================
+// ⚙️  Forge IDE - Module Declarations
+// Description:
+//   Idempotent editing of `lib.rs` / `mod.rs`: detects existing top-level
+//   `mod x;` or `mod x { ... }` items (any visibility, parsed with syn) and
+//   inserts `pub mod x;` next to the other declarations instead of blindly
+//   appending.
+// ============================================================================
+
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::Path;
+use syn::spanned::Spanned;
+use syn::Item;
+
+/// Top-level `mod` items of `source`: name, whether it ends in `;`, and the
+/// 1-based line it ends on. Modules nested in other items (`mod tests { mod
+/// fixtures; }`) are not declarations of the file's own children.
+fn top_level_mods(source: &str) -> Result<Vec<(String, bool, usize)>> {
+    let file = syn::parse_file(source).context("parse Rust source")?;
+    Ok(file
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Mod(m) => Some((m.ident.to_string(), m.semi.is_some(), item.span().end().line)),
+            _ => None,
+        })
+        .collect())
+}
+
+/// True if `source` already declares module `name` at the top level.
+pub fn has_mod_decl(source: &str, name: &str) -> Result<bool> {
+    Ok(top_level_mods(source)?.iter().any(|(n, _, _)| n == name))
+}
+
+/// Insert `pub mod name;` into `source` unless already declared.
+/// Goes after the last top-level `mod x;`, else after the leading `//!`
+/// docs / inner attributes, else at the top. Fails if `source` does not parse.
+pub fn insert_mod_decl(source: &str, name: &str) -> Result<Option<String>> {
+    let mods = top_level_mods(source)?;
+    if mods.iter().any(|(n, _, _)| n == name) {
+        return Ok(None);
+    }
+    let lines: Vec<&str> = source.lines().collect();
+    let decl = format!("pub mod {name};");
+
+    let after_last_decl = mods
+        .iter()
+        .rev()
+        .find(|(_, semi, _)| *semi)
+        .map(|(_, _, line)| *line);
+    let (at, pad) = match after_last_decl {
+        Some(i) => (i, false),
+        None => {
+            let header = lines
+                .iter()
+                .take_while(|l| {
+                    let t = l.trim_start();
+                    t.starts_with("//") || t.starts_with("#![") || t.is_empty()
+                })
+                .count();
+            // keep a blank line between the header and the declaration
+            let header = lines[..header]
+                .iter()
+                .rposition(|l| !l.trim().is_empty())
+                .map_or(0, |i| i + 1);
+            (header, true)
+        }
+    };
+
+    let mut out: Vec<String> = lines[..at].iter().map(|s| s.to_string()).collect();
+    if pad && at > 0 {
+        out.push(String::new());
+    }
+    out.push(decl);
+    if pad && lines.get(at).is_some_and(|l| !l.trim().is_empty()) {
+        out.push(String::new());
+    }
+    out.extend(lines[at..].iter().map(|s| s.to_string()));
+    Ok(Some(out.join("\n") + "\n"))
+}
+
+/// Ensure `file` declares `pub mod name;`. Returns `true` if the file changed.
+pub fn ensure_mod_decl(file: &Path, name: &str) -> Result<bool> {
+    let source = fs::read_to_string(file).with_context(|| format!("read {}", file.display()))?;
+    match insert_mod_decl(&source, name).with_context(|| format!("edit {}", file.display()))? {
+        Some(updated) => {
+            fs::write(file, updated).with_context(|| format!("write {}", file.display()))?;
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_any_visibility_and_inline_modules() {
+        let src = "pub(crate) mod a;\nmod b { }\n  pub mod c ;\n// mod d;\nfn module() {}\n";
+        assert!(has_mod_decl(src, "a").unwrap());
+        assert!(has_mod_decl(src, "b").unwrap());
+        assert!(has_mod_decl(src, "c").unwrap());
+        assert!(!has_mod_decl(src, "d").unwrap());
+        assert!(!has_mod_decl(src, "module").unwrap());
+    }
+
+    #[test]
+    fn inserts_after_last_declaration_once() {
+        let src = "//! crate docs\n\npub mod schema;\nmod tree;\n\npub use tree::*;\n";
+        let out = insert_mod_decl(src, "watch").unwrap().unwrap();
+        assert_eq!(
+            out,
+            "//! crate docs\n\npub mod schema;\nmod tree;\npub mod watch;\n\npub use tree::*;\n"
+        );
+        assert!(insert_mod_decl(&out, "watch").unwrap().is_none());
+    }
+
+    #[test]
+    fn ignores_modules_nested_in_other_items() {
+        let src = "pub mod schema;\n\n#[cfg(test)]\nmod tests {\n    mod fixtures;\n}\n";
+        assert!(!has_mod_decl(src, "fixtures").unwrap());
+        assert!(has_mod_decl(src, "tests").unwrap());
+        assert_eq!(
+            insert_mod_decl(src, "fixtures").unwrap().unwrap(),
+            "pub mod schema;\npub mod fixtures;\n\n#[cfg(test)]\nmod tests {\n    mod fixtures;\n}\n"
+        );
+        assert!(insert_mod_decl("mod broken {", "x").is_err());
+    }
+
+    #[test]
+    fn inserts_below_header_when_no_declarations() {
+        let src = "//! Module: button\n\npub fn add() {}\n";
+        assert_eq!(
+            insert_mod_decl(src, "model").unwrap().unwrap(),
+            "//! Module: button\n\npub mod model;\n\npub fn add() {}\n"
+        );
+        assert_eq!(insert_mod_decl("", "x").unwrap().unwrap(), "pub mod x;\n");
+    }
+}
@@ -0,0 +1,251 @@
+// ============================================================================
+// ⚙️  Forge IDE - Scaffolding
+// Description:
+//   Creating crates, module directories and module files. Module bodies are
+//   rendered from `templates/forge_ide/scaffold/*.mustache`; parent
+//   `lib.rs` / `mod.rs` and the workspace members list are edited in place.
+//   Every operation is idempotent: re-running only fills in what is missing.
+// ============================================================================
+
+use super::{ensure_mod_decl, ensure_workspace_member, ForgeWorkspace};
+use crate::cargo::cargo_bin;
+use anyhow::{bail, Context, Result};
+use serde::Serialize;
+use serde_json::json;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const MOD_DIR_TEMPLATE: &str = "forge_ide/scaffold/mod_dir.mustache";
+const MOD_FILE_TEMPLATE: &str = "forge_ide/scaffold/mod_file.mustache";
+
+/// `cargo new` flavour
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum CrateKind {
+    Lib,
+    Bin,
+}
+
+impl CrateKind {
+    fn flag(self) -> &'static str {
+        match self {
+            Self::Lib => "--lib",
+            Self::Bin => "--bin",
+        }
+    }
+}
+
+/// What a scaffolding call touched
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Scaffolded {
+    /// Files / directories that did not exist before
+    pub created: Vec<PathBuf>,
+    /// Existing files that were edited (declarations, members)
+    pub updated: Vec<PathBuf>,
+    /// Things that were already in place
+    pub unchanged: Vec<PathBuf>,
+}
+
+impl Scaffolded {
+    pub(super) fn record(&mut self, path: &Path, changed: bool, existed: bool) {
+        let bucket = match (changed, existed) {
+            (true, false) => &mut self.created,
+            (true, true) => &mut self.updated,
+            (false, _) => &mut self.unchanged,
+        };
+        bucket.push(path.to_path_buf());
+    }
+}
+
+/// An ASCII identifier usable as a module name: `syn` rejects keywords
+/// (`type`, `impl`, `self`, …) and a lone `_`.
+pub(super) fn is_rust_ident(name: &str) -> bool {
+    name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && syn::parse_str::<syn::Ident>(name).is_ok()
+}
+
+/// A single cargo package name segment: ASCII letters, digits, `-` and `_`,
+/// not starting with a digit or `-`. Keeps `crates/<name>` inside `crates/`.
+fn is_package_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+impl ForgeWorkspace {
+    /// `forge_create`: `cargo new` under `crates/` and register the member.
+    pub fn create_crate(&self, name: &str, kind: CrateKind) -> Result<Scaffolded> {
+        if !is_package_name(name) {
+            bail!("'{name}' is not a valid crate name");
+        }
+        let mut report = Scaffolded::default();
+        let dir = self.crate_dir(name);
+        if dir.exists() {
+            report.record(&dir, false, true);
+        } else {
+            let status = Command::new(cargo_bin())
+                .args(["new", "--vcs", "none", kind.flag()])
+                .arg(&dir)
+                .current_dir(self.root())
+                .status()
+                .context("run cargo new")?;
+            if !status.success() {
+                bail!("cargo new failed for {}", dir.display());
+            }
+            report.record(&dir, true, false);
+        }
+
+        let manifest = self.root().join("Cargo.toml");
+        let changed = ensure_workspace_member(&manifest, &format!("crates/{name}"))?;
+        report.record(&manifest, changed, true);
+        Ok(report)
+    }
+
+    /// `create_mod_dir`: `src/<name>/mod.rs` plus `pub mod <name>;` in the crate root.
+    pub fn create_mod_dir(&self, crate_name: &str, name: &str) -> Result<Scaffolded> {
+        if !is_rust_ident(name) {
+            bail!("'{name}' is not a valid module name");
+        }
+        let src = self.crate_dir(crate_name).join("src");
+        let root_file = crate_root_file(&src)?;
+        let mut report = Scaffolded::default();
+
+        let mod_rs = src.join(name).join("mod.rs");
+        let ctx = json!({
+            "header": { "template": MOD_DIR_TEMPLATE },
+            "payload": { "crate": crate_name, "name": name },
+        });
+        let created = self.write_rendered(&mod_rs, MOD_DIR_TEMPLATE, &ctx)?;
+        report.record(&mod_rs, created, !created);
+
+        let changed = ensure_mod_decl(&root_file, name)?;
+        report.record(&root_file, changed, true);
+        Ok(report)
+    }
+
+    /// `create_mod_file`: `src/<dir>/<name>.rs` plus `pub mod <name>;` in `<dir>/mod.rs`.
+    /// `dir` may be nested (`button/parts`).
+    pub fn create_mod_file(&self, crate_name: &str, dir: &str, name: &str) -> Result<Scaffolded> {
+        if !is_rust_ident(name) {
+            bail!("'{name}' is not a valid module name");
+        }
+        let dir = dir.trim_matches('/');
+        let parent = self.crate_dir(crate_name).join("src").join(dir);
+        let parent_mod = parent.join("mod.rs");
+        if !parent_mod.is_file() {
+            bail!(
+                "{} not found; create the module directory first (`forge-ide mod-dir {dir}`)",
+                parent_mod.display()
+            );
+        }
+        let mut report = Scaffolded::default();
+
+        let file = parent.join(format!("{name}.rs"));
+        let ctx = json!({
+            "header": { "template": MOD_FILE_TEMPLATE },
+            "payload": { "crate": crate_name, "parent": dir.replace('/', "::"), "name": name },
+        });
+        let created = self.write_rendered(&file, MOD_FILE_TEMPLATE, &ctx)?;
+        report.record(&file, created, !created);
+
+        let changed = ensure_mod_decl(&parent_mod, name)?;
+        report.record(&parent_mod, changed, true);
+        Ok(report)
+    }
+
+    /// Render `template` into `path` unless the file already exists.
+    pub(super) fn write_rendered(&self, path: &Path, template: &str, ctx: &serde_json::Value) -> Result<bool> {
+        if path.exists() {
+            return Ok(false);
+        }
+        let body = forge_template::render_value_from_abs(ctx, self.template(template))
+            .with_context(|| format!("render {template}"))?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).with_context(|| format!("create {}", dir.display()))?;
+        }
+        fs::write(path, body).with_context(|| format!("write {}", path.display()))?;
+        Ok(true)
+    }
+}
+
+/// `lib.rs`, falling back to `main.rs` for binary crates.
+pub(super) fn crate_root_file(src: &Path) -> Result<PathBuf> {
+    ["lib.rs", "main.rs"]
+        .iter()
+        .map(|f| src.join(f))
+        .find(|p| p.is_file())
+        .with_context(|| format!("no lib.rs or main.rs in {}", src.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn workspace() -> (tempfile::TempDir, ForgeWorkspace) {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("crates/demo/src")).unwrap();
+        fs::write(root.join("crates/demo/src/lib.rs"), "pub fn add() {}\n").unwrap();
+        let tpl = root.join("templates/forge_ide/scaffold");
+        fs::create_dir_all(&tpl).unwrap();
+        fs::write(
+            tpl.join("mod_dir.mustache"),
+            "//! {{payload.name_PascalCase}}\n",
+        )
+        .unwrap();
+        fs::write(
+            tpl.join("mod_file.mustache"),
+            "//! {{payload.parent}}::{{payload.name}}\n",
+        )
+        .unwrap();
+        let ws = ForgeWorkspace::new(root);
+        (dir, ws)
+    }
+
+    #[test]
+    fn mod_dir_and_file_are_rendered_and_declared_idempotently() {
+        let (dir, ws) = workspace();
+        let src = dir.path().join("crates/demo/src");
+
+        let first = ws.create_mod_dir("demo", "text_input").unwrap();
+        assert_eq!(first.created, [src.join("text_input/mod.rs")]);
+        assert_eq!(
+            fs::read_to_string(src.join("text_input/mod.rs")).unwrap(),
+            "//! TextInput\n"
+        );
+        ws.create_mod_file("demo", "text_input", "model").unwrap();
+        assert_eq!(
+            fs::read_to_string(src.join("text_input/model.rs")).unwrap(),
+            "//! text_input::model\n"
+        );
+
+        let again = ws.create_mod_dir("demo", "text_input").unwrap();
+        assert!(again.created.is_empty() && again.updated.is_empty());
+        ws.create_mod_file("demo", "text_input", "model").unwrap();
+
+        let lib = fs::read_to_string(src.join("lib.rs")).unwrap();
+        assert_eq!(lib.matches("pub mod text_input;").count(), 1);
+        let mod_rs = fs::read_to_string(src.join("text_input/mod.rs")).unwrap();
+        assert_eq!(mod_rs.matches("pub mod model;").count(), 1);
+
+        assert!(ws.create_mod_file("demo", "missing", "x").is_err());
+        assert!(ws.create_mod_dir("demo", "not-valid").is_err());
+        assert!(ws.create_mod_dir("demo", "type").is_err());
+        assert!(ws.create_mod_file("demo", "text_input", "self").is_err());
+        assert!(!src.join("type").exists());
+    }
+
+    #[test]
+    fn crate_names_must_stay_inside_crates_dir() {
+        let (dir, ws) = workspace();
+        for name in ["../x", "a/b", "", ".", "-x", "1x"] {
+            assert!(ws.create_crate(name, CrateKind::Lib).is_err(), "{name:?}");
+        }
+        assert!(!dir.path().join("x").exists());
+        assert!(is_package_name("forge-web_ui2"));
+    }
+}
@@ -0,0 +1,38 @@
+// ============================================================================
+// ⚙️  Forge IDE - Tree View
+// Description:
+//   Plain-text rendering of a `TreeNode` (the `tree_view` zsh helper), using
+//   the same ignore rules as the live workbench tree.
+// ============================================================================
+
+use crate::tree::TreeNode;
+
+/// Scratch directories hidden from the text view on top of the ignore rules
+const SCRATCH_DIRS: &[&str] = &["out", "tmp", "temp", "node_modules"];
+
+/// Render `node` as an indented tree with box-drawing connectors.
+pub fn render_tree(node: &TreeNode) -> String {
+    let mut out = String::from(".\n");
+    render_children(node, "", &mut out);
+    out
+}
+
+fn render_children(node: &TreeNode, indent: &str, out: &mut String) {
+    let visible: Vec<&TreeNode> = node
+        .children
+        .iter()
+        .filter(|c| !(c.is_dir && SCRATCH_DIRS.contains(&c.name.as_str())))
+        .collect();
+    for (i, child) in visible.iter().enumerate() {
+        let last = i + 1 == visible.len();
+        out.push_str(indent);
+        out.push_str(if last { "└── " } else { "├── " });
+        out.push_str(&child.name);
+        if child.is_dir {
+            out.push('/');
+        }
+        out.push('\n');
+        let deeper = format!("{indent}{}", if last { "    " } else { "│   " });
+        render_children(child, &deeper, out);
+    }
+}
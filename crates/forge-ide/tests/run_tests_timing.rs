@@ -0,0 +1,41 @@
+// ============================================================================
+// Forge Integration Test
+// File: tests/run_tests_timing.rs
+// Crate: forge-ide
+// Description: Runs a real nested `cargo test` over a scratch crate with
+//              `timed` set and checks per-test durations. Compiles a crate
+//              and measures wall-clock time, so it is ignored by default:
+//              `cargo test -p forge-ide --test run_tests_timing -- --ignored`.
+// ============================================================================
+
+use forge_ide::commands::RunTestsCommand;
+use forge_ide::terminal::TerminalSlice;
+
+#[test]
+#[ignore = "runs a nested cargo build and checks wall-clock timings"]
+fn timed_runs_measure_each_test() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("Cargo.toml"),
+        "[package]\nname = \"timed\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[workspace]\n",
+    )
+    .unwrap();
+    std::fs::create_dir(dir.path().join("src")).unwrap();
+    std::fs::write(
+        dir.path().join("src/lib.rs"),
+        "#[test]\nfn slow() { std::thread::sleep(std::time::Duration::from_millis(300)); }\n\
+         #[test]\nfn quick() {}\n",
+    )
+    .unwrap();
+
+    let cmd = RunTestsCommand::new(dir.path(), TerminalSlice::default());
+    let summary = cmd.run(None, None, false, true).unwrap();
+    assert!(summary.success, "{}", summary.headline());
+
+    let duration = |name: &str| {
+        let case = summary.tests.iter().find(|t| t.name == name).unwrap();
+        case.duration_ms.unwrap_or_else(|| panic!("no duration for {name}"))
+    };
+    assert!(duration("slow") >= 300, "slow took {} ms", duration("slow"));
+    assert!(duration("quick") < duration("slow"));
+}
@@ -1,57 +1,257 @@
-use proc_macro::{TokenStream, TokenTree};
-
-/// Parse exactly two string literal arguments separated by a comma:
-///   render_yaml!("/abs/template.mustache", "/abs/values.yaml")
-/// or relative paths:
-///   render_yaml!("resources/templates/thing.mustache", "resources/values/thing.yaml")
-pub(crate) fn two_string_args(input: TokenStream) -> Result<(String, String), String> {
-    let mut literals: Vec<String> = Vec::new();
-
-    for tt in input.into_iter() {
-        match tt {
-            TokenTree::Literal(l) => literals.push(l.to_string()),
-            TokenTree::Punct(p) if p.as_char() == ',' => {},
+use proc_macro2::Span;
+use serde_json::{Map, Number, Value};
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::{braced, bracketed, token, Ident, Lit, LitBool, LitStr, Token};
+
+const USAGE: &str = "usage: render_yaml!(\"<template>\", \"<yaml>\") or \
+                     render_yaml!(template = \"..\" | template_str = \"..\", \
+                     values = \"..\" | values = { .. } | yaml = \"..\", strict = bool)";
+
+/// Where `render_yaml!` takes its template from
+pub(crate) enum TemplateArg {
+    /// `template = "path"` (or the first positional argument)
+    Path(LitStr),
+    /// `template_str = "{{...}}"`
+    Inline(LitStr),
+}
+
+/// Where `render_yaml!` takes its values from
+pub(crate) enum ValuesArg {
+    /// `values = "path.yaml"` (or the second positional argument)
+    Path(LitStr),
+    /// `yaml = "payload: ..."`
+    Yaml(LitStr),
+    /// `values = { payload: { name: "widget" } }`
+    Literal(Value, Span),
+}
+
+/// Parsed `render_yaml!` arguments, either positional:
+///   render_yaml!("<template>", "<yaml>")
+/// or named (any order, trailing comma allowed):
+///   render_yaml!(template = "<path>", values = "<path>", strict = true)
+pub(crate) struct RenderArgs {
+    /// Optional: `header.backend: rust_ast` values need no template
+    pub template: Option<TemplateArg>,
+    pub values: ValuesArg,
+    pub strict: bool,
+}
+
+impl RenderArgs {
+    /// Token that render failures are reported on.
+    pub(crate) fn span(&self) -> Span {
+        match (&self.template, &self.values) {
+            (Some(TemplateArg::Path(lit) | TemplateArg::Inline(lit)), _) => lit.span(),
+            (None, ValuesArg::Path(lit) | ValuesArg::Yaml(lit)) => lit.span(),
+            (None, ValuesArg::Literal(_, span)) => *span,
+        }
+    }
+}
+
+impl Parse for RenderArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.is_empty() {
+            return Err(syn::Error::new(Span::call_site(), format!("missing arguments; {USAGE}")));
+        }
+        if input.peek(LitStr) {
+            parse_positional(input)
+        } else {
+            parse_named(input)
+        }
+    }
+}
+
+fn parse_positional(input: ParseStream) -> syn::Result<RenderArgs> {
+    let template: LitStr = input.parse()?;
+    if input.is_empty() {
+        return Err(syn::Error::new(
+            template.span(),
+            "only one argument found; expected a template and a values path",
+        ));
+    }
+    input.parse::<Token![,]>()?;
+    let values: LitStr = input.parse()?;
+    if input.peek(Token![,]) {
+        input.parse::<Token![,]>()?;
+    }
+    if !input.is_empty() {
+        return Err(input.error("too many arguments; expected exactly two string literals"));
+    }
+    Ok(RenderArgs {
+        template: Some(TemplateArg::Path(non_empty(template)?)),
+        values: ValuesArg::Path(non_empty(values)?),
+        strict: false,
+    })
+}
+
+fn parse_named(input: ParseStream) -> syn::Result<RenderArgs> {
+    let mut template: Option<(Ident, TemplateArg)> = None;
+    let mut values: Option<(Ident, ValuesArg)> = None;
+    let mut strict: Option<LitBool> = None;
+
+    while !input.is_empty() {
+        let key = Ident::parse_any(input)?;
+        input.parse::<Token![=]>()?;
+        match key.to_string().as_str() {
+            "template" | "template_str" => {
+                if let Some((prev, _)) = &template {
+                    return Err(conflict(&key, prev));
+                }
+                let lit = non_empty(input.parse()?)?;
+                let arg = if key == "template" {
+                    TemplateArg::Path(lit)
+                } else {
+                    TemplateArg::Inline(lit)
+                };
+                template = Some((key, arg));
+            }
+            "values" | "yaml" => {
+                if let Some((prev, _)) = &values {
+                    return Err(conflict(&key, prev));
+                }
+                let arg = if key == "yaml" {
+                    ValuesArg::Yaml(input.parse()?)
+                } else if input.peek(LitStr) {
+                    ValuesArg::Path(non_empty(input.parse()?)?)
+                } else if input.peek(token::Brace) {
+                    let span = input.span();
+                    ValuesArg::Literal(parse_value(input)?, span)
+                } else {
+                    return Err(input.error("expected a values path (string) or a `{ key: value }` literal"));
+                };
+                values = Some((key, arg));
+            }
+            "strict" => {
+                if strict.is_some() {
+                    return Err(syn::Error::new(key.span(), "duplicate argument `strict`"));
+                }
+                strict = Some(input.parse()?);
+            }
             other => {
-                return Err(format!(
-                    "expected two string literal arguments separated by a comma, got `{other}`"
+                return Err(syn::Error::new(
+                    key.span(),
+                    format!("unknown argument `{other}`; expected template, template_str, values, yaml or strict"),
                 ))
             }
         }
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
     }
 
-    match literals.len() {
-        2 => Ok((
-            validate_and_extract(&literals[0])?,
-            validate_and_extract(&literals[1])?,
-        )),
-        0 => Err("missing arguments; usage: render_yaml!(\"<template>\", \"<yaml>\")".into()),
-        1 => Err("only one argument found; expected two string literals".into()),
-        _ => Err("too many arguments; expected exactly two string literals".into()),
-    }
+    let Some((_, values)) = values else {
+        return Err(syn::Error::new(Span::call_site(), format!("missing `values` or `yaml`; {USAGE}")));
+    };
+    Ok(RenderArgs {
+        template: template.map(|(_, t)| t),
+        values,
+        strict: strict.is_some_and(|b| b.value),
+    })
 }
 
-// ------------------ small helpers ------------------
-
-#[inline]
-fn validate_and_extract(lit_raw: &str) -> Result<String, String> {
-    ensure_is_quoted_string(lit_raw)?;
-    let val = strip_quotes_and_trim(lit_raw);
-    ensure_not_empty(&val)?;
-    Ok(val)
+fn conflict(key: &Ident, prev: &Ident) -> syn::Error {
+    let message = if key == prev {
+        format!("duplicate argument `{key}`")
+    } else {
+        format!("`{key}` conflicts with `{prev}`; give only one")
+    };
+    syn::Error::new(key.span(), message)
 }
 
-fn ensure_is_quoted_string(lit_text: &str) -> Result<(), String> {
-    if lit_text.starts_with('"') && lit_text.ends_with('"') {
-        Ok(())
+fn non_empty(lit: LitStr) -> syn::Result<LitStr> {
+    if lit.value().trim().is_empty() {
+        Err(syn::Error::new(lit.span(), "argument cannot be empty"))
     } else {
-        Err("argument must be a quoted string literal".into())
+        Ok(lit)
     }
 }
 
-fn strip_quotes_and_trim(lit_text: &str) -> String {
-    lit_text.trim_matches('"').trim().to_string()
+// ------------------ struct-literal values ------------------
+
+/// `{ key: value, .. }`, `[a, b]`, string / number / bool literals.
+/// Keys may be identifiers (keywords allowed, e.g. `type`) or strings.
+fn parse_value(input: ParseStream) -> syn::Result<Value> {
+    if input.peek(token::Brace) {
+        let content;
+        braced!(content in input);
+        let mut map = Map::new();
+        while !content.is_empty() {
+            let key = if content.peek(LitStr) {
+                content.parse::<LitStr>()?.value()
+            } else {
+                Ident::parse_any(&content)?.unraw().to_string()
+            };
+            content.parse::<Token![:]>()?;
+            map.insert(key, parse_value(&content)?);
+            if !content.is_empty() {
+                content.parse::<Token![,]>()?;
+            }
+        }
+        return Ok(Value::Object(map));
+    }
+    if input.peek(token::Bracket) {
+        let content;
+        bracketed!(content in input);
+        let mut items = Vec::new();
+        while !content.is_empty() {
+            items.push(parse_value(&content)?);
+            if !content.is_empty() {
+                content.parse::<Token![,]>()?;
+            }
+        }
+        return Ok(Value::Array(items));
+    }
+    let negative = input.peek(Token![-]);
+    if negative {
+        input.parse::<Token![-]>()?;
+    }
+    let lit: Lit = input
+        .parse()
+        .map_err(|e| syn::Error::new(e.span(), "expected a string, number, bool, `[..]` or `{ .. }`"))?;
+    let number = |n: Option<Number>| n.map(Value::Number).ok_or_else(|| syn::Error::new(lit.span(), "number out of range"));
+    match &lit {
+        Lit::Str(s) if !negative => Ok(Value::String(s.value())),
+        Lit::Bool(b) if !negative => Ok(Value::Bool(b.value)),
+        Lit::Int(i) => {
+            let n: i64 = i.base10_parse()?;
+            number(Some(Number::from(if negative { -n } else { n })))
+        }
+        Lit::Float(f) => {
+            let n: f64 = f.base10_parse()?;
+            number(Number::from_f64(if negative { -n } else { n }))
+        }
+        _ => Err(syn::Error::new(lit.span(), "unsupported literal in values")),
+    }
 }
 
-fn ensure_not_empty(s: &str) -> Result<(), String> {
-    if s.is_empty() { Err("argument cannot be empty".into()) } else { Ok(()) }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn named_args_with_struct_literal_values() {
+        let args: RenderArgs = syn::parse_str(
+            r#"template = "t.mustache", values = { name: "widget", type: "u8", tags: ["a", "b"], size: -2, pub: true }, strict = true"#,
+        )
+        .unwrap();
+        assert!(matches!(args.template, Some(TemplateArg::Path(ref l)) if l.value() == "t.mustache"));
+        assert!(args.strict);
+        let ValuesArg::Literal(values, _) = args.values else { panic!("expected literal values") };
+        assert_eq!(values, json!({ "name": "widget", "type": "u8", "tags": ["a", "b"], "size": -2, "pub": true }));
+
+        let positional: RenderArgs = syn::parse_str(r#""t.mustache", "v.yaml""#).unwrap();
+        assert!(matches!(positional.values, ValuesArg::Path(ref l) if l.value() == "v.yaml"));
+    }
+
+    #[test]
+    fn argument_errors_name_the_problem() {
+        let err = |src: &str| syn::parse_str::<RenderArgs>(src).err().map(|e| e.to_string()).unwrap_or_default();
+        assert!(err(r#"tempalte = "x", values = "v""#).contains("unknown argument `tempalte`"));
+        assert!(err(r#"template = "x", template_str = "y", values = "v""#).contains("conflicts with `template`"));
+        assert!(err(r#"template = "x""#).contains("missing `values`"));
+        assert!(err(r#"values = { a: foo }"#).contains("expected a string"));
+        assert!(err(r#""a", "b", "c""#).contains("too many arguments"));
+        assert!(err(r#""""#).contains("only one argument"));
+    }
 }
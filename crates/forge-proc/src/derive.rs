@@ -0,0 +1,292 @@
+use crate::args::TemplateArg;
+use crate::templates::render;
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use serde_json::{json, Map, Value};
+use syn::spanned::Spanned;
+use syn::{Attribute, Data, DeriveInput, Expr, Fields, Lit, LitBool, LitStr, Meta, Visibility};
+
+/// Macro expander for `#[derive(ForgeTemplate)]`.
+/// Every `#[forge(template = "..")]` attribute renders its template with the
+/// annotated type's definition as `payload` (see [`type_payload`]) and the
+/// output is emitted right after the type.
+pub(crate) fn expand_forge_template(input: proc_macro::TokenStream) -> syn::Result<TokenStream> {
+    let input: DeriveInput = syn::parse(input)?;
+    let jobs = forge_attrs(&input)?;
+    if jobs.is_empty() {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "`#[derive(ForgeTemplate)]` needs at least one `#[forge(template = \"..\")]` attribute",
+        ));
+    }
+
+    let raw = json!({ "payload": type_payload(&input)? });
+    let mut out = TokenStream::new();
+    for job in jobs {
+        out.extend(render(&raw, Some(&job.template), job.strict, Vec::new(), job.span)?);
+    }
+    Ok(out)
+}
+
+/// One `#[forge(..)]` attribute
+struct Job {
+    template: TemplateArg,
+    strict: bool,
+    span: proc_macro2::Span,
+}
+
+/// `#[forge(template = "path" | template_str = "..", strict = bool)]`
+fn forge_attrs(input: &DeriveInput) -> syn::Result<Vec<Job>> {
+    let mut jobs = Vec::new();
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("forge")) {
+        let mut template = None;
+        let mut strict = false;
+        attr.parse_nested_meta(|meta| {
+            let key = if meta.path.is_ident("template") {
+                "template"
+            } else if meta.path.is_ident("template_str") {
+                "template_str"
+            } else if meta.path.is_ident("strict") {
+                strict = meta.value()?.parse::<LitBool>()?.value;
+                return Ok(());
+            } else {
+                return Err(meta.error("unknown forge argument; expected template, template_str or strict"));
+            };
+            if template.is_some() {
+                return Err(meta.error("give only one of `template` / `template_str` per #[forge(..)]"));
+            }
+            let lit: LitStr = meta.value()?.parse()?;
+            if lit.value().trim().is_empty() {
+                return Err(syn::Error::new(lit.span(), "argument cannot be empty"));
+            }
+            template = Some(if key == "template" {
+                TemplateArg::Path(lit)
+            } else {
+                TemplateArg::Inline(lit)
+            });
+            Ok(())
+        })?;
+        let Some(template) = template else {
+            return Err(syn::Error::new(attr.span(), "missing `template = \"..\"` or `template_str = \"..\"`"));
+        };
+        jobs.push(Job {
+            template,
+            strict,
+            span: attr.span(),
+        });
+    }
+    Ok(jobs)
+}
+
+// ------------------ type definition → payload ------------------
+
+/// The annotated type as template values:
+///   name, kind (`struct` / `enum`), is_struct / is_enum, vis, desc, doc_lines,
+///   attrs, derives, impl_generics / ty_generics / where_clause,
+///   fields (structs) or variants (enums, each with their own fields).
+/// Fields carry name, type, vis, desc, doc_lines, attrs and index; tuple
+/// fields are named by position. Naming variants (`name_snake_case`, ..) are
+/// added by the usual context expansion.
+pub(crate) fn type_payload(input: &DeriveInput) -> syn::Result<Value> {
+    let mut payload = Map::new();
+    payload.insert("name".into(), json!(input.ident.to_string()));
+    payload.insert("vis".into(), json!(vis_string(&input.vis)));
+    insert_attrs(&mut payload, &input.attrs);
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    payload.insert("impl_generics".into(), json!(tidy(impl_generics.to_token_stream())));
+    payload.insert("ty_generics".into(), json!(tidy(ty_generics.to_token_stream())));
+    payload.insert("where_clause".into(), json!(tidy(where_clause.to_token_stream())));
+
+    match &input.data {
+        Data::Struct(data) => {
+            payload.insert("kind".into(), json!("struct"));
+            payload.insert("is_struct".into(), json!(true));
+            insert_fields(&mut payload, &data.fields);
+        }
+        Data::Enum(data) => {
+            payload.insert("kind".into(), json!("enum"));
+            payload.insert("is_enum".into(), json!(true));
+            let variants = data
+                .variants
+                .iter()
+                .map(|v| {
+                    let mut variant = Map::new();
+                    variant.insert("name".into(), json!(v.ident.to_string()));
+                    insert_attrs(&mut variant, &v.attrs);
+                    insert_fields(&mut variant, &v.fields);
+                    if let Some((_, discriminant)) = &v.discriminant {
+                        variant.insert("discriminant".into(), json!(tidy(discriminant.to_token_stream())));
+                    }
+                    Value::Object(variant)
+                })
+                .collect();
+            payload.insert("variants".into(), Value::Array(variants));
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new(
+                data.union_token.span,
+                "`#[derive(ForgeTemplate)]` supports structs and enums, not unions",
+            ))
+        }
+    }
+    Ok(Value::Object(payload))
+}
+
+/// `fields` plus `is_unit` / `is_tuple` for the shape.
+fn insert_fields(target: &mut Map<String, Value>, fields: &Fields) {
+    let items = fields
+        .iter()
+        .enumerate()
+        .map(|(index, f)| {
+            let mut field = Map::new();
+            let name = f.ident.as_ref().map_or_else(|| index.to_string(), |i| i.to_string());
+            field.insert("name".into(), json!(name));
+            field.insert("type".into(), json!(tidy(f.ty.to_token_stream())));
+            field.insert("vis".into(), json!(vis_string(&f.vis)));
+            field.insert("index".into(), json!(index));
+            insert_attrs(&mut field, &f.attrs);
+            Value::Object(field)
+        })
+        .collect();
+    target.insert("fields".into(), Value::Array(items));
+    target.insert("is_unit".into(), json!(matches!(fields, Fields::Unit)));
+    target.insert("is_tuple".into(), json!(matches!(fields, Fields::Unnamed(_))));
+}
+
+/// `desc` / `doc_lines` from `///` comments, `derives`, and every other
+/// attribute except `forge` as source text in `attrs` (`serde(default)`).
+fn insert_attrs(target: &mut Map<String, Value>, attrs: &[Attribute]) {
+    let mut doc_lines = Vec::new();
+    let mut derives = Vec::new();
+    let mut rest = Vec::new();
+    for attr in attrs {
+        match &attr.meta {
+            Meta::NameValue(nv) if nv.path.is_ident("doc") => {
+                if let Expr::Lit(syn::ExprLit { lit: Lit::Str(s), .. }) = &nv.value {
+                    let line = s.value();
+                    doc_lines.push(line.strip_prefix(' ').unwrap_or(&line).to_string());
+                }
+            }
+            Meta::List(list) if list.path.is_ident("derive") => {
+                derives.extend(list.tokens.to_string().split(',').map(|d| tidy_str(d.trim())).filter(|d| !d.is_empty()));
+            }
+            _ if attr.path().is_ident("forge") => {}
+            meta => rest.push(tidy(meta.to_token_stream())),
+        }
+    }
+    target.insert("desc".into(), json!(doc_lines.join("\n")));
+    target.insert("doc_lines".into(), json!(doc_lines));
+    target.insert("derives".into(), json!(derives));
+    target.insert("attrs".into(), json!(rest));
+}
+
+fn vis_string(vis: &Visibility) -> String {
+    tidy(vis.to_token_stream())
+}
+
+/// Source-like text for a token stream: `Option < Vec < u8 > >` → `Option<Vec<u8>>`.
+fn tidy(tokens: TokenStream) -> String {
+    tidy_str(&tokens.to_string())
+}
+
+fn tidy_str(s: &str) -> String {
+    let mut out = s.to_string();
+    for (from, to) in [
+        (" :: ", "::"),
+        (":: ", "::"),
+        (" ::", "::"),
+        ("< ", "<"),
+        (" <", "<"),
+        (" >", ">"),
+        (" ,", ","),
+        ("& ", "&"),
+        ("( ", "("),
+        (" )", ")"),
+        ("[ ", "["),
+        (" ]", "]"),
+        (" ;", ";"),
+        (" : ", ": "),
+        ("# ", "#"),
+    ] {
+        out = out.replace(from, to);
+    }
+    // `serde (default)` → `serde(default)`
+    let mut tidied = String::with_capacity(out.len());
+    let mut chars = out.chars().peekable();
+    while let Some(c) = chars.next() {
+        let before = tidied.chars().last();
+        if c == ' ' && chars.peek() == Some(&'(') && before.is_some_and(|b| b.is_alphanumeric() || b == '_') {
+            continue;
+        }
+        tidied.push(c);
+    }
+    tidied
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn struct_and_enum_definitions_become_payload() {
+        let input: DeriveInput = syn::parse_str(
+            r#"
+            /// A widget.
+            /// Second line.
+            #[derive(Debug, Clone)]
+            #[forge(template = "x.mustache")]
+            #[serde(rename_all = "camelCase")]
+            pub struct Widget<'a, T: Clone> where T: Default {
+                /// Display label
+                #[serde(default)]
+                pub label: Option<&'a str>,
+                items: Vec<std::collections::HashMap<String, T>>,
+            }
+            "#,
+        )
+        .unwrap();
+        let payload = type_payload(&input).unwrap();
+        assert_eq!(payload["name"], "Widget");
+        assert_eq!(payload["kind"], "struct");
+        assert_eq!(payload["vis"], "pub");
+        assert_eq!(payload["desc"], "A widget.\nSecond line.");
+        assert_eq!(payload["derives"], json!(["Debug", "Clone"]));
+        assert_eq!(payload["attrs"], json!(["serde(rename_all = \"camelCase\")"]));
+        assert_eq!(payload["impl_generics"], "<'a, T: Clone>");
+        assert_eq!(payload["ty_generics"], "<'a, T>");
+        assert_eq!(payload["where_clause"], "where T: Default");
+        assert_eq!(payload["is_tuple"], false);
+
+        let fields = &payload["fields"];
+        assert_eq!(fields[0]["name"], "label");
+        assert_eq!(fields[0]["type"], "Option<&'a str>");
+        assert_eq!(fields[0]["desc"], "Display label");
+        assert_eq!(fields[0]["attrs"], json!(["serde(default)"]));
+        assert_eq!(fields[1]["type"], "Vec<std::collections::HashMap<String, T>>");
+        assert_eq!(fields[1]["vis"], "");
+
+        let input: DeriveInput = syn::parse_str("enum Kind { A, B(u8, String), C { id: u32 } = 3 }").unwrap();
+        let payload = type_payload(&input).unwrap();
+        assert_eq!(payload["is_enum"], true);
+        let variants = &payload["variants"];
+        assert_eq!(variants[0]["is_unit"], true);
+        assert_eq!(variants[1]["is_tuple"], true);
+        assert_eq!(variants[1]["fields"][1]["name"], "1");
+        assert_eq!(variants[1]["fields"][1]["type"], "String");
+        assert_eq!(variants[2]["fields"][0]["name"], "id");
+        assert_eq!(variants[2]["discriminant"], "3");
+    }
+
+    #[test]
+    fn forge_attribute_errors() {
+        let err = |src: &str| {
+            let input: DeriveInput = syn::parse_str(src).unwrap();
+            forge_attrs(&input).err().map(|e| e.to_string()).unwrap_or_default()
+        };
+        assert!(err(r#"#[forge(tempalte = "x")] struct A;"#).contains("unknown forge argument"));
+        assert!(err(r#"#[forge(strict = true)] struct A;"#).contains("missing `template"));
+        assert!(err(r#"#[forge(template = "x", template_str = "y")] struct A;"#).contains("only one"));
+        assert!(err(r#"#[forge(template = "x", strict = true)] struct A;"#).is_empty());
+    }
+}
@@ -1,9 +1,85 @@
 // forge-proc/src/env.rs
 use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
 
-/// Fetch the repo root (from FORGE_ROOT env var).
-/// Fails with a clear error message if not set.
-pub(crate) fn forge_root() -> Result<String, String> {
-    env::var("FORGE_ROOT")
-        .map_err(|_| "FORGE_ROOT must be set (e.g., /Users/you/forge-oss)".to_string())
+/// Directories relative macro paths are resolved against, in order:
+/// 1. `FORGE_ROOT`, when set (explicit override)
+/// 2. `CARGO_MANIFEST_DIR` of the crate invoking the macro
+/// 3. its workspace root (nearest ancestor whose Cargo.toml has `[workspace]`)
+pub(crate) fn search_roots() -> Vec<PathBuf> {
+    let mut roots = Vec::new();
+    if let Some(root) = env::var_os("FORGE_ROOT").filter(|v| !v.is_empty()) {
+        roots.push(PathBuf::from(root));
+    }
+    if let Some(manifest_dir) = env::var_os("CARGO_MANIFEST_DIR").map(PathBuf::from) {
+        let ws = workspace_root(&manifest_dir);
+        roots.push(manifest_dir);
+        roots.extend(ws);
+    }
+    roots.dedup();
+    roots
+}
+
+/// Resolve `path_in`: absolute paths are used as-is, relative ones against the
+/// first root where the file exists. The error lists every directory tried.
+pub(crate) fn resolve(path_in: &str, roots: &[PathBuf]) -> Result<PathBuf, String> {
+    let path = Path::new(path_in);
+    if path.is_absolute() {
+        return Ok(path.to_path_buf());
+    }
+    if let Some(found) = roots.iter().map(|r| r.join(path)).find(|p| p.is_file()) {
+        return Ok(found);
+    }
+    let tried: Vec<String> = roots.iter().map(|r| r.display().to_string()).collect();
+    Err(if tried.is_empty() {
+        format!("cannot resolve `{path_in}`: no CARGO_MANIFEST_DIR or FORGE_ROOT to search")
+    } else {
+        format!("cannot find `{path_in}`; tried: {}", tried.join(", "))
+    })
+}
+
+/// Nearest ancestor of `start` (inclusive) whose Cargo.toml declares `[workspace]`.
+pub(crate) fn workspace_root(start: &Path) -> Option<PathBuf> {
+    start.ancestors().find_map(|dir| {
+        let manifest = fs::read_to_string(dir.join("Cargo.toml")).ok()?;
+        manifest
+            .lines()
+            .any(|l| l.trim() == "[workspace]")
+            .then(|| dir.to_path_buf())
+    })
+}
+
+/// True when `FORGE_PROC_VERBOSE` is set to anything but `""` / `0`.
+/// Gates the per-expansion diagnostics on stderr.
+pub(crate) fn verbose() -> bool {
+    env::var("FORGE_PROC_VERBOSE").is_ok_and(|v| !v.is_empty() && v != "0")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_against_crate_then_workspace_and_lists_tried_dirs() {
+        let tmp = tempfile::tempdir().unwrap();
+        let ws = tmp.path().to_path_buf();
+        let krate = ws.join("crates/app");
+        fs::create_dir_all(krate.join("tpl")).unwrap();
+        fs::create_dir_all(ws.join("templates")).unwrap();
+        fs::write(ws.join("Cargo.toml"), "[workspace]\nmembers = [\"crates/app\"]\n").unwrap();
+        fs::write(krate.join("Cargo.toml"), "[package]\nname = \"app\"\n").unwrap();
+        fs::write(krate.join("tpl/a.mustache"), "").unwrap();
+        fs::write(ws.join("templates/b.mustache"), "").unwrap();
+
+        assert_eq!(workspace_root(&krate), Some(ws.clone()));
+        let roots = [krate.clone(), ws.clone()];
+        assert_eq!(resolve("tpl/a.mustache", &roots).unwrap(), krate.join("tpl/a.mustache"));
+        assert_eq!(resolve("templates/b.mustache", &roots).unwrap(), ws.join("templates/b.mustache"));
+        assert_eq!(resolve("/abs/c.yaml", &roots).unwrap(), PathBuf::from("/abs/c.yaml"));
+
+        let err = resolve("nope.yaml", &roots).unwrap_err();
+        assert!(err.contains(&krate.display().to_string()), "{err}");
+        assert!(err.contains(&ws.display().to_string()), "{err}");
+    }
 }
@@ -1,17 +1,57 @@
 use proc_macro::TokenStream;
 
 mod args;
+mod derive;
 mod env;
 mod templates;
 
 /// Render code from YAML + Mustache, given two string args:
 ///   render_yaml!("<template.rel.or.abs>", "<yaml.rel.or.abs>")
+/// or named arguments (any order):
+///   render_yaml!(template = "<path>", values = "<path>", strict = true)
+///   render_yaml!(template_str = "pub struct {{payload.name_PascalCase}};", values = { name: "widget" })
+///   render_yaml!(template = "<path>", yaml = "payload: { name: widget }")
 ///
+/// - Inline values (`values = { .. }`, `yaml = ".."`) without `header` /
+///   `payload` keys are used as the payload.
+/// - `strict = true` fails on template variables missing from the values.
+/// - Argument and render errors point at the offending token.
 /// - If an arg is absolute, we use it as-is.
-/// - If an arg is relative, we prefix it with FORGE_ROOT.
+/// - If an arg is relative, it is looked up under `FORGE_ROOT` (optional
+///   override), then the calling crate's `CARGO_MANIFEST_DIR`, then its
+///   workspace root; the error lists every directory tried.
+/// - With `header.backend: rust_ast` the YAML's `payload.ast` is emitted
+///   directly as tokens and the template argument is not read.
+/// - Every file read is tracked (`include_bytes!`), so editing the YAML,
+///   template or a partial rebuilds the caller; use the macro in item position.
+/// - Set `FORGE_PROC_VERBOSE=1` to log resolved paths at expansion time.
 #[proc_macro]
 pub fn render_yaml(input: TokenStream) -> TokenStream {
-    templates::expand_render_yaml(input).unwrap_or_else(|e| {
-        format!("compile_error!(\"{e}\");").parse().unwrap()
-    })
+    templates::expand_render_yaml(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Render templates from a type's own definition:
+///   #[derive(ForgeTemplate)]
+///   #[forge(template = "templates/builder.mustache")]
+///   pub struct Widget { pub label: String }
+///
+/// - The type becomes the `payload` (templates read `payload.name`,
+///   `{{#payload.fields}}`, ...): `name`, `kind` (`struct` / `enum`),
+///   `is_struct` / `is_enum`, `vis`, `desc` + `doc_lines`, `derives`, `attrs`,
+///   `impl_generics` / `ty_generics` / `where_clause`, and `fields` (or
+///   `variants`, each with `fields`). Fields have `name`, `type`, `vis`,
+///   `desc`, `doc_lines`, `attrs` and `index`; tuple fields are named `0`, `1`, ...
+/// - Naming variants are expanded as for YAML values (`name_snake_case`,
+///   `type_PascalCase`, ...).
+/// - `#[forge(..)]` takes `template = "<path>"` (resolved like `render_yaml!`)
+///   or `template_str = ".."`, plus optional `strict = true`; repeat the
+///   attribute to render several templates.
+/// - The rendered items are emitted next to the type.
+#[proc_macro_derive(ForgeTemplate, attributes(forge))]
+pub fn forge_template(input: TokenStream) -> TokenStream {
+    derive::expand_forge_template(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
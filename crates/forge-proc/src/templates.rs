@@ -1,40 +1,98 @@
-use proc_macro::TokenStream;
-use crate::args::two_string_args;
-use crate::env::forge_root;
-
-/// Macro expander for `render_yaml!(<template>, <yaml>)`.
-/// Resolves absolute paths via FORGE_ROOT and uses `forge_template::codegen::render_yaml_from_abs`.
-pub(crate) fn expand_render_yaml(input: TokenStream) -> Result<TokenStream, String> {
-    let (template_in, yaml_in) = two_string_args(input)?;
-
-    let root = forge_root()?;
-    let template_abs = absolutize(&root, &template_in);
-    let yaml_abs = absolutize(&root, &yaml_in);
-
-    eprintln!("[forge-proc] render_yaml:");
-    eprintln!("  template_abs = {}", template_abs);
-    eprintln!("  yaml_abs     = {}", yaml_abs);
-
-    // Call into forge-template directly
-    match forge_template::codegen::render_yaml_from_abs(&yaml_abs, &template_abs) {
-        Ok(rendered) => {
-            // Return the rendered code as a TokenStream
-            rendered.parse::<TokenStream>().map_err(|e| {
-                format!("forge-proc: failed to parse rendered template into tokens: {e}")
-            })
+use crate::args::{RenderArgs, TemplateArg, ValuesArg};
+use crate::env::{resolve, search_roots, verbose};
+use forge_template::codegen::{render_value_tracked_with, TemplateSource};
+use forge_template::template::with_payload;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Macro expander for `render_yaml!`.
+/// Resolves relative paths via [`search_roots`] and renders through
+/// `forge_template::codegen::render_value_tracked_with`, which returns
+/// `header.backend: rust_ast` payloads as tokens without a string round-trip.
+/// Every file read (YAML, template, partials) is tracked with an `include_bytes!` shim so
+/// cargo recompiles the calling crate when one changes.
+pub(crate) fn expand_render_yaml(input: proc_macro::TokenStream) -> syn::Result<TokenStream> {
+    let args: RenderArgs = syn::parse(input)?;
+    let roots = search_roots();
+    let mut inputs = Vec::new();
+
+    let raw = match &args.values {
+        ValuesArg::Path(lit) => {
+            let path = resolve(&lit.value(), &roots).map_err(|e| syn::Error::new(lit.span(), e))?;
+            let text = fs::read_to_string(&path)
+                .map_err(|e| syn::Error::new(lit.span(), format!("failed to read {}: {e}", path.display())))?;
+            let raw = serde_yaml::from_str(&text)
+                .map_err(|e| syn::Error::new(lit.span(), format!("failed to parse YAML {}: {e}", path.display())))?;
+            inputs.push(path);
+            raw
         }
-        Err(e) => Err(format!("forge-proc: render_yaml failed: {e:?}")),
-    }
+        ValuesArg::Yaml(lit) => with_payload(
+            serde_yaml::from_str(&lit.value())
+                .map_err(|e| syn::Error::new(lit.span(), format!("invalid inline YAML: {e}")))?,
+        ),
+        ValuesArg::Literal(values, _) => with_payload(values.clone()),
+    };
+
+    let span = args.span();
+    render(&raw, args.template.as_ref(), args.strict, inputs, span)
 }
 
-#[inline]
-fn absolutize(root: &str, path_in: &str) -> String {
-    use std::path::Path;
-    let p = Path::new(path_in);
-    if p.is_absolute() {
-        path_in.to_string()
-    } else {
-        let rel = path_in.trim_start_matches('/');
-        format!("{}/{}", root.trim_end_matches('/'), rel)
+/// Render `raw` with `template` and append dependency tracking for `inputs`
+/// plus every template file read. Render failures are reported on `span`.
+pub(crate) fn render(
+    raw: &Value,
+    template: Option<&TemplateArg>,
+    strict: bool,
+    mut inputs: Vec<PathBuf>,
+    span: Span,
+) -> syn::Result<TokenStream> {
+    let roots = search_roots();
+    // The rust_ast backend never reads the template, so only a failed render
+    // turns an unresolved template into an error.
+    let mut unresolved = None;
+    let template_path: Option<PathBuf> = match template {
+        Some(TemplateArg::Path(lit)) => resolve(&lit.value(), &roots)
+            .map_err(|e| unresolved = Some(syn::Error::new(lit.span(), e)))
+            .ok(),
+        _ => None,
+    };
+    let inline = match template {
+        Some(TemplateArg::Inline(lit)) => Some(lit.value()),
+        _ => None,
+    };
+    let source = match (&template_path, &inline) {
+        (Some(path), _) => Some(TemplateSource::Path(path)),
+        (_, Some(text)) => Some(TemplateSource::Inline(text)),
+        _ => None,
+    };
+
+    if verbose() {
+        eprintln!("[forge-proc] render:");
+        eprintln!("  template     = {source:?}");
+        eprintln!("  values       = {}", inputs.first().map_or("<inline>".into(), |p| p.display().to_string()));
     }
+
+    let tracked = render_value_tracked_with(raw, source, strict).map_err(|e| {
+        unresolved.unwrap_or_else(|| syn::Error::new(span, format!("forge-proc: render failed: {e:#}")))
+    })?;
+    inputs.extend(tracked.inputs);
+    if verbose() {
+        for input in &inputs {
+            eprintln!("  tracked      = {}", input.display());
+        }
+    }
+
+    let tokens = tracked.tokens;
+    let tracking = track_inputs(&inputs);
+    Ok(quote! { #tokens #tracking })
+}
+
+/// `const _: &[u8] = include_bytes!("<abs path>");` per input: the only stable
+/// way for a proc macro to register a file dependency with cargo.
+fn track_inputs(inputs: &[impl AsRef<Path>]) -> TokenStream {
+    let paths = inputs.iter().map(|p| p.as_ref().display().to_string());
+    quote! { #( const _: &[u8] = include_bytes!(#paths); )* }
 }
@@ -0,0 +1,92 @@
+// ============================================================================
+// Forge Integration Test
+// File: tests/derive_tests.rs
+// Crate: forge-proc
+// Description: Derives `ForgeTemplate` on a struct and an enum and checks the
+//              items rendered from their definitions (inline and file
+//              templates, repeated attributes, generics).
+// ============================================================================
+
+use forge_proc::ForgeTemplate;
+
+/// A labelled widget
+#[derive(ForgeTemplate)]
+#[forge(template_str = r#"
+impl {{payload.name}} {
+    pub fn field_names() -> &'static [&'static str] {
+        &[{{#payload.fields}}"{{name}}", {{/payload.fields}}]
+    }
+    pub fn field_types() -> &'static [&'static str] {
+        &[{{#payload.fields}}"{{{type}}}", {{/payload.fields}}]
+    }
+}
+"#)]
+#[forge(template = "tests/fixtures/describe.mustache")]
+#[allow(dead_code)]
+pub struct Widget {
+    label: String,
+    delay_ms: Option<u32>,
+}
+
+#[derive(ForgeTemplate)]
+#[forge(template_str = r#"
+impl {{payload.name}} {
+    pub fn variant_names() -> &'static [&'static str] {
+        &[{{#payload.variants}}"{{name_snake_case}}", {{/payload.variants}}]
+    }
+    pub fn arity(&self) -> usize {
+        match self {
+            {{#payload.variants}}
+            Self::{{name}}{{#is_tuple}}(..){{/is_tuple}}{{^is_tuple}}{{^is_unit}} { .. }{{/is_unit}}{{/is_tuple}} => {{fields_len}},
+            {{/payload.variants}}
+        }
+    }
+}
+"#)]
+#[forge(template = "tests/fixtures/describe.mustache")]
+#[allow(dead_code)]
+pub enum Mode {
+    Idle,
+    Busy(u8, u8),
+    Done { code: i32 },
+}
+
+#[derive(ForgeTemplate)]
+#[forge(template_str = r#"
+impl{{{payload.impl_generics}}} {{payload.name}}{{{payload.ty_generics}}} {{{payload.where_clause}}} {
+    pub fn fields(&self) -> [&T; {{payload.fields_len}}] {
+        [{{#payload.fields}}&self.{{name}}, {{/payload.fields}}]
+    }
+}
+"#)]
+pub struct Pair<T>
+where
+    T: Clone,
+{
+    left: T,
+    right: T,
+}
+
+#[test]
+fn struct_items_come_from_its_fields() {
+    assert_eq!(Widget::field_names(), ["label", "delay_ms"]);
+    assert_eq!(Widget::field_types(), ["String", "Option<u32>"]);
+    assert_eq!(Widget::KIND, "struct");
+    assert_eq!(Widget::DOC, "A labelled widget");
+}
+
+#[test]
+fn enum_items_come_from_its_variants() {
+    assert_eq!(Mode::variant_names(), ["idle", "busy", "done"]);
+    assert_eq!(Mode::Idle.arity(), 0);
+    assert_eq!(Mode::Busy(1, 2).arity(), 2);
+    assert_eq!(Mode::Done { code: 3 }.arity(), 1);
+    assert_eq!(Mode::KIND, "enum");
+    assert_eq!(Mode::DOC, "");
+}
+
+#[test]
+fn generics_are_carried_into_impls() {
+    let pair = Pair { left: 'a', right: 'b' };
+    assert_eq!(pair.fields(), [&'a', &'b']);
+}
@@ -0,0 +1,58 @@
+// ============================================================================
+// Forge Integration Test
+// File: tests/render_yaml_tests.rs
+// Crate: forge-proc
+// Description: Expands `render_yaml!` in every argument form (positional,
+//              named paths, `template_str`, `values = { .. }`, `yaml = ".."`)
+//              and checks the generated items.
+// ============================================================================
+
+mod positional {
+    forge_proc::render_yaml!("tests/fixtures/widget.mustache", "tests/fixtures/widget.yaml");
+}
+
+mod named {
+    forge_proc::render_yaml!(
+        values = "tests/fixtures/widget.yaml",
+        template = "tests/fixtures/widget.mustache",
+        strict = true,
+    );
+}
+
+mod inline_values {
+    forge_proc::render_yaml!(
+        template_str = "pub struct {{payload.name_PascalCase}};",
+        values = { name: "widget" }
+    );
+}
+
+mod inline_values_wrapped {
+    forge_proc::render_yaml!(
+        template = "tests/fixtures/widget.mustache",
+        values = { header: { type: "struct" }, payload: { name: "side_bar", fields: [{ name: "open" }] } }
+    );
+}
+
+mod inline_yaml {
+    forge_proc::render_yaml!(
+        template = "tests/fixtures/widget.mustache",
+        yaml = "name: status_line\nfields: [{ name: text }, { name: level }]"
+    );
+}
+
+#[test]
+fn path_forms_render_the_fixture() {
+    assert_eq!(positional::ToolTip::NAME, "tool_tip");
+    assert_eq!(positional::ToolTip::FIELDS, ["label", "delay_ms"]);
+    assert_eq!(named::ToolTip::NAME, positional::ToolTip::NAME);
+    assert_eq!(named::ToolTip::FIELDS, positional::ToolTip::FIELDS);
+}
+
+#[test]
+fn inline_forms_render_their_values() {
+    assert_eq!(std::any::type_name::<inline_values::Widget>(), "render_yaml_tests::inline_values::Widget");
+    assert_eq!(inline_values_wrapped::SideBar::NAME, "side_bar");
+    assert_eq!(inline_values_wrapped::SideBar::FIELDS, ["open"]);
+    assert_eq!(inline_yaml::StatusLine::NAME, "status_line");
+    assert_eq!(inline_yaml::StatusLine::FIELDS, ["text", "level"]);
+}
@@ -0,0 +1,90 @@
+// ============================================================================
+// Forge Template — Render Benchmarks
+// File: benches/render.rs
+// Role: Throughput guards for the render pipeline: the real forge-ide module
+//       manifests (templates/forge_ide/core.yaml) and a synthetic payload with
+//       thousands of fields. Run with `cargo bench -p forge-template`.
+// ============================================================================
+
+use std::path::{Path, PathBuf};
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use forge_template::codegen::cache::TemplateCache;
+use forge_template::context::codegen_ctx::build_yaml_codegen_context;
+use forge_template::{render_value_from_abs, Template};
+use serde_json::{json, Value};
+
+fn templates_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../../templates/forge_ide")
+}
+
+/// `(module name, template path, render value)` for every module of core.yaml,
+/// shaped like forge-ide's generator input.
+fn forge_ide_modules() -> Vec<(String, PathBuf, Value)> {
+    let dir = templates_dir();
+    let text = std::fs::read_to_string(dir.join("core.yaml")).expect("read core.yaml");
+    let manifest: Value = serde_yaml::from_str(&text).expect("parse core.yaml");
+    let mods = manifest["payload"]["mods"].as_array().cloned().unwrap_or_default();
+    mods.iter()
+        .flat_map(|m| m["modules"].as_array().cloned().unwrap_or_default())
+        .filter_map(|module| {
+            let kind = [("isSchema", "schema"), ("isCommand", "command"), ("isProvider", "provider"), ("isRouter", "router")]
+                .into_iter()
+                .find(|(flag, _)| module[*flag] == true)?
+                .1;
+            let name = module["name"].as_str()?.to_string();
+            let value = json!({
+                "header": { "type": format!("{kind}_module"), "ast": forge_template::ast::RUST_AST },
+                "payload": module,
+            });
+            Some((name, dir.join(format!("{kind}.mustache")), value))
+        })
+        .collect()
+}
+
+/// `payload.fields` with `n` entries, each expanded into naming variants.
+fn large_payload(n: usize) -> Value {
+    let fields: Vec<Value> = (0..n)
+        .map(|i| json!({ "name": format!("field_number_{i}"), "type": "String", "desc": "generated", "optional": i % 2 == 0 }))
+        .collect();
+    json!({ "header": { "type": "dto" }, "payload": { "name": "LargeRecord", "fields": fields } })
+}
+
+fn bench_forge_ide_modules(c: &mut Criterion) {
+    let modules = forge_ide_modules();
+    let mut group = c.benchmark_group("forge_ide_modules");
+    for (name, template, value) in &modules {
+        group.bench_with_input(BenchmarkId::new("cached", name), value, |b, v| {
+            b.iter(|| render_value_from_abs(black_box(v), template).expect("render"))
+        });
+        group.bench_with_input(BenchmarkId::new("uncached", name), value, |b, v| {
+            b.iter(|| {
+                TemplateCache::global().clear();
+                render_value_from_abs(black_box(v), template).expect("render")
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_large_payload(c: &mut Criterion) {
+    let template = Template::compile(
+        "pub struct {{payload.name}} {\n{{#payload.fields}}    pub {{name_snake_case}}: {{type}},\n{{/payload.fields}}}\n",
+    )
+    .expect("compile");
+    let mut group = c.benchmark_group("large_payload");
+    for n in [1_000, 5_000] {
+        let value = large_payload(n);
+        group.throughput(Throughput::Elements(n as u64));
+        group.bench_with_input(BenchmarkId::new("expand", n), &value, |b, v| {
+            b.iter(|| build_yaml_codegen_context(black_box(v)))
+        });
+        group.bench_with_input(BenchmarkId::new("render", n), &value, |b, v| {
+            b.iter(|| template.render(black_box(v)).expect("render"))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_forge_ide_modules, bench_large_payload);
+criterion_main!(benches);
@@ -0,0 +1,476 @@
+// ============================================================================
+// Forge Template — rust_ast Backend
+// File: ast/emit.rs
+// Role: Second rendering backend (`header.backend: rust_ast`). Builds a
+//       `proc_macro2::TokenStream` straight from a validated `RustModule`
+//       with syn/quote and pretty-prints it with prettyplease. Every type,
+//       expression and attribute fragment goes through syn, so bad input is
+//       reported with its AST path instead of surfacing later in rustc.
+// ============================================================================
+
+use super::model::*;
+use super::normalize::{body_lines, use_decl};
+use crate::errors::{AstError, AstErrors};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::Parse;
+
+/// Tokens for `module` (assumed validated).
+pub fn module_tokens(module: &RustModule) -> Result<TokenStream, AstErrors> {
+    let mut emitter = Emitter::default();
+    let tokens = emitter.module(module);
+    if emitter.errors.is_empty() {
+        Ok(tokens)
+    } else {
+        Err(AstErrors(emitter.errors))
+    }
+}
+
+/// Pretty-printed source for `module`.
+pub fn pretty_print(module: &RustModule) -> Result<String, AstErrors> {
+    let tokens = module_tokens(module)?;
+    let file: syn::File = syn::parse2(tokens).map_err(|e| {
+        AstErrors(vec![AstError {
+            path: "payload.ast".into(),
+            message: format!("emitted tokens are not a Rust file: {e}"),
+        }])
+    })?;
+    Ok(prettyplease::unparse(&file))
+}
+
+/// Where a fn is emitted; decides visibility and body handling.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Place {
+    Inherent,
+    TraitDecl,
+    TraitImpl,
+}
+
+#[derive(Default)]
+struct Emitter {
+    errors: Vec<AstError>,
+}
+
+impl Emitter {
+    fn err(&mut self, path: &str, message: impl Into<String>) {
+        self.errors.push(AstError {
+            path: path.to_string(),
+            message: message.into(),
+        });
+    }
+
+    /// Parse `src` with syn, recording an error on failure.
+    fn parse<T: Parse>(&mut self, at: &str, what: &str, src: &str) -> Option<T> {
+        match syn::parse_str::<T>(src) {
+            Ok(t) => Some(t),
+            Err(e) => {
+                self.err(at, format!("{what} `{src}` does not parse: {e}"));
+                None
+            }
+        }
+    }
+
+    fn ident(&mut self, at: &str, what: &str, name: &str) -> Option<syn::Ident> {
+        self.parse(at, what, name)
+    }
+
+    fn generics(&mut self, at: &str, generics: &Option<String>) -> syn::Generics {
+        generics
+            .as_deref()
+            .and_then(|g| self.parse(at, "generics", g))
+            .unwrap_or_default()
+    }
+
+    fn attrs(&mut self, at: &str, attrs: &[Attribute]) -> TokenStream {
+        let metas: Vec<syn::Meta> = attrs
+            .iter()
+            .enumerate()
+            .filter_map(|(i, a)| self.parse(&format!("{at}.attrs[{i}]"), "attribute", a.0.trim()))
+            .collect();
+        quote! { #( #[#metas] )* }
+    }
+
+    fn derives(&mut self, at: &str, derives: &[String]) -> TokenStream {
+        if derives.is_empty() {
+            return TokenStream::new();
+        }
+        let paths: Vec<syn::Path> = derives
+            .iter()
+            .enumerate()
+            .filter_map(|(i, d)| self.parse(&format!("{at}.derives[{i}]"), "derive", d))
+            .collect();
+        quote! { #[derive( #(#paths),* )] }
+    }
+
+    // -------------------------------------------------------------------------
+    // Module & items
+    // -------------------------------------------------------------------------
+
+    fn module(&mut self, m: &RustModule) -> TokenStream {
+        let inner_docs: Vec<TokenStream> = doc_texts(&m.desc)
+            .into_iter()
+            .map(|text| quote! { #![doc = #text] })
+            .collect();
+        let uses: Vec<syn::ItemUse> = m
+            .imports
+            .iter()
+            .enumerate()
+            .filter_map(|(i, u)| self.parse(&format!("imports[{i}]"), "use", &use_decl(u)))
+            .collect();
+        let items: Vec<TokenStream> = m
+            .items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let at = format!("items[{i}]");
+                match item {
+                    Item::Struct(s) => self.struct_def(&at, s),
+                    Item::Enum(e) => self.enum_def(&at, e),
+                    Item::Trait(t) => self.trait_def(&at, t),
+                    Item::Impl(imp) => self.impl_def(&at, imp),
+                }
+            })
+            .collect();
+        quote! { #(#inner_docs)* #(#uses)* #(#items)* }
+    }
+
+    fn struct_def(&mut self, at: &str, s: &StructDef) -> TokenStream {
+        let docs = doc_attrs(&s.desc);
+        let derives = self.derives(at, &s.derives);
+        let attrs = self.attrs(at, &s.attrs);
+        let vis = vis(s.vis);
+        let name = self.ident(at, "struct name", &s.name);
+        let generics = self.generics(at, &s.generics);
+        let fields = self.fields(at, &s.fields, true);
+        let methods = self.fns(at, &s.methods, Place::Inherent);
+
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let inherent = (!s.methods.is_empty()).then(|| {
+            quote! {
+                impl #impl_generics #name #ty_generics #where_clause {
+                    #(#methods)*
+                }
+            }
+        });
+        quote! {
+            #docs
+            #derives
+            #attrs
+            #vis struct #name #generics #where_clause {
+                #(#fields),*
+            }
+            #inherent
+        }
+    }
+
+    fn enum_def(&mut self, at: &str, e: &EnumDef) -> TokenStream {
+        let docs = doc_attrs(&e.desc);
+        let derives = self.derives(at, &e.derives);
+        let attrs = self.attrs(at, &e.attrs);
+        let vis = vis(e.vis);
+        let name = self.ident(at, "enum name", &e.name);
+        let variants: Vec<TokenStream> = e
+            .variants
+            .iter()
+            .enumerate()
+            .map(|(i, v)| {
+                let vat = format!("{at}.variants[{i}]");
+                let docs = doc_attrs(&v.desc);
+                let attrs = self.attrs(&vat, &v.attrs);
+                let name = self.ident(&vat, "variant name", &v.name);
+                if v.fields.is_empty() {
+                    quote! { #docs #attrs #name }
+                } else {
+                    let fields = self.fields(&vat, &v.fields, false);
+                    quote! { #docs #attrs #name { #(#fields),* } }
+                }
+            })
+            .collect();
+        quote! {
+            #docs
+            #derives
+            #attrs
+            #vis enum #name {
+                #(#variants),*
+            }
+        }
+    }
+
+    fn trait_def(&mut self, at: &str, t: &TraitDef) -> TokenStream {
+        let docs = doc_attrs(&t.desc);
+        let attrs = self.attrs(at, &t.attrs);
+        let vis = vis(t.vis);
+        let name = self.ident(at, "trait name", &t.name);
+        let bounds: Vec<syn::TypeParamBound> = t
+            .supertraits
+            .iter()
+            .enumerate()
+            .filter_map(|(i, s)| self.parse(&format!("{at}.supertraits[{i}]"), "supertrait", s))
+            .collect();
+        let colon = (!bounds.is_empty()).then(|| quote!(:));
+        let methods = self.fns(at, &t.methods, Place::TraitDecl);
+        quote! {
+            #docs
+            #attrs
+            #vis trait #name #colon #(#bounds)+* {
+                #(#methods)*
+            }
+        }
+    }
+
+    fn impl_def(&mut self, at: &str, imp: &ImplDef) -> TokenStream {
+        let docs = doc_attrs(&imp.desc);
+        let attrs = self.attrs(at, &imp.attrs);
+        let generics = self.generics(at, &imp.generics);
+        let target: Option<syn::Type> = self.parse(at, "impl target", &imp.target);
+        let (place, for_trait) = match &imp.trait_name {
+            Some(tr) => {
+                let path: Option<syn::Path> = self.parse(at, "trait", tr);
+                (Place::TraitImpl, Some(quote! { #path for }))
+            }
+            None => (Place::Inherent, None),
+        };
+        let methods = self.fns(at, &imp.methods, place);
+        quote! {
+            #docs
+            #attrs
+            impl #generics #for_trait #target {
+                #(#methods)*
+            }
+        }
+    }
+
+    fn fields(&mut self, at: &str, fields: &[FieldDef], with_vis: bool) -> Vec<TokenStream> {
+        fields
+            .iter()
+            .enumerate()
+            .map(|(i, f)| {
+                let fat = format!("{at}.fields[{i}]");
+                let docs = doc_attrs(&f.desc);
+                let attrs = self.attrs(&fat, &f.attrs);
+                let vis = with_vis.then(|| vis(f.vis));
+                let name = self.ident(&fat, "field name", &f.name);
+                let ty: Option<syn::Type> = self.parse(&fat, "field type", &f.ty);
+                quote! { #docs #attrs #vis #name: #ty }
+            })
+            .collect()
+    }
+
+    // -------------------------------------------------------------------------
+    // Functions
+    // -------------------------------------------------------------------------
+
+    fn fns(&mut self, at: &str, fns: &[FnDef], place: Place) -> Vec<TokenStream> {
+        fns.iter()
+            .enumerate()
+            .map(|(i, f)| self.fn_item(&format!("{at}.methods[{i}]"), f, place))
+            .collect()
+    }
+
+    fn fn_item(&mut self, at: &str, f: &FnDef, place: Place) -> TokenStream {
+        let docs = doc_attrs(&f.desc);
+        let attrs = self.attrs(at, &f.attrs);
+        let vis = (place == Place::Inherent).then(|| vis(f.vis));
+        let asyncness = f.is_async.then(|| quote!(async));
+        let name = self.ident(at, "fn name", &f.name);
+        let generics = self.generics(at, &f.generics);
+        let where_clause = &generics.where_clause;
+
+        let receiver = f.receiver.map(|r| match r {
+            Receiver::Ref => quote!(&self),
+            Receiver::RefMut => quote!(&mut self),
+            Receiver::Value => quote!(self),
+            Receiver::MutValue => quote!(mut self),
+        });
+        let args: Vec<TokenStream> = f
+            .args
+            .iter()
+            .enumerate()
+            .map(|(i, a)| {
+                let aat = format!("{at}.args[{i}]");
+                let name = self.ident(&aat, "argument name", &a.name);
+                let ty: Option<syn::Type> = self.parse(&aat, "argument type", &a.ty);
+                quote! { #name: #ty }
+            })
+            .collect();
+        let params = receiver.into_iter().chain(args);
+        let ret = f.returns.as_deref().map(|r| {
+            let ty: Option<syn::Type> = self.parse(at, "return type", r);
+            quote! { -> #ty }
+        });
+
+        let body = match (place, &f.default_body, &f.body) {
+            (Place::TraitDecl, None, _) => quote!(;),
+            (Place::TraitDecl, Some(src), _) => {
+                let block: Option<syn::Block> = self.parse(
+                    &format!("{at}.default_body"),
+                    "default body",
+                    &format!("{{ {src} }}"),
+                );
+                quote! { #block }
+            }
+            (_, _, Some(body)) => self.body(&format!("{at}.body"), body),
+            (_, _, None) => quote!({}),
+        };
+
+        quote! {
+            #docs
+            #attrs
+            #vis #asyncness fn #name #generics ( #(#params),* ) #ret #where_clause
+            #body
+        }
+    }
+
+    fn body(&mut self, at: &str, body: &FnBody) -> TokenStream {
+        let src = match &body.match_expr {
+            Some(m) => {
+                let mut arms = String::new();
+                for (i, arm) in m.arms.iter().enumerate() {
+                    match &arm.expr {
+                        Some(expr) => arms.push_str(&format!("{} => {expr},\n", arm.pattern)),
+                        None => self.err(
+                            &format!("{at}.match.arms[{i}]"),
+                            "`variant`-only arms are expanded by mustache templates; \
+                             the rust_ast backend needs `expr`",
+                        ),
+                    }
+                }
+                format!("{{ match {} {{\n{arms}}} }}", m.variable)
+            }
+            None => format!("{{\n{}\n}}", body_lines(body).join("\n")),
+        };
+        let block: Option<syn::Block> = self.parse(at, "body", &src);
+        quote! { #block }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Helpers
+// -----------------------------------------------------------------------------
+
+fn vis(v: Visibility) -> TokenStream {
+    match v {
+        Visibility::Pub => quote!(pub),
+        Visibility::PubCrate => quote!(pub(crate)),
+        Visibility::Private => TokenStream::new(),
+    }
+}
+
+/// `#[doc = "..."]` texts; a leading space keeps `/// text` formatting.
+fn doc_texts(desc: &Option<Doc>) -> Vec<String> {
+    desc.iter()
+        .flat_map(|d| d.0.trim_end().lines())
+        .map(|l| match l.trim_end() {
+            "" => String::new(),
+            text => format!(" {text}"),
+        })
+        .collect()
+}
+
+fn doc_attrs(desc: &Option<Doc>) -> TokenStream {
+    let texts = doc_texts(desc);
+    quote! { #( #[doc = #texts] )* }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(yaml: &str) -> RustModule {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    #[test]
+    fn emits_pretty_printed_items() {
+        let m = parse(
+            r#"
+desc: Generated router
+imports:
+  - { path: std::sync, names: [Arc] }
+items:
+  - item: struct
+    name: Router
+    desc: Routes requests
+    derives: [Default, Clone]
+    generics: "<T: Clone>"
+    fields:
+      - { name: inner, type: "Arc<T>", desc: Shared state }
+    methods:
+      - name: new
+        args: [{ name: inner, type: "Arc<T>" }]
+        returns: Self
+        body: { fields: [{ name: inner }] }
+  - item: enum
+    name: Kind
+    attrs: ['serde(rename_all = "camelCase")']
+    variants:
+      - { name: Build, fields: [{ name: release, type: bool, attrs: ["serde(default)"] }] }
+      - { name: Env }
+  - item: trait
+    name: Cmd
+    attrs: [async_trait]
+    supertraits: [Send, Sync]
+    methods:
+      - { name: run, async: true, receiver: "&self", returns: u8 }
+      - { name: name, receiver: "&self", returns: "&'static str", default_body: '"cmd"' }
+  - item: impl
+    target: Kind
+    trait: Cmd
+    methods:
+      - name: run
+        async: true
+        receiver: "&self"
+        returns: u8
+        body:
+          match:
+            variable: self
+            arms:
+              - { pattern: "Kind::Build { .. }", expr: "1" }
+              - { pattern: Kind::Env, expr: "2" }
+"#,
+        );
+        let out = pretty_print(&m).unwrap();
+        for expected in [
+            "//! Generated router",
+            "use std::sync::Arc;",
+            "/// Routes requests\n#[derive(Default, Clone)]\npub struct Router<T: Clone> {",
+            "    /// Shared state\n    pub inner: Arc<T>,",
+            "impl<T: Clone> Router<T> {\n    pub fn new(inner: Arc<T>) -> Self {\n        Self { inner }",
+            "#[serde(rename_all = \"camelCase\")]\npub enum Kind {",
+            "    Build { #[serde(default)] release: bool },",
+            "#[async_trait]\npub trait Cmd: Send + Sync {\n    async fn run(&self) -> u8;",
+            "fn name(&self) -> &'static str {\n        \"cmd\"",
+            "impl Cmd for Kind {\n    async fn run(&self) -> u8 {\n        match self {",
+        ] {
+            assert!(out.contains(expected), "missing {expected:?} in:\n{out}");
+        }
+    }
+
+    #[test]
+    fn unparsable_fragments_report_their_path() {
+        let m = parse(
+            r#"
+items:
+  - item: struct
+    name: A
+    fields: [{ name: a, type: "Vec<" }]
+    methods:
+      - { name: f, returns: u8, body: { statements: ["1 +"] } }
+      - name: g
+        receiver: "&self"
+        body: { match: { variable: x, arms: [{ pattern: "_", variant: Build }] } }
+"#,
+        );
+        let errs = module_tokens(&m).unwrap_err().0;
+        let paths: Vec<_> = errs.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "items[0].fields[0]",
+                "items[0].methods[0].body",
+                "items[0].methods[1].body.match.arms[0]",
+            ]
+        );
+        assert!(errs[0].message.contains("field type `Vec<`"));
+    }
+}
@@ -0,0 +1,96 @@
+// ============================================================================
+// Forge Template — Typed Rust AST
+// File: ast/mod.rs
+// Role: Opt-in typed model for `payload.ast`. When a YAML sets
+//       `header.ast: rust`, the block is deserialized into `RustModule`,
+//       validated, and replaced by its normalized context before the usual
+//       naming-variant expansion runs. With `header.backend: rust_ast` the
+//       same model is emitted as tokens by `emit` instead of a template.
+// ============================================================================
+
+pub mod emit;
+pub mod model;
+pub mod normalize;
+pub mod validate;
+
+pub use model::*;
+pub use emit::{module_tokens, pretty_print};
+pub use normalize::{body_lines, fn_params, fn_signature, normalize, use_decl};
+pub use validate::{is_ident, validate};
+
+use crate::errors::{AstError, AstErrors};
+use proc_macro2::TokenStream;
+use serde_json::Value;
+
+/// `header.ast` value selecting the typed model
+pub const RUST_AST: &str = "rust";
+/// `header.backend` value selecting the syn/quote emitter
+pub const RUST_AST_BACKEND: &str = "rust_ast";
+
+/// Parse and validate a `payload.ast` value.
+pub fn parse_module(ast: &Value) -> Result<RustModule, AstErrors> {
+    let module: RustModule = serde_json::from_value(ast.clone()).map_err(|e| {
+        AstErrors(vec![AstError {
+            path: "payload.ast".into(),
+            message: e.to_string(),
+        }])
+    })?;
+    validate(&module)?;
+    Ok(module)
+}
+
+fn header_str<'a>(conditioned: &'a Value, key: &str) -> Option<&'a str> {
+    conditioned
+        .get("header")
+        .and_then(|h| h.get(key))
+        .and_then(Value::as_str)
+}
+
+/// True if the conditioned document opts into the typed model.
+pub fn uses_rust_ast(conditioned: &Value) -> bool {
+    header_str(conditioned, "ast") == Some(RUST_AST)
+}
+
+/// True if the conditioned document selects the `rust_ast` backend.
+pub fn uses_rust_ast_backend(conditioned: &Value) -> bool {
+    header_str(conditioned, "backend") == Some(RUST_AST_BACKEND)
+}
+
+/// Replace `payload.ast` with its normalized context when `header.ast: rust`.
+/// Documents without the header flag are returned untouched.
+pub fn apply(conditioned: &mut Value) -> Result<(), AstErrors> {
+    if !uses_rust_ast(conditioned) {
+        return Ok(());
+    }
+    let Some(ast) = conditioned.pointer_mut("/payload/ast") else {
+        return Ok(());
+    };
+    let module = parse_module(ast)?;
+    *ast = normalize(&module);
+    Ok(())
+}
+
+/// `rust_ast` backend: tokens for the `payload.ast` of a conditioned document.
+pub fn emit_tokens(conditioned: &Value) -> Result<TokenStream, AstErrors> {
+    module_tokens(&parse_payload_ast(conditioned)?)
+}
+
+/// `rust_ast` backend: pretty-printed source, preceded by a provenance comment
+/// when `header.file` is set.
+pub fn emit_source(conditioned: &Value) -> Result<String, AstErrors> {
+    let code = pretty_print(&parse_payload_ast(conditioned)?)?;
+    Ok(match header_str(conditioned, "file") {
+        Some(file) => format!("// Generated by forge-template (backend: {RUST_AST_BACKEND}) from {file}\n\n{code}"),
+        None => code,
+    })
+}
+
+fn parse_payload_ast(conditioned: &Value) -> Result<RustModule, AstErrors> {
+    let ast = conditioned.pointer("/payload/ast").ok_or_else(|| {
+        AstErrors(vec![AstError {
+            path: "payload.ast".into(),
+            message: format!("required by `backend: {RUST_AST_BACKEND}`"),
+        }])
+    })?;
+    parse_module(ast)
+}
@@ -0,0 +1,337 @@
+// ============================================================================
+// Forge Template — Rust AST Model
+// File: ast/model.rs
+// Role: Typed serde model for `payload.ast` blocks describing a Rust module
+//       (uses, structs, enums, traits, impls, fns). Unknown keys are rejected
+//       so typos surface as errors instead of silently empty template output.
+// ============================================================================
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+// Shared building blocks
+// -----------------------------------------------------------------------------
+
+/// Doc comment text; one `///` line per text line.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+#[serde(transparent)]
+pub struct Doc(pub String);
+
+impl Doc {
+    /// `/// ...` lines (blank lines become a bare `///`).
+    pub fn lines(&self) -> Vec<String> {
+        self.0
+            .trim_end()
+            .lines()
+            .map(|l| match l.trim_end() {
+                "" => "///".to_string(),
+                text => format!("/// {text}"),
+            })
+            .collect()
+    }
+}
+
+/// Outer attribute body without `#[...]`, e.g. `serde(default)`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct Attribute(pub String);
+
+impl Attribute {
+    /// `#[body]`
+    pub fn render(&self) -> String {
+        format!("#[{}]", self.0.trim())
+    }
+}
+
+/// Item / field visibility
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Visibility {
+    #[default]
+    #[serde(rename = "pub")]
+    Pub,
+    #[serde(rename = "pub(crate)")]
+    PubCrate,
+    #[serde(rename = "private")]
+    Private,
+}
+
+impl Visibility {
+    /// Prefix including the trailing space (`""` for private).
+    pub fn prefix(self) -> &'static str {
+        match self {
+            Self::Pub => "pub ",
+            Self::PubCrate => "pub(crate) ",
+            Self::Private => "",
+        }
+    }
+}
+
+/// `self` parameter of a method
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Receiver {
+    #[serde(rename = "&self")]
+    Ref,
+    #[serde(rename = "&mut self")]
+    RefMut,
+    #[serde(rename = "self")]
+    Value,
+    #[serde(rename = "mut self")]
+    MutValue,
+}
+
+impl Receiver {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Ref => "&self",
+            Self::RefMut => "&mut self",
+            Self::Value => "self",
+            Self::MutValue => "mut self",
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Module & imports
+// -----------------------------------------------------------------------------
+
+/// A whole generated module (`payload.ast`)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(deny_unknown_fields)]
+pub struct RustModule {
+    /// Free-form module kind (`schema_file`, `router_file`, ...)
+    #[serde(default)]
+    pub kind: Option<String>,
+    #[serde(default)]
+    pub version: Option<f64>,
+    #[serde(default)]
+    pub namespace: Option<String>,
+    #[serde(default)]
+    pub desc: Option<Doc>,
+    #[serde(default)]
+    pub imports: Vec<UseDecl>,
+    #[serde(default)]
+    pub items: Vec<Item>,
+}
+
+/// `use path::{names};`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct UseDecl {
+    /// Module path, e.g. `crate::schema` or `std::sync`
+    pub path: String,
+    /// Imported names; empty imports the path itself
+    #[serde(default)]
+    pub names: Vec<String>,
+    #[serde(default = "private")]
+    pub vis: Visibility,
+}
+
+fn private() -> Visibility {
+    Visibility::Private
+}
+
+// -----------------------------------------------------------------------------
+// Items
+// -----------------------------------------------------------------------------
+
+/// Top-level item, tagged by `item:`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "item", rename_all = "snake_case")]
+pub enum Item {
+    Struct(StructDef),
+    Enum(EnumDef),
+    Trait(TraitDef),
+    Impl(ImplDef),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(deny_unknown_fields)]
+pub struct StructDef {
+    pub name: String,
+    #[serde(default)]
+    pub desc: Option<Doc>,
+    #[serde(default)]
+    pub vis: Visibility,
+    #[serde(default)]
+    pub generics: Option<String>,
+    #[serde(default)]
+    pub derives: Vec<String>,
+    #[serde(default)]
+    pub attrs: Vec<Attribute>,
+    #[serde(default)]
+    pub fields: Vec<FieldDef>,
+    /// Inherent methods (`impl Name { ... }`)
+    #[serde(default)]
+    pub methods: Vec<FnDef>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(deny_unknown_fields)]
+pub struct EnumDef {
+    pub name: String,
+    #[serde(default)]
+    pub desc: Option<Doc>,
+    #[serde(default)]
+    pub vis: Visibility,
+    #[serde(default)]
+    pub derives: Vec<String>,
+    #[serde(default)]
+    pub attrs: Vec<Attribute>,
+    #[serde(default)]
+    pub variants: Vec<VariantDef>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(deny_unknown_fields)]
+pub struct VariantDef {
+    pub name: String,
+    #[serde(default)]
+    pub desc: Option<Doc>,
+    #[serde(default)]
+    pub attrs: Vec<Attribute>,
+    /// Named fields; empty for a unit variant
+    #[serde(default)]
+    pub fields: Vec<FieldDef>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(deny_unknown_fields)]
+pub struct FieldDef {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: String,
+    #[serde(default)]
+    pub desc: Option<Doc>,
+    /// Ignored for enum variant fields
+    #[serde(default)]
+    pub vis: Visibility,
+    #[serde(default)]
+    pub attrs: Vec<Attribute>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(deny_unknown_fields)]
+pub struct TraitDef {
+    pub name: String,
+    #[serde(default)]
+    pub desc: Option<Doc>,
+    #[serde(default)]
+    pub vis: Visibility,
+    #[serde(default)]
+    pub attrs: Vec<Attribute>,
+    #[serde(default)]
+    pub supertraits: Vec<String>,
+    #[serde(default)]
+    pub methods: Vec<FnDef>,
+}
+
+/// `impl [Trait for] Target { ... }`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(deny_unknown_fields)]
+pub struct ImplDef {
+    pub target: String,
+    #[serde(default, rename = "trait")]
+    pub trait_name: Option<String>,
+    #[serde(default)]
+    pub generics: Option<String>,
+    #[serde(default)]
+    pub desc: Option<Doc>,
+    #[serde(default)]
+    pub attrs: Vec<Attribute>,
+    #[serde(default)]
+    pub methods: Vec<FnDef>,
+}
+
+// -----------------------------------------------------------------------------
+// Functions
+// -----------------------------------------------------------------------------
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(deny_unknown_fields)]
+pub struct FnDef {
+    pub name: String,
+    #[serde(default)]
+    pub desc: Option<Doc>,
+    /// Ignored inside traits and trait impls
+    #[serde(default)]
+    pub vis: Visibility,
+    #[serde(default)]
+    pub attrs: Vec<Attribute>,
+    #[serde(default, rename = "async")]
+    pub is_async: bool,
+    #[serde(default)]
+    pub generics: Option<String>,
+    #[serde(default)]
+    pub receiver: Option<Receiver>,
+    #[serde(default)]
+    pub args: Vec<ArgDef>,
+    #[serde(default)]
+    pub returns: Option<String>,
+    #[serde(default)]
+    pub body: Option<FnBody>,
+    /// Trait methods only: provided implementation
+    #[serde(default)]
+    pub default_body: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct ArgDef {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: String,
+}
+
+/// Function body; exactly one form may be used.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+#[serde(deny_unknown_fields)]
+pub struct FnBody {
+    /// Raw statements / tail expression, one per line
+    #[serde(default)]
+    pub statements: Vec<String>,
+    /// `Self { .. }` struct literal
+    #[serde(default)]
+    pub fields: Vec<FieldInit>,
+    /// `base.call().call()` method chain
+    #[serde(default)]
+    pub chain: Option<Chain>,
+    /// `match` over a variable; arms are expanded by the template
+    #[serde(default, rename = "match")]
+    pub match_expr: Option<MatchExpr>,
+}
+
+/// `name: value`, or shorthand `name` when `value` is absent
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct FieldInit {
+    pub name: String,
+    #[serde(default)]
+    pub value: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct Chain {
+    pub base: String,
+    #[serde(default)]
+    pub calls: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct MatchExpr {
+    pub variable: String,
+    #[serde(default)]
+    pub arms: Vec<MatchArm>,
+}
+
+/// `pattern => expr`; `variant` lets templates derive the expression.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct MatchArm {
+    pub pattern: String,
+    #[serde(default)]
+    pub variant: Option<String>,
+    #[serde(default)]
+    pub expr: Option<String>,
+}
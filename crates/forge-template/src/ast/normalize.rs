@@ -0,0 +1,356 @@
+// ============================================================================
+// Forge Template — Rust AST Normalization
+// File: ast/normalize.rs
+// Role: Turns a validated `RustModule` into the template context: the model's
+//       own keys plus precomputed Rust fragments (`decl`, `signature`,
+//       `params`, `derive_attr`, `doc_lines`, `body.lines`, ...) and
+//       `is_struct` / `is_enum` / `is_trait` / `is_impl` flags, so templates
+//       never assemble Rust syntax from parts.
+// ============================================================================
+
+use super::model::*;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+/// Normalized context for `module` (assumed validated).
+pub fn normalize(module: &RustModule) -> Value {
+    let mut out = to_map(module);
+    out.insert("doc_lines".into(), doc_lines(&module.desc));
+    out.insert(
+        "imports".into(),
+        module.imports.iter().map(use_ctx).collect(),
+    );
+    out.insert("has_imports".into(), json!(!module.imports.is_empty()));
+    out.insert("items".into(), module.items.iter().map(item_ctx).collect());
+    strip_nulls(Value::Object(out))
+}
+
+/// `use path::{a, b};`
+pub fn use_decl(u: &UseDecl) -> String {
+    let target = match u.names.as_slice() {
+        [] => u.path.clone(),
+        [one] => format!("{}::{one}", u.path),
+        many => format!("{}::{{{}}}", u.path, many.join(", ")),
+    };
+    format!("{}use {target};", u.vis.prefix())
+}
+
+/// `pub async fn name<G>(&self, a: A) -> R` (no vis when `in_trait`).
+pub fn fn_signature(f: &FnDef, in_trait: bool) -> String {
+    let vis = if in_trait { "" } else { f.vis.prefix() };
+    let asyncness = if f.is_async { "async " } else { "" };
+    format!(
+        "{vis}{asyncness}fn {}{}({}){}",
+        f.name,
+        f.generics.as_deref().unwrap_or_default(),
+        fn_params(f),
+        returns_clause(f)
+    )
+}
+
+/// Receiver plus arguments, comma separated.
+pub fn fn_params(f: &FnDef) -> String {
+    f.receiver
+        .map(|r| r.as_str().to_string())
+        .into_iter()
+        .chain(f.args.iter().map(|a| format!("{}: {}", a.name, a.ty)))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn returns_clause(f: &FnDef) -> String {
+    f.returns
+        .as_deref()
+        .map(|r| format!(" -> {r}"))
+        .unwrap_or_default()
+}
+
+fn use_ctx(u: &UseDecl) -> Value {
+    let mut out = to_map(u);
+    out.insert("decl".into(), json!(use_decl(u)));
+    Value::Object(out)
+}
+
+// -----------------------------------------------------------------------------
+// Items
+// -----------------------------------------------------------------------------
+
+fn item_ctx(item: &Item) -> Value {
+    let (mut out, kind) = match item {
+        Item::Struct(s) => (struct_ctx(s), "struct"),
+        Item::Enum(e) => (enum_ctx(e), "enum"),
+        Item::Trait(t) => (trait_ctx(t), "trait"),
+        Item::Impl(i) => (impl_ctx(i), "impl"),
+    };
+    for flag in ["struct", "enum", "trait", "impl"] {
+        out.insert(format!("is_{flag}"), json!(flag == kind));
+    }
+    Value::Object(out)
+}
+
+fn struct_ctx(s: &StructDef) -> Map<String, Value> {
+    let mut out = to_map(s);
+    common(&mut out, &s.desc, &s.derives, &s.attrs);
+    out.insert(
+        "decl".into(),
+        json!(format!(
+            "{}struct {}{}",
+            s.vis.prefix(),
+            s.name,
+            s.generics.as_deref().unwrap_or_default()
+        )),
+    );
+    out.insert("fields".into(), fields_ctx(&s.fields, true));
+    out.insert("has_fields".into(), json!(!s.fields.is_empty()));
+    out.insert("methods".into(), fns_ctx(&s.methods, false));
+    out.insert("has_methods".into(), json!(!s.methods.is_empty()));
+    out
+}
+
+fn enum_ctx(e: &EnumDef) -> Map<String, Value> {
+    let mut out = to_map(e);
+    common(&mut out, &e.desc, &e.derives, &e.attrs);
+    out.insert(
+        "decl".into(),
+        json!(format!("{}enum {}", e.vis.prefix(), e.name)),
+    );
+    let variants = e
+        .variants
+        .iter()
+        .map(|v| {
+            let mut vm = to_map(v);
+            vm.insert("doc_lines".into(), doc_lines(&v.desc));
+            vm.insert("attr_lines".into(), attr_lines(&v.attrs));
+            vm.insert("fields".into(), fields_ctx(&v.fields, false));
+            vm.insert("has_fields".into(), json!(!v.fields.is_empty()));
+            Value::Object(vm)
+        })
+        .collect();
+    out.insert("variants".into(), variants);
+    out
+}
+
+fn trait_ctx(t: &TraitDef) -> Map<String, Value> {
+    let mut out = to_map(t);
+    common(&mut out, &t.desc, &[], &t.attrs);
+    let bounds = if t.supertraits.is_empty() {
+        String::new()
+    } else {
+        format!(": {}", t.supertraits.join(" + "))
+    };
+    out.insert(
+        "decl".into(),
+        json!(format!("{}trait {}{bounds}", t.vis.prefix(), t.name)),
+    );
+    out.insert("methods".into(), fns_ctx(&t.methods, true));
+    out
+}
+
+fn impl_ctx(i: &ImplDef) -> Map<String, Value> {
+    let mut out = to_map(i);
+    common(&mut out, &i.desc, &[], &i.attrs);
+    let generics = i.generics.as_deref().unwrap_or_default();
+    let decl = match &i.trait_name {
+        Some(tr) => format!("impl{generics} {tr} for {}", i.target),
+        None => format!("impl{generics} {}", i.target),
+    };
+    out.insert("decl".into(), json!(decl));
+    out.insert(
+        "methods".into(),
+        fns_ctx(&i.methods, i.trait_name.is_some()),
+    );
+    out
+}
+
+/// Keys shared by every item: docs, attributes and `#[derive(..)]`.
+fn common(
+    out: &mut Map<String, Value>,
+    desc: &Option<Doc>,
+    derives: &[String],
+    attrs: &[Attribute],
+) {
+    out.insert("doc_lines".into(), doc_lines(desc));
+    out.insert("attr_lines".into(), attr_lines(attrs));
+    if !derives.is_empty() {
+        out.insert(
+            "derive_attr".into(),
+            json!(format!("#[derive({})]", derives.join(", "))),
+        );
+    }
+}
+
+fn fields_ctx(fields: &[FieldDef], with_vis: bool) -> Value {
+    fields
+        .iter()
+        .map(|f| {
+            let mut fm = to_map(f);
+            let vis = if with_vis { f.vis.prefix() } else { "" };
+            fm.insert("decl".into(), json!(format!("{vis}{}: {}", f.name, f.ty)));
+            fm.insert("doc_lines".into(), doc_lines(&f.desc));
+            fm.insert("attr_lines".into(), attr_lines(&f.attrs));
+            Value::Object(fm)
+        })
+        .collect()
+}
+
+// -----------------------------------------------------------------------------
+// Functions
+// -----------------------------------------------------------------------------
+
+fn fns_ctx(fns: &[FnDef], in_trait: bool) -> Value {
+    fns.iter().map(|f| fn_ctx(f, in_trait)).collect()
+}
+
+fn fn_ctx(f: &FnDef, in_trait: bool) -> Value {
+    let mut out = to_map(f);
+    out.insert("signature".into(), json!(fn_signature(f, in_trait)));
+    out.insert("params".into(), json!(fn_params(f)));
+    out.insert("returns_clause".into(), json!(returns_clause(f)));
+    out.insert("has_receiver".into(), json!(f.receiver.is_some()));
+    out.insert("doc_lines".into(), doc_lines(&f.desc));
+    out.insert("attr_lines".into(), attr_lines(&f.attrs));
+    if let Some(body) = &f.body {
+        out.insert("body".into(), body_ctx(body));
+    }
+    Value::Object(out)
+}
+
+fn body_ctx(body: &FnBody) -> Value {
+    let mut out = to_map(body);
+    out.insert("lines".into(), json!(body_lines(body)));
+    if body.match_expr.is_none() {
+        out.remove("match");
+    }
+    Value::Object(out)
+}
+
+/// Source lines of the statement / field / chain forms (empty for `match`,
+/// whose arms are expanded by the template or backend).
+pub fn body_lines(body: &FnBody) -> Vec<String> {
+    if !body.statements.is_empty() {
+        body.statements
+            .iter()
+            .map(|s| s.trim_end().to_string())
+            .collect()
+    } else if !body.fields.is_empty() {
+        let inits: Vec<String> = body
+            .fields
+            .iter()
+            .map(|f| match &f.value {
+                Some(v) if v != &f.name => format!("{}: {v}", f.name),
+                _ => f.name.clone(),
+            })
+            .collect();
+        vec![format!("Self {{ {} }}", inits.join(", "))]
+    } else if let Some(chain) = &body.chain {
+        let calls: String = chain.calls.iter().map(|c| format!(".{c}")).collect();
+        vec![format!("{}{calls}", chain.base)]
+    } else {
+        Vec::new()
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Helpers
+// -----------------------------------------------------------------------------
+
+fn to_map<T: Serialize>(t: &T) -> Map<String, Value> {
+    match serde_json::to_value(t) {
+        Ok(Value::Object(map)) => map,
+        _ => Map::new(),
+    }
+}
+
+/// Drop absent optionals so `{{#returns}}`-style sections treat them as
+/// missing keys rather than empty strings.
+fn strip_nulls(value: Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .filter(|(_, v)| !v.is_null())
+                .map(|(k, v)| (k, strip_nulls(v)))
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.into_iter().map(strip_nulls).collect()),
+        other => other,
+    }
+}
+
+fn doc_lines(desc: &Option<Doc>) -> Value {
+    json!(desc.as_ref().map(Doc::lines).unwrap_or_default())
+}
+
+fn attr_lines(attrs: &[Attribute]) -> Value {
+    json!(attrs.iter().map(Attribute::render).collect::<Vec<_>>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signatures_and_decls_are_precomputed() {
+        let m: RustModule = serde_yaml::from_str(
+            r#"
+imports:
+  - { path: crate::schema, names: [ForgeRequest, ForgeResponse] }
+  - { path: async_trait, names: [async_trait] }
+items:
+  - item: struct
+    name: Registry
+    derives: [Default]
+    fields:
+      - { name: providers, type: "HashMap<Kind, Arc<dyn Cmd>>" }
+    methods:
+      - name: register
+        receiver: "&mut self"
+        args: [{ name: kind, type: Kind }, { name: cmd, type: "Arc<dyn Cmd>" }]
+        body: { statements: ["self.providers.insert(kind, cmd);"] }
+      - name: new
+        args: [{ name: providers, type: "HashMap<Kind, Arc<dyn Cmd>>" }]
+        returns: Self
+        body: { fields: [{ name: providers }] }
+  - item: trait
+    name: Cmd
+    supertraits: [Send, Sync]
+    methods:
+      - name: execute
+        async: true
+        receiver: "&self"
+        args: [{ name: request, type: "&ForgeRequest" }]
+        returns: ForgeResponse
+"#,
+        )
+        .unwrap();
+        let ctx = normalize(&m);
+
+        assert_eq!(
+            ctx["imports"][0]["decl"],
+            "use crate::schema::{ForgeRequest, ForgeResponse};"
+        );
+        assert_eq!(ctx["imports"][1]["decl"], "use async_trait::async_trait;");
+
+        let s = &ctx["items"][0];
+        assert_eq!(s["is_struct"], true);
+        assert_eq!(s["decl"], "pub struct Registry");
+        assert_eq!(s["derive_attr"], "#[derive(Default)]");
+        assert_eq!(
+            s["fields"][0]["decl"],
+            "pub providers: HashMap<Kind, Arc<dyn Cmd>>"
+        );
+        assert_eq!(
+            s["methods"][0]["signature"],
+            "pub fn register(&mut self, kind: Kind, cmd: Arc<dyn Cmd>)"
+        );
+        assert_eq!(s["methods"][1]["body"]["lines"][0], "Self { providers }");
+        assert!(s["methods"][0].get("returns").is_none());
+
+        let t = &ctx["items"][1];
+        assert_eq!(t["decl"], "pub trait Cmd: Send + Sync");
+        assert_eq!(
+            t["methods"][0]["signature"],
+            "async fn execute(&self, request: &ForgeRequest) -> ForgeResponse"
+        );
+        assert_eq!(t["methods"][0]["params"], "&self, request: &ForgeRequest");
+    }
+}
@@ -0,0 +1,394 @@
+// ============================================================================
+// Forge Template — Rust AST Validation
+// File: ast/validate.rs
+// Role: Semantic checks the serde model cannot express: identifiers, unique
+//       names, receiver vs `self` args, body shapes. All problems are
+//       collected (with their AST path) instead of stopping at the first.
+// ============================================================================
+
+use super::model::*;
+use crate::errors::{AstError, AstErrors};
+use std::collections::HashSet;
+
+/// Validate `module`, returning every problem found.
+pub fn validate(module: &RustModule) -> Result<(), AstErrors> {
+    let mut v = Validator::default();
+    v.module(module);
+    if v.errors.is_empty() {
+        Ok(())
+    } else {
+        Err(AstErrors(v.errors))
+    }
+}
+
+/// True for a plain or raw (`r#type`) Rust identifier.
+pub fn is_ident(name: &str) -> bool {
+    let name = name.strip_prefix("r#").unwrap_or(name);
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && name != "_"
+}
+
+/// True for `a::b::C` style paths (optionally `crate::` / `super::` rooted).
+fn is_path(path: &str) -> bool {
+    !path.is_empty() && path.split("::").all(is_ident)
+}
+
+#[derive(Default)]
+struct Validator {
+    errors: Vec<AstError>,
+}
+
+impl Validator {
+    fn err(&mut self, path: &str, message: impl Into<String>) {
+        self.errors.push(AstError {
+            path: path.to_string(),
+            message: message.into(),
+        });
+    }
+
+    fn ident(&mut self, path: &str, what: &str, name: &str) {
+        if !is_ident(name) {
+            self.err(
+                path,
+                format!("{what} `{name}` is not a valid Rust identifier"),
+            );
+        }
+    }
+
+    fn unique<'a>(&mut self, path: &str, what: &str, names: impl Iterator<Item = &'a str>) {
+        let mut seen = HashSet::new();
+        for name in names {
+            if !seen.insert(name) {
+                self.err(path, format!("duplicate {what} `{name}`"));
+            }
+        }
+    }
+
+    fn non_empty(&mut self, path: &str, what: &str, text: &str) {
+        if text.trim().is_empty() {
+            self.err(path, format!("{what} must not be empty"));
+        }
+    }
+
+    fn attrs(&mut self, path: &str, attrs: &[Attribute]) {
+        for (i, attr) in attrs.iter().enumerate() {
+            let at = format!("{path}.attrs[{i}]");
+            let body = attr.0.trim();
+            if body.is_empty() {
+                self.err(&at, "attribute must not be empty");
+            } else if body.starts_with("#[") || body.starts_with('#') {
+                self.err(
+                    &at,
+                    format!(
+                        "write the attribute body only (`{}`)",
+                        body.trim_start_matches("#[").trim_end_matches(']')
+                    ),
+                );
+            } else if !balanced(body) {
+                self.err(&at, format!("unbalanced brackets in `{body}`"));
+            }
+        }
+    }
+
+    fn derives(&mut self, path: &str, derives: &[String]) {
+        for (i, d) in derives.iter().enumerate() {
+            if !is_path(d) {
+                self.err(
+                    &format!("{path}.derives[{i}]"),
+                    format!("`{d}` is not a derive path"),
+                );
+            }
+        }
+        self.unique(path, "derive", derives.iter().map(String::as_str));
+    }
+
+    // -------------------------------------------------------------------------
+    // Module & items
+    // -------------------------------------------------------------------------
+
+    fn module(&mut self, m: &RustModule) {
+        for (i, u) in m.imports.iter().enumerate() {
+            let at = format!("imports[{i}]");
+            if !is_path(&u.path) {
+                self.err(
+                    &at,
+                    format!("`{}` is not a module path (omit `use` and `;`)", u.path),
+                );
+            }
+            for name in &u.names {
+                if name != "*"
+                    && name != "self"
+                    && !is_ident(name.split(" as ").next().unwrap_or_default())
+                {
+                    self.err(&at, format!("`{name}` is not an importable name"));
+                }
+            }
+        }
+
+        let mut type_names = Vec::new();
+        for (i, item) in m.items.iter().enumerate() {
+            let at = format!("items[{i}]");
+            match item {
+                Item::Struct(s) => {
+                    type_names.push(s.name.as_str());
+                    self.struct_def(&at, s);
+                }
+                Item::Enum(e) => {
+                    type_names.push(e.name.as_str());
+                    self.enum_def(&at, e);
+                }
+                Item::Trait(t) => {
+                    type_names.push(t.name.as_str());
+                    self.trait_def(&at, t);
+                }
+                Item::Impl(imp) => self.impl_def(&at, imp),
+            }
+        }
+        self.unique("items", "item", type_names.into_iter());
+    }
+
+    fn struct_def(&mut self, at: &str, s: &StructDef) {
+        self.ident(at, "struct name", &s.name);
+        self.derives(at, &s.derives);
+        self.attrs(at, &s.attrs);
+        self.fields(at, &s.fields);
+        self.fns(at, &s.methods, false);
+    }
+
+    fn enum_def(&mut self, at: &str, e: &EnumDef) {
+        self.ident(at, "enum name", &e.name);
+        self.derives(at, &e.derives);
+        self.attrs(at, &e.attrs);
+        if e.variants.is_empty() {
+            self.err(at, "enum has no variants");
+        }
+        for (i, variant) in e.variants.iter().enumerate() {
+            let vat = format!("{at}.variants[{i}]");
+            self.ident(&vat, "variant name", &variant.name);
+            self.attrs(&vat, &variant.attrs);
+            self.fields(&vat, &variant.fields);
+        }
+        self.unique(at, "variant", e.variants.iter().map(|v| v.name.as_str()));
+    }
+
+    fn trait_def(&mut self, at: &str, t: &TraitDef) {
+        self.ident(at, "trait name", &t.name);
+        self.attrs(at, &t.attrs);
+        for (i, s) in t.supertraits.iter().enumerate() {
+            self.non_empty(&format!("{at}.supertraits[{i}]"), "supertrait", s);
+        }
+        self.fns(at, &t.methods, true);
+    }
+
+    fn impl_def(&mut self, at: &str, imp: &ImplDef) {
+        self.non_empty(at, "impl target", &imp.target);
+        self.attrs(at, &imp.attrs);
+        self.fns(at, &imp.methods, false);
+    }
+
+    fn fields(&mut self, at: &str, fields: &[FieldDef]) {
+        for (i, f) in fields.iter().enumerate() {
+            let fat = format!("{at}.fields[{i}]");
+            self.ident(&fat, "field name", &f.name);
+            self.non_empty(&fat, "field type", &f.ty);
+            self.attrs(&fat, &f.attrs);
+        }
+        self.unique(at, "field", fields.iter().map(|f| f.name.as_str()));
+    }
+
+    // -------------------------------------------------------------------------
+    // Functions
+    // -------------------------------------------------------------------------
+
+    fn fns(&mut self, at: &str, fns: &[FnDef], in_trait: bool) {
+        for (i, f) in fns.iter().enumerate() {
+            self.fn_def(&format!("{at}.methods[{i}]"), f, in_trait);
+        }
+        self.unique(at, "method", fns.iter().map(|f| f.name.as_str()));
+    }
+
+    fn fn_def(&mut self, at: &str, f: &FnDef, in_trait: bool) {
+        self.ident(at, "fn name", &f.name);
+        self.attrs(at, &f.attrs);
+        for (i, arg) in f.args.iter().enumerate() {
+            let aat = format!("{at}.args[{i}]");
+            if arg.name == "self" {
+                self.err(&aat, "use `receiver` instead of a `self` argument");
+                continue;
+            }
+            self.ident(&aat, "argument name", &arg.name);
+            self.non_empty(&aat, "argument type", &arg.ty);
+        }
+        self.unique(at, "argument", f.args.iter().map(|a| a.name.as_str()));
+        if let Some(ret) = &f.returns {
+            self.non_empty(at, "return type", ret);
+        }
+
+        if in_trait && f.body.is_some() {
+            self.err(at, "trait methods take `default_body`, not `body`");
+        }
+        if !in_trait && f.default_body.is_some() {
+            self.err(at, "`default_body` is only allowed on trait methods");
+        }
+        if let Some(body) = &f.body {
+            self.body(&format!("{at}.body"), body);
+        }
+    }
+
+    fn body(&mut self, at: &str, body: &FnBody) {
+        let forms = [
+            !body.statements.is_empty(),
+            !body.fields.is_empty(),
+            body.chain.is_some(),
+            body.match_expr.is_some(),
+        ];
+        match forms.iter().filter(|&&used| used).count() {
+            0 => self.err(
+                at,
+                "empty body (use one of statements, fields, chain, match)",
+            ),
+            1 => {}
+            _ => self.err(
+                at,
+                "body mixes several forms (statements, fields, chain, match)",
+            ),
+        }
+
+        for (i, init) in body.fields.iter().enumerate() {
+            self.ident(&format!("{at}.fields[{i}]"), "field name", &init.name);
+        }
+        self.unique(at, "field", body.fields.iter().map(|f| f.name.as_str()));
+        if let Some(chain) = &body.chain {
+            self.non_empty(&format!("{at}.chain"), "chain base", &chain.base);
+        }
+        if let Some(m) = &body.match_expr {
+            self.non_empty(&format!("{at}.match"), "match variable", &m.variable);
+            if m.arms.is_empty() {
+                self.err(&format!("{at}.match"), "match has no arms");
+            }
+            for (i, arm) in m.arms.iter().enumerate() {
+                let aat = format!("{at}.match.arms[{i}]");
+                self.non_empty(&aat, "pattern", &arm.pattern);
+                match (&arm.variant, &arm.expr) {
+                    (None, None) => self.err(&aat, "arm needs `variant` or `expr`"),
+                    (Some(v), _) => self.ident(&aat, "variant", v),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+fn balanced(text: &str) -> bool {
+    let mut stack = Vec::new();
+    let mut in_str = false;
+    let mut escaped = false;
+    for c in text.chars() {
+        if in_str {
+            match (escaped, c) {
+                (true, _) => escaped = false,
+                (false, '\\') => escaped = true,
+                (false, '"') => in_str = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => in_str = true,
+            '(' | '[' | '{' => stack.push(c),
+            ')' | ']' | '}' => {
+                let open = match c {
+                    ')' => '(',
+                    ']' => '[',
+                    _ => '{',
+                };
+                if stack.pop() != Some(open) {
+                    return false;
+                }
+            }
+            _ => {}
+        }
+    }
+    stack.is_empty() && !in_str
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(yaml: &str) -> RustModule {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    #[test]
+    fn collects_every_problem_with_its_path() {
+        let m = parse(
+            r##"
+items:
+  - item: struct
+    name: Bad Name
+    attrs: ["#[derive(Debug)]", "serde(rename = \"x\""]
+    fields:
+      - { name: a, type: u8 }
+      - { name: a, type: "" }
+    methods:
+      - name: run
+        args: [{ name: self, type: "&self" }]
+        body: { statements: [x], fields: [{ name: a }] }
+  - item: enum
+    name: Empty
+"##,
+        );
+        let errs = validate(&m).unwrap_err().0;
+        let paths: Vec<_> = errs.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "items[0]",
+                "items[0].attrs[0]",
+                "items[0].attrs[1]",
+                "items[0].fields[1]",
+                "items[0]",
+                "items[0].methods[0].args[0]",
+                "items[0].methods[0].body",
+                "items[1]",
+            ]
+        );
+        assert!(errs[5].message.contains("receiver"));
+    }
+
+    #[test]
+    fn trait_bodies_and_identifiers() {
+        let m = parse(
+            r#"
+items:
+  - item: trait
+    name: Cmd
+    methods:
+      - { name: a, body: { statements: [x] } }
+      - { name: b, default_body: "1", receiver: "&self", returns: u8 }
+  - item: impl
+    target: Foo
+    methods:
+      - { name: c, default_body: "1" }
+"#,
+        );
+        let errs = validate(&m).unwrap_err().0;
+        assert_eq!(errs.len(), 2);
+        assert!(is_ident("r#type") && is_ident("_x") && !is_ident("_") && !is_ident("9a"));
+        assert!(balanced(r#"serde(rename = ")")"#) && !balanced("a(b]"));
+    }
+
+    #[test]
+    fn unknown_keys_are_rejected() {
+        let err = serde_yaml::from_str::<RustModule>(
+            "items:\n  - item: struct\n    name: A\n    feilds: []\n",
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("feilds"));
+    }
+}
@@ -0,0 +1,435 @@
+// ============================================================================
+// Forge Template — Gherkin Import / Export
+// File: bdd/gherkin.rs
+// Role: Converts between `.feature` files and the BDD scenario YAML.
+//       Step text maps to snake_case step IDs word by word through
+//       `ValuesTemplateExpansion`, with the keyword as prefix:
+//         Given x → set_up_x   When x → execute_x   Then x → verify_x
+//         And / But x → and_<previous prefix>x      * x → x
+//       `<placeholders>` of a Scenario Outline become step args backed by the
+//       `examples` table. `@crate:<name>` / `@test_mod:<name>` feature tags
+//       carry the spec settings; every other tag is preserved as-is.
+// ============================================================================
+
+use std::fmt::Write as _;
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+
+use super::{validate, BddSpec, Examples, Scenario, Step};
+use crate::context::values_template_expansion::ValuesTemplateExpansion as Naming;
+
+const CRATE_TAG: &str = "crate:";
+const TEST_MOD_TAG: &str = "test_mod:";
+
+/// Keyword ↔ step-id prefix
+const KEYWORDS: [(&str, &str); 3] = [("Given", "set_up_"), ("When", "execute_"), ("Then", "verify_")];
+
+// ----------------------------------------------------------------------------
+// Import
+// ----------------------------------------------------------------------------
+
+/// Parse a `.feature` file into a spec. `default_crate` is used when the
+/// feature has no `@crate:<name>` tag. Facade / glue paths follow the
+/// `tests/scenario_<name>.rs` / `tests/glue/<name>.rs` convention.
+pub fn parse_feature(text: &str, default_crate: Option<&str>) -> Result<BddSpec> {
+    let mut parser = Parser::default();
+    for (i, raw) in text.lines().enumerate() {
+        parser.line(raw.trim()).map_err(|e| e.context(format!("line {}: {}", i + 1, raw.trim())))?;
+    }
+    let mut spec = parser.finish(default_crate)?;
+    for scenario in &mut spec.scenarios {
+        scenario.description = scenario.description.trim().to_string();
+    }
+    spec.description = spec.description.trim().to_string();
+    validate(&spec)?;
+    Ok(spec)
+}
+
+#[derive(Default)]
+struct Parser {
+    spec: Option<BddSpec>,
+    tags: Vec<String>,
+    background: Vec<Step>,
+    in_background: bool,
+    /// Prefix of the last Given/When/Then, for And/But
+    last_prefix: Option<&'static str>,
+    /// Inside `Examples:` (table rows belong to it)
+    in_examples: bool,
+}
+
+impl Parser {
+    fn line(&mut self, line: &str) -> Result<()> {
+        if line.is_empty() || line.starts_with('#') {
+            return Ok(());
+        }
+        if line.starts_with('@') {
+            self.tags.extend(line.split_whitespace().map(|t| t.trim_start_matches('@').to_string()));
+            return Ok(());
+        }
+        if let Some(title) = keyword(line, &["Feature"]) {
+            if self.spec.is_some() {
+                bail!("only one Feature per file");
+            }
+            let mut spec = BddSpec {
+                crate_name: String::new(),
+                test_mod: super::default_test_mod(),
+                feature: Some(title.to_string()),
+                description: String::new(),
+                tags: Vec::new(),
+                scenarios: Vec::new(),
+            };
+            for tag in std::mem::take(&mut self.tags) {
+                if let Some(name) = tag.strip_prefix(CRATE_TAG) {
+                    spec.crate_name = name.to_string();
+                } else if let Some(name) = tag.strip_prefix(TEST_MOD_TAG) {
+                    spec.test_mod = name.to_string();
+                } else {
+                    spec.tags.push(tag);
+                }
+            }
+            self.spec = Some(spec);
+            return Ok(());
+        }
+        let Some(spec) = self.spec.as_mut() else {
+            bail!("expected `Feature:` first");
+        };
+        if keyword(line, &["Rule"]).is_some() {
+            bail!("`Rule:` blocks are not supported");
+        }
+        if line.starts_with("\"\"\"") || line.starts_with("```") {
+            bail!("step doc strings are not supported");
+        }
+        if keyword(line, &["Background"]).is_some() {
+            self.in_background = true;
+            self.last_prefix = None;
+            return Ok(());
+        }
+        if let Some(title) = keyword(line, &["Scenario Outline", "Scenario Template", "Scenario", "Example"]) {
+            let name = snake(title);
+            spec.scenarios.push(Scenario {
+                facade_file: PathBuf::from(format!("tests/scenario_{name}.rs")),
+                glue_file: PathBuf::from(format!("tests/glue/{name}.rs")),
+                name,
+                description: String::new(),
+                tags: std::mem::take(&mut self.tags),
+                steps: self.background.clone(),
+                examples: None,
+            });
+            self.in_background = false;
+            self.in_examples = false;
+            self.last_prefix = None;
+            return Ok(());
+        }
+        if keyword(line, &["Examples", "Scenarios"]).is_some() {
+            let Some(scenario) = spec.scenarios.last_mut() else {
+                bail!("`Examples:` outside a scenario");
+            };
+            if scenario.examples.is_some() {
+                bail!("only one `Examples:` table per scenario is supported");
+            }
+            self.tags.clear();
+            self.in_examples = true;
+            return Ok(());
+        }
+        if let Some(cells) = line.strip_prefix('|') {
+            let cells: Vec<String> = cells.trim_end_matches('|').split('|').map(|c| c.trim().to_string()).collect();
+            let scenario = spec.scenarios.last_mut().filter(|_| self.in_examples);
+            let Some(scenario) = scenario else {
+                bail!("step data tables are not supported");
+            };
+            match &mut scenario.examples {
+                None => scenario.examples = Some(Examples { columns: cells, rows: Vec::new() }),
+                Some(examples) => examples.rows.push(cells),
+            }
+            return Ok(());
+        }
+        if let Some(step) = parse_step(line, &mut self.last_prefix)? {
+            if self.in_background {
+                self.background.push(step);
+            } else if let Some(scenario) = spec.scenarios.last_mut() {
+                scenario.steps.push(step);
+            }
+            return Ok(());
+        }
+
+        // free text: description of the feature or the current scenario
+        let target = match spec.scenarios.last_mut() {
+            Some(scenario) if scenario.steps.len() == self.background.len() => &mut scenario.description,
+            Some(_) => bail!("unexpected text after steps"),
+            None => &mut spec.description,
+        };
+        target.push_str(line);
+        target.push('\n');
+        Ok(())
+    }
+
+    fn finish(self, default_crate: Option<&str>) -> Result<BddSpec> {
+        let Some(mut spec) = self.spec else {
+            bail!("no `Feature:` found");
+        };
+        if spec.crate_name.is_empty() {
+            match default_crate {
+                Some(name) => spec.crate_name = name.to_string(),
+                None => bail!("no `@{CRATE_TAG}<name>` feature tag; pass the crate explicitly"),
+            }
+        }
+        Ok(spec)
+    }
+}
+
+/// A step line as a [`Step`]; `None` if `line` is not a step.
+fn parse_step(line: &str, last_prefix: &mut Option<&'static str>) -> Result<Option<Step>> {
+    let (kw, text) = match line.split_once(char::is_whitespace) {
+        Some((kw, text)) => (kw, text.trim()),
+        None => return Ok(None),
+    };
+    let prefix = match kw {
+        "*" => String::new(),
+        "And" | "But" => format!("and_{}", last_prefix.unwrap_or_default()),
+        _ => match KEYWORDS.iter().find(|(k, _)| *k == kw) {
+            Some((_, prefix)) => {
+                *last_prefix = Some(prefix);
+                prefix.to_string()
+            }
+            None => return Ok(None),
+        },
+    };
+
+    // `<column>` placeholders become args; their names stay in the id
+    let mut args = Vec::new();
+    let mut words = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('<') {
+        let Some(len) = rest[start..].find('>') else {
+            bail!("unclosed `<` placeholder");
+        };
+        let column = &rest[start + 1..start + len];
+        words.push_str(&rest[..start]);
+        words.push_str(column);
+        args.push(column.to_string());
+        rest = &rest[start + len + 1..];
+    }
+    words.push_str(rest);
+
+    let name = format!("{prefix}{}", snake(&words));
+    Ok(Some(if args.is_empty() {
+        Step::Plain(name)
+    } else {
+        Step::Param { step: name, args }
+    }))
+}
+
+/// `Keyword: rest` → `rest` for any of `keywords`.
+fn keyword<'a>(line: &'a str, keywords: &[&str]) -> Option<&'a str> {
+    keywords
+        .iter()
+        .find_map(|k| line.strip_prefix(k)?.trim_start().strip_prefix(':'))
+        .map(str::trim)
+}
+
+/// Step / scenario text → snake_case id, one word at a time.
+fn snake(text: &str) -> String {
+    text.split_whitespace()
+        .map(Naming::sanitize)
+        .filter(|w| !w.is_empty())
+        .map(|w| Naming::to_snake_case(&w, Naming::detect_format(&w)).to_ascii_lowercase())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+// ----------------------------------------------------------------------------
+// Export
+// ----------------------------------------------------------------------------
+
+/// Render a spec as a `.feature` file. Facade / glue paths are not part of
+/// Gherkin; importing the output restores the conventional ones.
+pub fn to_feature(spec: &BddSpec) -> String {
+    let mut out = String::new();
+    let mut feature_tags = vec![format!("@{CRATE_TAG}{}", spec.crate_name)];
+    if spec.test_mod != super::default_test_mod() {
+        feature_tags.push(format!("@{TEST_MOD_TAG}{}", spec.test_mod));
+    }
+    feature_tags.extend(spec.tags.iter().map(|t| format!("@{t}")));
+    let title = spec.feature.clone().unwrap_or_else(|| spec.crate_name.clone());
+    let _ = writeln!(out, "{}\nFeature: {title}", feature_tags.join(" "));
+    for line in spec.description.lines() {
+        let _ = writeln!(out, "  {line}");
+    }
+
+    for scenario in &spec.scenarios {
+        out.push('\n');
+        if !scenario.tags.is_empty() {
+            let tags: Vec<String> = scenario.tags.iter().map(|t| format!("@{t}")).collect();
+            let _ = writeln!(out, "  {}", tags.join(" "));
+        }
+        let kind = if scenario.examples.is_some() { "Scenario Outline" } else { "Scenario" };
+        let _ = writeln!(out, "  {kind}: {}", sentence(&scenario.name));
+        for line in scenario.description.lines() {
+            let _ = writeln!(out, "    {line}");
+        }
+
+        let mut last_prefix: Option<&str> = None;
+        for step in &scenario.steps {
+            let (kw, rest) = step_keyword(step.name(), &mut last_prefix);
+            let _ = writeln!(out, "    {kw} {}", step_text(rest, step.args()));
+        }
+
+        if let Some(examples) = &scenario.examples {
+            let _ = writeln!(out, "\n    Examples:");
+            let widths: Vec<usize> = (0..examples.columns.len())
+                .map(|i| {
+                    std::iter::once(&examples.columns)
+                        .chain(&examples.rows)
+                        .map(|row| row.get(i).map_or(0, |c| c.chars().count()))
+                        .max()
+                        .unwrap_or(0)
+                })
+                .collect();
+            for row in std::iter::once(&examples.columns).chain(&examples.rows) {
+                let cells: Vec<String> = row
+                    .iter()
+                    .zip(&widths)
+                    .map(|(c, w)| format!(" {c}{} ", " ".repeat(w - c.chars().count())))
+                    .collect();
+                let _ = writeln!(out, "      |{}|", cells.join("|"));
+            }
+        }
+    }
+    out
+}
+
+/// Keyword for a step id plus the id without its prefix.
+fn step_keyword<'a>(id: &'a str, last_prefix: &mut Option<&'static str>) -> (&'static str, &'a str) {
+    for (kw, prefix) in KEYWORDS {
+        if let Some(rest) = id.strip_prefix(prefix) {
+            *last_prefix = Some(prefix);
+            return (kw, rest);
+        }
+    }
+    if let (Some(prefix), Some(rest)) = (*last_prefix, id.strip_prefix("and_")) {
+        if let Some(rest) = rest.strip_prefix(prefix) {
+            return ("And", rest);
+        }
+    }
+    ("*", id)
+}
+
+/// `file_containing_text` with args `[text]` → `file containing <text>`.
+fn step_text(rest: &str, args: &[String]) -> String {
+    let mut words: Vec<String> = rest.split('_').filter(|w| !w.is_empty()).map(str::to_string).collect();
+    for arg in args {
+        let arg_words: Vec<&str> = arg.split('_').collect();
+        let found = (0..words.len().saturating_sub(arg_words.len() - 1))
+            .rev()
+            .find(|&i| words[i..i + arg_words.len()].iter().zip(&arg_words).all(|(a, b)| a == b));
+        match found {
+            Some(i) => {
+                words.splice(i..i + arg_words.len(), [format!("<{arg}>")]);
+            }
+            None => words.push(format!("<{arg}>")),
+        }
+    }
+    words.join(" ")
+}
+
+/// `read_simple_file` → `Read simple file`.
+fn sentence(snake: &str) -> String {
+    let text = snake.replace('_', " ");
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(c) => c.to_uppercase().collect::<String>() + chars.as_str(),
+        None => text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FEATURE: &str = r#"
+@crate:forge-fs @fs
+Feature: File read
+  Reading files from disk.
+
+  Background:
+    Given a clean temp dir
+
+  @smoke
+  Scenario: Read simple file contents
+    Reads a small text file.
+    Given temp file with known text
+    When read file contents
+    Then contents match expected
+    And no error
+
+  Scenario Outline: Read encoded file
+    Given file containing <text> as <encoding>
+    When read file contents
+    Then result equals <text>
+
+    Examples:
+      | text  | encoding |
+      | héllo | utf8     |
+      | ""    | ascii    |
+"#;
+
+    #[test]
+    fn imports_steps_outlines_and_tags() {
+        let spec = parse_feature(FEATURE, None).unwrap();
+        assert_eq!(spec.crate_name, "forge-fs");
+        assert_eq!(spec.tags, ["fs"]);
+        assert_eq!(spec.description, "Reading files from disk.");
+
+        let simple = &spec.scenarios[0];
+        assert_eq!(simple.name, "read_simple_file_contents");
+        assert_eq!(simple.tags, ["smoke"]);
+        assert_eq!(simple.description, "Reads a small text file.");
+        assert_eq!(simple.facade_file, PathBuf::from("tests/scenario_read_simple_file_contents.rs"));
+        let ids: Vec<&str> = simple.steps.iter().map(Step::name).collect();
+        assert_eq!(
+            ids,
+            [
+                "set_up_a_clean_temp_dir",
+                "set_up_temp_file_with_known_text",
+                "execute_read_file_contents",
+                "verify_contents_match_expected",
+                "and_verify_no_error"
+            ]
+        );
+
+        let outline = &spec.scenarios[1];
+        assert_eq!(
+            outline.steps[1],
+            Step::Param { step: "set_up_file_containing_text_as_encoding".into(), args: vec!["text".into(), "encoding".into()] }
+        );
+        let examples = outline.examples.as_ref().unwrap();
+        assert_eq!(examples.columns, ["text", "encoding"]);
+        assert_eq!(examples.rows[0], ["héllo", "utf8"]);
+    }
+
+    #[test]
+    fn export_round_trips_through_import() {
+        let spec = parse_feature(FEATURE, None).unwrap();
+        let feature = to_feature(&spec);
+        assert!(feature.starts_with("@crate:forge-fs @fs\nFeature: File read\n"), "{feature}");
+        assert!(feature.contains("  @smoke\n  Scenario: Read simple file contents\n"), "{feature}");
+        assert!(feature.contains("    Then contents match expected\n    And no error\n"), "{feature}");
+        assert!(feature.contains("    Given file containing <text> as <encoding>\n"), "{feature}");
+        assert!(feature.contains("      | text  | encoding |\n      | héllo | utf8     |\n"), "{feature}");
+        assert_eq!(parse_feature(&feature, None).unwrap(), spec);
+
+        // the existing YAML specs survive a round trip too
+        let yaml = include_str!("../../../../resources/bdd/forge-fs/tree_builder.yaml");
+        let spec: BddSpec = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(parse_feature(&to_feature(&spec), None).unwrap().scenarios, spec.scenarios);
+    }
+
+    #[test]
+    fn unsupported_constructs_name_the_line() {
+        let err = |text: &str| format!("{:#}", parse_feature(text, Some("c")).unwrap_err());
+        assert!(err("Feature: f\n Scenario: s\n  Given x\n  | a |\n").contains("line 4"));
+        assert!(err("Scenario: s\n").contains("expected `Feature:` first"));
+        assert!(parse_feature("Feature: f\n", None).unwrap_err().to_string().contains("@crate:"));
+    }
+}
@@ -0,0 +1,529 @@
+// ============================================================================
+// Forge Template — BDD Scenario Generator
+// File: bdd/mod.rs
+// Role: Turns `resources/bdd/<crate>/*.yaml` scenario specs into one facade
+//       integration test per scenario (steps called in order on a shared
+//       `World`) plus a glue module of step functions. Glue files are owned
+//       by the developer: regeneration keeps every existing body and only
+//       appends stubs for new steps. Steps whose body still calls `todo!` /
+//       `unimplemented!` are reported as pending and `#[ignore]` the facade.
+//       Specs can also be imported from / exported to Gherkin (`gherkin`).
+// ============================================================================
+
+pub mod gherkin;
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use once_cell::sync::Lazy;
+use proc_macro2::{TokenStream, TokenTree};
+use quote::ToTokens;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::ast::validate::is_ident;
+use crate::template::Template;
+
+static FACADE: Lazy<Template> = Lazy::new(|| embedded(include_str!("templates/facade.mustache")));
+static GLUE: Lazy<Template> = Lazy::new(|| embedded(include_str!("templates/glue.mustache")));
+static STEP: Lazy<Template> = Lazy::new(|| embedded(include_str!("templates/step.mustache")));
+
+fn embedded(text: &str) -> Template {
+    Template::compile(text).expect("embedded BDD template compiles").expand(false)
+}
+
+// ----------------------------------------------------------------------------
+// Spec model
+// ----------------------------------------------------------------------------
+
+/// One `resources/bdd/**.yaml` file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BddSpec {
+    /// Crate the tests are generated into
+    #[serde(rename = "crate")]
+    pub crate_name: String,
+    /// Module name the facade binds the glue file to
+    #[serde(default = "default_test_mod", skip_serializing_if = "is_default_test_mod")]
+    pub test_mod: String,
+    /// Gherkin `Feature:` title
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feature: Option<String>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub description: String,
+    /// Feature-level tags, without `@`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub scenarios: Vec<Scenario>,
+}
+
+fn default_test_mod() -> String {
+    "glue".into()
+}
+
+fn is_default_test_mod(name: &str) -> bool {
+    name == "glue"
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Scenario {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub description: String,
+    /// Tags without `@`; listed on the facade test
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Relative to the crate directory, e.g. `tests/scenario_x.rs`
+    pub facade_file: PathBuf,
+    /// Relative to the crate directory, e.g. `tests/glue/x.rs`
+    pub glue_file: PathBuf,
+    /// Step functions, called in order
+    pub steps: Vec<Step>,
+    /// Scenario Outline rows; every row becomes its own test
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub examples: Option<Examples>,
+}
+
+/// A step: a bare function name, or one taking example columns as `&str` args.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Step {
+    Plain(String),
+    Param {
+        step: String,
+        /// Example column names, passed in this order
+        args: Vec<String>,
+    },
+}
+
+impl Step {
+    pub fn name(&self) -> &str {
+        match self {
+            Step::Plain(name) | Step::Param { step: name, .. } => name,
+        }
+    }
+
+    pub fn args(&self) -> &[String] {
+        match self {
+            Step::Plain(_) => &[],
+            Step::Param { args, .. } => args,
+        }
+    }
+}
+
+/// `Examples:` table of a Scenario Outline.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Examples {
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+/// Parse and validate a spec file.
+pub fn load_spec(path: &Path) -> Result<BddSpec> {
+    let text = fs::read_to_string(path).with_context(|| format!("read BDD spec {}", path.display()))?;
+    if is_feature(path) {
+        return gherkin::parse_feature(&text, None).with_context(|| format!("parse BDD feature {}", path.display()));
+    }
+    let spec: BddSpec =
+        serde_yaml::from_str(&text).with_context(|| format!("parse BDD spec {}", path.display()))?;
+    validate(&spec).with_context(|| format!("invalid BDD spec {}", path.display()))?;
+    Ok(spec)
+}
+
+/// Write `spec` as YAML, or as Gherkin for a `.feature` path.
+pub fn save_spec(spec: &BddSpec, path: &Path) -> Result<()> {
+    let text = if is_feature(path) { gherkin::to_feature(spec) } else { serde_yaml::to_string(spec)? };
+    fs::write(path, text).with_context(|| format!("write BDD spec {}", path.display()))
+}
+
+pub(crate) fn is_feature(path: &Path) -> bool {
+    path.extension().is_some_and(|e| e == "feature")
+}
+
+pub(crate) fn validate(spec: &BddSpec) -> Result<()> {
+    let mut problems = Vec::new();
+    if !is_ident(&spec.test_mod) {
+        problems.push(format!("test_mod `{}` is not a Rust identifier", spec.test_mod));
+    }
+    let mut names = BTreeSet::new();
+    for (i, s) in spec.scenarios.iter().enumerate() {
+        if !is_ident(&s.name) {
+            problems.push(format!("scenarios[{i}].name `{}` is not a Rust identifier", s.name));
+        }
+        if !names.insert(&s.name) {
+            problems.push(format!("scenarios[{i}].name `{}` is duplicated", s.name));
+        }
+        if s.steps.is_empty() {
+            problems.push(format!("scenarios[{i}] `{}` has no steps", s.name));
+        }
+        for (j, step) in s.steps.iter().enumerate() {
+            if !is_ident(step.name()) {
+                problems.push(format!("scenarios[{i}].steps[{j}] `{}` is not a Rust identifier", step.name()));
+            }
+            for arg in step.args() {
+                let known = s.examples.as_ref().is_some_and(|e| e.columns.contains(arg));
+                if !known {
+                    problems.push(format!("scenarios[{i}].steps[{j}] arg `{arg}` is not an examples column"));
+                } else if !is_ident(arg) {
+                    problems.push(format!("scenarios[{i}].steps[{j}] arg `{arg}` is not a Rust identifier"));
+                }
+            }
+        }
+        if let Some(examples) = &s.examples {
+            if examples.rows.is_empty() {
+                problems.push(format!("scenarios[{i}].examples has no rows"));
+            }
+            for (r, row) in examples.rows.iter().enumerate() {
+                if row.len() != examples.columns.len() {
+                    problems.push(format!(
+                        "scenarios[{i}].examples.rows[{r}] has {} cells, expected {}",
+                        row.len(),
+                        examples.columns.len()
+                    ));
+                }
+            }
+        }
+    }
+    if !problems.is_empty() {
+        bail!("{}", problems.join("\n"));
+    }
+    Ok(())
+}
+
+// ----------------------------------------------------------------------------
+// Generation
+// ----------------------------------------------------------------------------
+
+/// A file the generator wants on disk.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeneratedFile {
+    pub path: PathBuf,
+    pub contents: String,
+    /// Differs from what is on disk (or the file is new)
+    pub changed: bool,
+}
+
+/// Step status of one scenario after generation.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ScenarioReport {
+    pub name: String,
+    pub steps: usize,
+    /// Steps whose glue body still calls `todo!` / `unimplemented!`
+    pub pending: Vec<String>,
+    /// Stubs appended to the glue file by this run
+    pub added: Vec<String>,
+    /// Public glue functions the spec no longer mentions
+    pub orphaned: Vec<String>,
+}
+
+/// Result of [`generate`]: files to write plus the step report.
+#[derive(Debug, Clone, Default)]
+pub struct Generation {
+    pub files: Vec<GeneratedFile>,
+    pub scenarios: Vec<ScenarioReport>,
+}
+
+impl Generation {
+    /// Write changed files; returns their paths.
+    pub fn write(&self) -> Result<Vec<PathBuf>> {
+        let mut written = Vec::new();
+        for file in self.files.iter().filter(|f| f.changed) {
+            if let Some(parent) = file.path.parent() {
+                fs::create_dir_all(parent).with_context(|| format!("create {}", parent.display()))?;
+            }
+            fs::write(&file.path, &file.contents).with_context(|| format!("write {}", file.path.display()))?;
+            written.push(file.path.clone());
+        }
+        Ok(written)
+    }
+
+    pub fn pending_steps(&self) -> usize {
+        self.scenarios.iter().map(|s| s.pending.len()).sum()
+    }
+}
+
+impl fmt::Display for Generation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let total: usize = self.scenarios.iter().map(|s| s.steps).sum();
+        for s in &self.scenarios {
+            let mark = if s.pending.is_empty() { "✅" } else { "⏳" };
+            writeln!(f, "{mark} {} ({}/{} steps implemented)", s.name, s.steps - s.pending.len(), s.steps)?;
+            for step in &s.pending {
+                let new = if s.added.contains(step) { " (new stub)" } else { "" };
+                writeln!(f, "     unimplemented: {step}{new}")?;
+            }
+            for step in &s.orphaned {
+                writeln!(f, "     not in spec:   {step}")?;
+            }
+        }
+        write!(f, "{} of {total} steps unimplemented", self.pending_steps())
+    }
+}
+
+/// Plan the facade and glue files of every scenario of `spec` under
+/// `crate_dir`. `spec_label` is quoted in the generated banners.
+pub fn generate(spec: &BddSpec, spec_label: &str, crate_dir: &Path) -> Result<Generation> {
+    let mut generation = Generation::default();
+    for scenario in &spec.scenarios {
+        let glue_path = crate_dir.join(&scenario.glue_file);
+        let existing = fs::read_to_string(&glue_path).ok();
+        let (glue, report) = glue_file(scenario, spec_label, existing.as_deref())
+            .with_context(|| format!("glue file {}", glue_path.display()))?;
+
+        let facade_path = crate_dir.join(&scenario.facade_file);
+        let facade_dir = scenario.facade_file.parent().unwrap_or(Path::new(""));
+        let facade = FACADE.render(&json!({
+            "name": scenario.name,
+            "spec": spec_label,
+            "glue_file": slash_path(&scenario.glue_file),
+            "glue_path": slash_path(&relative_to(facade_dir, &scenario.glue_file)),
+            "test_mod": spec.test_mod,
+            "cases": test_cases(scenario, &report.pending),
+        }))?;
+
+        for (path, contents) in [(facade_path, facade), (glue_path, glue)] {
+            let changed = fs::read_to_string(&path).map_or(true, |old| old != contents);
+            generation.files.push(GeneratedFile { path, contents, changed });
+        }
+        generation.scenarios.push(report);
+    }
+    Ok(generation)
+}
+
+/// One `#[test]` per scenario, or per example row of an outline.
+fn test_cases(scenario: &Scenario, pending: &[String]) -> Vec<serde_json::Value> {
+    let tags = scenario.tags.iter().map(|t| format!("@{t}")).collect::<Vec<_>>().join(" ");
+    let case = |fn_name: String, description: String, row: Option<&[String]>| {
+        let calls: Vec<_> = scenario
+            .steps
+            .iter()
+            .map(|step| {
+                let args: String = step
+                    .args()
+                    .iter()
+                    .map(|arg| {
+                        let column = scenario.examples.as_ref().and_then(|e| e.columns.iter().position(|c| c == arg));
+                        let value = row.zip(column).and_then(|(row, i)| row.get(i)).map_or("", String::as_str);
+                        format!(", {value:?}")
+                    })
+                    .collect();
+                json!({ "name": step.name(), "args": args })
+            })
+            .collect();
+        json!({
+            "fn_name": fn_name,
+            "description": description,
+            "tags": tags,
+            "pending": pending.join(", "),
+            "calls": calls,
+        })
+    };
+
+    match &scenario.examples {
+        None => vec![case(scenario.name.clone(), scenario.description.clone(), None)],
+        Some(examples) => examples
+            .rows
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let values: Vec<String> =
+                    examples.columns.iter().zip(row).map(|(c, v)| format!("{c} = {v:?}")).collect();
+                let description = match scenario.description.as_str() {
+                    "" => format!("Example {}: {}", i + 1, values.join(", ")),
+                    d => format!("{d} (example {}: {})", i + 1, values.join(", ")),
+                };
+                case(format!("{}_example_{}", scenario.name, i + 1), description, Some(row))
+            })
+            .collect(),
+    }
+}
+
+/// New glue text (existing text + appended stubs) and the step report.
+fn glue_file(scenario: &Scenario, spec_label: &str, existing: Option<&str>) -> Result<(String, ScenarioReport)> {
+    let mut text = match existing {
+        Some(text) => text.to_string(),
+        None => GLUE.render(&json!({
+            "name": scenario.name,
+            "spec": spec_label,
+            "facade_file": slash_path(&scenario.facade_file),
+        }))?,
+    };
+    let defined = match existing {
+        Some(text) => glue_functions(text)?,
+        None => Vec::new(),
+    };
+
+    let mut report = ScenarioReport {
+        name: scenario.name.clone(),
+        steps: scenario.steps.len(),
+        ..Default::default()
+    };
+    let mut seen = BTreeSet::new();
+    for step in &scenario.steps {
+        let name = step.name().to_string();
+        if !seen.insert(name.clone()) {
+            continue; // a step may run more than once
+        }
+        match defined.iter().find(|f| f.name == name) {
+            Some(f) if f.pending => report.pending.push(name),
+            Some(_) => {}
+            None => {
+                if !text.ends_with('\n') {
+                    text.push('\n');
+                }
+                let params: String = step.args().iter().map(|a| format!(", _{a}: &str")).collect();
+                text.push_str(&STEP.render(&json!({ "name": name, "params": params, "scenario": scenario.name }))?);
+                report.added.push(name.clone());
+                report.pending.push(name);
+            }
+        }
+    }
+    report.steps = seen.len();
+    report.orphaned = defined
+        .into_iter()
+        .filter(|f| f.public && !seen.contains(&f.name))
+        .map(|f| f.name)
+        .collect();
+    Ok((text, report))
+}
+
+struct GlueFn {
+    name: String,
+    public: bool,
+    pending: bool,
+}
+
+/// Top-level functions of an existing glue file.
+fn glue_functions(text: &str) -> Result<Vec<GlueFn>> {
+    let file = syn::parse_file(text).context("existing glue file is not valid Rust; fix it before regenerating")?;
+    Ok(file
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            syn::Item::Fn(f) => Some(GlueFn {
+                name: f.sig.ident.to_string(),
+                public: matches!(f.vis, syn::Visibility::Public(_)),
+                pending: calls_pending_macro(f.block.to_token_stream()),
+            }),
+            _ => None,
+        })
+        .collect())
+}
+
+/// Whether `tokens` invoke `todo!` or `unimplemented!` anywhere.
+fn calls_pending_macro(tokens: TokenStream) -> bool {
+    let mut iter = tokens.into_iter().peekable();
+    while let Some(tt) = iter.next() {
+        match tt {
+            TokenTree::Ident(ident) if ident == "todo" || ident == "unimplemented" => {
+                if matches!(iter.peek(), Some(TokenTree::Punct(p)) if p.as_char() == '!') {
+                    return true;
+                }
+            }
+            TokenTree::Group(group) if calls_pending_macro(group.stream()) => return true,
+            _ => {}
+        }
+    }
+    false
+}
+
+/// `to` relative to directory `from` (both relative to the same root).
+fn relative_to(from: &Path, to: &Path) -> PathBuf {
+    let from: Vec<Component> = from.components().collect();
+    let to_parts: Vec<Component> = to.components().collect();
+    let common = from.iter().zip(&to_parts).take_while(|(a, b)| a == b).count();
+    let mut out: PathBuf = from[common..].iter().map(|_| Component::ParentDir).collect();
+    out.extend(&to_parts[common..]);
+    out
+}
+
+/// Forward-slash form for `#[path]` attributes and banners.
+fn slash_path(path: &Path) -> String {
+    path.components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec() -> BddSpec {
+        serde_yaml::from_str(
+            r#"
+crate: demo
+scenarios:
+  - name: read_file
+    description: "Reads a file"
+    facade_file: tests/scenario_read_file.rs
+    glue_file: tests/glue/read_file.rs
+    steps: [set_up_file, execute_read, verify_contents]
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn generates_facade_and_glue_stubs() {
+        let dir = tempfile::tempdir().unwrap();
+        let generation = generate(&spec(), "bdd/demo.yaml", dir.path()).unwrap();
+        let facade = &generation.files[0];
+        assert!(facade.contents.contains("#[path = \"glue/read_file.rs\"]\nmod glue;\n"), "{}", facade.contents);
+        assert!(facade.contents.contains(
+            "/// Reads a file\n#[test]\n#[ignore = \"pending steps: set_up_file, execute_read, verify_contents\"]\nfn read_file() {\n    let mut world = glue::World::default();\n    glue::set_up_file(&mut world);\n    glue::execute_read(&mut world);\n    glue::verify_contents(&mut world);\n}\n"
+        ), "{}", facade.contents);
+        let glue = &generation.files[1].contents;
+        assert!(glue.contains("pub fn execute_read(_world: &mut World) {\n    todo!(\"read_file: execute_read\")\n}\n"));
+        assert_eq!(generation.scenarios[0].added.len(), 3);
+        assert!(generation.to_string().ends_with("3 of 3 steps unimplemented"));
+        syn::parse_file(&facade.contents).unwrap();
+        syn::parse_file(glue).unwrap();
+    }
+
+    #[test]
+    fn regeneration_preserves_glue_bodies() {
+        let dir = tempfile::tempdir().unwrap();
+        generate(&spec(), "bdd/demo.yaml", dir.path()).unwrap().write().unwrap();
+
+        let glue_path = dir.path().join("tests/glue/read_file.rs");
+        let edited = fs::read_to_string(&glue_path)
+            .unwrap()
+            .replace("pub struct World {}", "pub struct World {\n    pub path: String,\n}")
+            .replacen("    todo!(\"read_file: set_up_file\")", "    _world.path = \"x\".into();", 1)
+            + "\npub fn old_step(_world: &mut World) {}\n";
+        fs::write(&glue_path, &edited).unwrap();
+
+        let mut spec = spec();
+        spec.scenarios[0].steps.push(Step::Plain("verify_length".into()));
+        let generation = generate(&spec, "bdd/demo.yaml", dir.path()).unwrap();
+        let glue = &generation.files[1].contents;
+        assert!(glue.starts_with(&edited), "existing text must be kept verbatim");
+        assert!(glue.contains("pub fn verify_length("));
+
+        let report = &generation.scenarios[0];
+        assert_eq!(report.pending, ["execute_read", "verify_contents", "verify_length"]);
+        assert_eq!(report.added, ["verify_length"]);
+        assert_eq!(report.orphaned, ["old_step"]);
+        assert!(generation.files[0].contents.contains("#[ignore = \"pending steps: execute_read, verify_contents, verify_length\"]"));
+
+        fs::write(&glue_path, "fn broken(").unwrap();
+        assert!(generate(&spec, "bdd/demo.yaml", dir.path()).is_err());
+    }
+
+    #[test]
+    fn invalid_specs_list_every_problem() {
+        let mut spec = spec();
+        spec.scenarios[0].steps.push(Step::Plain("verify-len".into()));
+        spec.scenarios.push(spec.scenarios[0].clone());
+        let msg = validate(&spec).unwrap_err().to_string();
+        assert!(msg.contains("steps[3] `verify-len`"), "{msg}");
+        assert!(msg.contains("is duplicated"), "{msg}");
+    }
+}
@@ -0,0 +1,262 @@
+// ============================================================================
+// Forge Template — build.rs Integration
+// File: build.rs
+// Role: Render values/template/output jobs from a `build.rs` script. Outputs
+//       go to `OUT_DIR` (or the source tree when opted in), every input is
+//       registered with `cargo:rerun-if-changed`, and render failures are
+//       reported as `cargo:warning` lines before the build fails.
+// ============================================================================
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, bail, Context, Result};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::codegen::{render_value_with, TemplateSource};
+use crate::read_and_parse;
+
+/// One render: `values` through `template` into `output`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Job {
+    pub values: PathBuf,
+    pub template: PathBuf,
+    /// Relative to the output directory
+    pub output: PathBuf,
+}
+
+/// Job manifest (YAML); job paths are relative to the manifest's directory.
+///
+/// ```yaml
+/// jobs:
+///   - values: ../../templates/ui_component/context.yaml
+///     template: ../../templates/ui_component/mod.mustache
+///     output: button/mod.rs
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Manifest {
+    #[serde(default)]
+    pub jobs: Vec<Job>,
+}
+
+/// Where outputs are written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OutputDir {
+    /// `$OUT_DIR` (default); include with `include!(concat!(env!("OUT_DIR"), "/x.rs"))`
+    OutDir,
+    /// `$CARGO_MANIFEST_DIR`, for generated files that are committed
+    SourceTree,
+    /// An explicit directory
+    Path(PathBuf),
+}
+
+/// Render jobs from `build.rs`:
+///
+/// ```no_run
+/// // build.rs `main`
+/// forge_template::build::Builder::new()
+///     .manifest("forge.yaml")
+///     .run()
+///     .expect("forge-template codegen");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Builder {
+    manifests: Vec<PathBuf>,
+    jobs: Vec<Job>,
+    output: OutputDir,
+    strict: bool,
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Builder {
+    pub fn new() -> Self {
+        Self {
+            manifests: Vec::new(),
+            jobs: Vec::new(),
+            output: OutputDir::OutDir,
+            strict: false,
+        }
+    }
+
+    /// Add every job of a YAML manifest (read when [`Builder::run`] is called).
+    pub fn manifest(mut self, path: impl Into<PathBuf>) -> Self {
+        self.manifests.push(path.into());
+        self
+    }
+
+    /// Add a single job; relative paths resolve against `CARGO_MANIFEST_DIR`.
+    pub fn job(mut self, values: impl Into<PathBuf>, template: impl Into<PathBuf>, output: impl Into<PathBuf>) -> Self {
+        self.jobs.push(Job {
+            values: values.into(),
+            template: template.into(),
+            output: output.into(),
+        });
+        self
+    }
+
+    pub fn output(mut self, output: OutputDir) -> Self {
+        self.output = output;
+        self
+    }
+
+    /// Write into the crate's source tree instead of `OUT_DIR`.
+    pub fn in_source_tree(self, on: bool) -> Self {
+        self.output(if on { OutputDir::SourceTree } else { OutputDir::OutDir })
+    }
+
+    /// Fail jobs whose template uses keys missing from the values.
+    pub fn strict(mut self, on: bool) -> Self {
+        self.strict = on;
+        self
+    }
+
+    /// Run every job, printing cargo directives to stdout.
+    /// Returns the files written (unchanged outputs are left untouched).
+    pub fn run(&self) -> Result<Vec<PathBuf>> {
+        self.run_with(&mut io::stdout().lock())
+    }
+
+    /// [`Builder::run`] with cargo directives written to `out`.
+    pub fn run_with(&self, out: &mut impl Write) -> Result<Vec<PathBuf>> {
+        let root = env_dir("CARGO_MANIFEST_DIR").unwrap_or_else(|_| PathBuf::from("."));
+        let out_dir = match &self.output {
+            OutputDir::OutDir => env_dir("OUT_DIR")?,
+            OutputDir::SourceTree => root.clone(),
+            OutputDir::Path(dir) => root.join(dir),
+        };
+
+        let mut jobs: Vec<Job> = self.jobs.iter().map(|j| j.resolved(&root)).collect();
+        for manifest in &self.manifests {
+            let path = root.join(manifest);
+            rerun_if_changed(out, &path)?;
+            let parsed: Manifest = serde_json::from_value(read_and_parse!(yaml, &path)?)
+                .with_context(|| format!("invalid forge-template manifest {}", path.display()))?;
+            let base = path.parent().unwrap_or(Path::new("."));
+            jobs.extend(parsed.jobs.iter().map(|j| j.resolved(base)));
+        }
+
+        let mut written = Vec::new();
+        let mut failed = Vec::new();
+        for job in &jobs {
+            let target = out_dir.join(&job.output);
+            match self.render_job(job, out) {
+                Ok(code) => {
+                    if write_if_changed(&target, &code)? {
+                        written.push(target);
+                    }
+                }
+                Err(err) => {
+                    for line in format!("{}: {err:#}", job.output.display()).lines() {
+                        writeln!(out, "cargo:warning=forge-template: {line}")?;
+                    }
+                    failed.push(job.output.display().to_string());
+                }
+            }
+        }
+        if !failed.is_empty() {
+            bail!("forge-template: {} job(s) failed: {}", failed.len(), failed.join(", "));
+        }
+        Ok(written)
+    }
+
+    fn render_job(&self, job: &Job, out: &mut impl Write) -> Result<String> {
+        rerun_if_changed(out, &job.values)?;
+        let template = TemplateSource::Path(&job.template);
+        // register the template and partials even if the render fails
+        for file in template.files().unwrap_or_else(|_| vec![job.template.clone()]) {
+            rerun_if_changed(out, &file)?;
+        }
+        let raw: Value = read_and_parse!(yaml, &job.values)?;
+        render_value_with(&raw, template, self.strict)
+    }
+}
+
+impl Job {
+    fn resolved(&self, base: &Path) -> Job {
+        Job {
+            values: base.join(&self.values),
+            template: base.join(&self.template),
+            output: self.output.clone(),
+        }
+    }
+}
+
+fn env_dir(var: &str) -> Result<PathBuf> {
+    std::env::var_os(var)
+        .map(PathBuf::from)
+        .ok_or_else(|| anyhow!("{var} is not set; forge_template::build runs from build.rs"))
+}
+
+fn rerun_if_changed(out: &mut impl Write, path: &Path) -> Result<()> {
+    writeln!(out, "cargo:rerun-if-changed={}", path.display())?;
+    Ok(())
+}
+
+/// Write `code` unless `path` already holds it (keeps mtimes stable, so
+/// source-tree outputs do not retrigger builds). Returns whether it wrote.
+fn write_if_changed(path: &Path, code: &str) -> Result<bool> {
+    if fs::read_to_string(path).is_ok_and(|existing| existing == code) {
+        return Ok(false);
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).with_context(|| format!("create {}", parent.display()))?;
+    }
+    fs::write(path, code).with_context(|| format!("write {}", path.display()))?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manifest_jobs_render_track_inputs_and_warn_on_failure() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("gen")).unwrap();
+        fs::write(root.join("gen/values.yaml"), "payload:\n  name: user_profile\n").unwrap();
+        fs::write(root.join("gen/item.mustache"), "pub struct {{payload.name_PascalCase}};{{> tail}}\n").unwrap();
+        fs::write(root.join("gen/tail.mustache"), "").unwrap();
+        fs::write(root.join("gen/strict.mustache"), "{{payload.nmae}}").unwrap();
+        fs::write(
+            root.join("gen/forge.yaml"),
+            "jobs:\n  - { values: values.yaml, template: item.mustache, output: model/user.rs }\n",
+        )
+        .unwrap();
+
+        let out_dir = root.join("out");
+        let builder = Builder::new()
+            .manifest(root.join("gen/forge.yaml"))
+            .output(OutputDir::Path(out_dir.clone()));
+        let mut log = Vec::new();
+        let written = builder.run_with(&mut log).unwrap();
+        assert_eq!(written, [out_dir.join("model/user.rs")]);
+        assert_eq!(fs::read_to_string(&written[0]).unwrap(), "pub struct UserProfile;\n");
+        let log = String::from_utf8(log).unwrap();
+        for input in ["forge.yaml", "values.yaml", "item.mustache", "tail.mustache"] {
+            assert!(log.contains(&format!("cargo:rerun-if-changed={}", root.join("gen").join(input).display())), "{log}");
+        }
+
+        // unchanged output is not rewritten
+        assert!(builder.run_with(&mut Vec::new()).unwrap().is_empty());
+
+        let mut log = Vec::new();
+        let err = builder
+            .job(root.join("gen/values.yaml"), root.join("gen/strict.mustache"), "bad.rs")
+            .strict(true)
+            .run_with(&mut log)
+            .unwrap_err();
+        assert!(err.to_string().contains("1 job(s) failed: bad.rs"), "{err}");
+        let log = String::from_utf8(log).unwrap();
+        assert!(log.contains("cargo:warning=forge-template: bad.rs: missing key: payload.nmae"), "{log}");
+    }
+}
@@ -91,3 +91,70 @@ pub struct ServeArgs {
     #[arg(short, long)]
     pub verbose: bool,
 }
+
+// ============================================================================
+// BDD COMMAND
+// ============================================================================
+#[derive(Args, Debug)]
+#[command(about = "Generate BDD facade tests and glue stubs from scenario YAML")]
+pub struct BddArgs {
+    /// Spec files (YAML or Gherkin `.feature`) or directories of specs (e.g. resources/bdd/forge-fs)
+    #[arg(required = true)]
+    pub specs: Vec<PathBuf>,
+
+    /// Crate to generate into (default: crates/<spec crate>)
+    #[arg(long)]
+    pub crate_dir: Option<PathBuf>,
+
+    /// Report only; write nothing
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Exit with an error while any step is unimplemented
+    #[arg(long)]
+    pub deny_pending: bool,
+}
+
+#[derive(Args, Debug)]
+#[command(about = "Convert a BDD spec between scenario YAML and Gherkin .feature")]
+pub struct BddConvertArgs {
+    /// Spec to read (`.yaml` / `.yml` or `.feature`)
+    pub input: PathBuf,
+
+    /// Spec to write; a `.feature` extension selects Gherkin, anything else YAML
+    pub output: PathBuf,
+}
+
+// ============================================================================
+// TOKENS COMMAND
+// ============================================================================
+#[derive(Args, Debug)]
+#[command(about = "Compile design tokens into CSS variables, Rust consts and TypeScript declarations")]
+pub struct TokensArgs {
+    /// Design token YAML (e.g. resources/templates/design_tokens.yaml)
+    pub tokens: PathBuf,
+
+    /// Write CSS `:root` custom properties here
+    #[arg(long)]
+    pub css: Option<PathBuf>,
+
+    /// Write the Rust `tokens` module here
+    #[arg(long)]
+    pub rust: Option<PathBuf>,
+
+    /// Write TypeScript declarations (`.d.ts`) here
+    #[arg(long)]
+    pub dts: Option<PathBuf>,
+
+    /// Render a custom template against the token context
+    #[arg(long)]
+    pub template: Option<PathBuf>,
+
+    /// Output for `--template` (default: stdout)
+    #[arg(short, long, requires = "template")]
+    pub out: Option<PathBuf>,
+
+    /// Print the token context as JSON instead of writing outputs
+    #[arg(long)]
+    pub json: bool,
+}
@@ -0,0 +1,66 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+
+use super::args::{BddArgs, BddConvertArgs};
+use crate::bdd::{generate, load_spec, save_spec};
+
+/// BDD command entrypoint
+pub fn run(args: BddArgs) -> Result<()> {
+    let mut pending = 0;
+    for spec_path in spec_files(&args.specs)? {
+        let spec = load_spec(&spec_path)?;
+        let crate_dir = args
+            .crate_dir
+            .clone()
+            .unwrap_or_else(|| Path::new("crates").join(&spec.crate_name));
+        let generation = generate(&spec, &spec_path.display().to_string(), &crate_dir)?;
+
+        println!("🧪 {} → {}", spec_path.display(), crate_dir.display());
+        if args.dry_run {
+            for file in generation.files.iter().filter(|f| f.changed) {
+                println!("   would write {}", file.path.display());
+            }
+        } else {
+            for path in generation.write()? {
+                println!("   ✍️  {}", path.display());
+            }
+        }
+        println!("{generation}\n");
+        pending += generation.pending_steps();
+    }
+
+    if args.deny_pending && pending > 0 {
+        bail!("{pending} BDD step(s) still unimplemented");
+    }
+    Ok(())
+}
+
+/// Convert a spec between YAML and Gherkin (direction from the extensions)
+pub fn convert(args: BddConvertArgs) -> Result<()> {
+    let spec = load_spec(&args.input)?;
+    save_spec(&spec, &args.output)?;
+    println!("🔁 {} → {}", args.input.display(), args.output.display());
+    Ok(())
+}
+
+/// Spec files named on the command line; directories contribute their
+/// `*.yaml` / `*.yml` / `*.feature` files in name order.
+fn spec_files(inputs: &[PathBuf]) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for input in inputs {
+        if input.is_dir() {
+            let mut found: Vec<PathBuf> = fs::read_dir(input)
+                .with_context(|| format!("read {}", input.display()))?
+                .filter_map(|e| e.ok().map(|e| e.path()))
+                .filter(|p| matches!(p.extension().and_then(|e| e.to_str()), Some("yaml" | "yml" | "feature")))
+                .collect();
+            found.sort();
+            files.extend(found);
+        } else {
+            files.push(input.clone());
+        }
+    }
+    Ok(files)
+}
@@ -97,10 +97,8 @@ fn print_value(
 ) -> Result<()> {
     match format {
         OutputFormat::Json => {
-            if compact {
+            if compact && !pretty {
                 println!("{}", serde_json::to_string(val)?);
-            } else if pretty {
-                println!("{}", serde_json::to_string_pretty(val)?);
             } else {
                 println!("{}", serde_json::to_string_pretty(val)?);
             }
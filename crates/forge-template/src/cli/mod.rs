@@ -1,7 +1,9 @@
 pub mod args;
+pub mod bdd_cmd;
 pub mod render_cmd;
 pub mod inspect_cmd;
 pub mod serve_cmd;
+pub mod tokens_cmd;
 
 use clap::{Parser, Subcommand};
 use anyhow::Result;
@@ -23,6 +25,15 @@ pub enum Commands {
 
     /// Run forge-template as a long-running service
     Serve(args::ServeArgs),
+
+    /// Generate BDD facade tests and glue stubs from scenario YAML
+    Bdd(args::BddArgs),
+
+    /// Convert a BDD spec between scenario YAML and Gherkin
+    BddConvert(args::BddConvertArgs),
+
+    /// Compile design tokens into CSS, Rust and TypeScript
+    Tokens(args::TokensArgs),
 }
 
 pub fn run() -> Result<()> {
@@ -32,5 +43,8 @@ pub fn run() -> Result<()> {
         Commands::Render(args) => render_cmd::run(args),
         Commands::Inspect(args) => inspect_cmd::run(args),
         Commands::Serve(args) => serve_cmd::run(args),
+        Commands::Bdd(args) => bdd_cmd::run(args),
+        Commands::BddConvert(args) => bdd_cmd::convert(args),
+        Commands::Tokens(args) => tokens_cmd::run(args),
     }
 }
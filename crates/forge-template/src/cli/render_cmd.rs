@@ -3,7 +3,6 @@ use std::time::Instant;
 use anyhow::{Context, Result};
 use crate::codegen::render_yaml_from_abs;
 use crate::context::{condition, codegen_ctx};
-use crate::utils;
 use super::args::RenderArgs;
 
 /// Render command entrypoint
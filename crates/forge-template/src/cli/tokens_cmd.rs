@@ -0,0 +1,45 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use super::args::TokensArgs;
+use crate::tokens;
+
+/// Tokens command entrypoint. Without any output flag the CSS goes to stdout.
+pub fn run(args: TokensArgs) -> Result<()> {
+    let set = tokens::load(&args.tokens)?;
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&set.context())?);
+        return Ok(());
+    }
+
+    let mut wrote = false;
+    for (path, output) in [(&args.css, set.to_css()?), (&args.rust, set.to_rust()?), (&args.dts, set.to_dts()?)] {
+        if let Some(path) = path {
+            write(path, &output)?;
+            wrote = true;
+        }
+    }
+    if let Some(template) = &args.template {
+        let output = set.render_with(template)?;
+        match &args.out {
+            Some(path) => write(path, &output)?,
+            None => print!("{output}"),
+        }
+        wrote = true;
+    }
+    if !wrote {
+        print!("{}", set.to_css()?);
+    }
+    Ok(())
+}
+
+fn write(path: &Path, contents: &str) -> Result<()> {
+    if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+        fs::create_dir_all(dir).with_context(|| format!("create {}", dir.display()))?;
+    }
+    fs::write(path, contents).with_context(|| format!("write {}", path.display()))?;
+    println!("🎨 {}", path.display());
+    Ok(())
+}
@@ -0,0 +1,137 @@
+// ============================================================================
+// Forge Template — Template Cache
+// File: codegen/cache.rs
+// Role: Compiled templates keyed by path, reused until the template file or
+//       one of its partials changes (mtime + size). mustache inlines partials
+//       at compile time, so their stamps are part of the key. The global
+//       cache backs every path-based render (CLI, forge-ide batch generator,
+//       proc macros).
+// ============================================================================
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+use once_cell::sync::Lazy;
+
+use crate::codegen::render::template_files;
+use crate::errors::Result;
+use crate::template::Template;
+
+static GLOBAL: Lazy<TemplateCache> = Lazy::new(TemplateCache::new);
+
+/// Modification time + length of one file; `None` if it cannot be read.
+type Stamp = Option<(SystemTime, u64)>;
+
+struct Entry {
+    /// Template file followed by its partials
+    stamps: Vec<(PathBuf, Stamp)>,
+    template: Arc<Template>,
+}
+
+impl Entry {
+    fn is_fresh(&self) -> bool {
+        self.stamps.iter().all(|(path, stamp)| stamp_of(path) == *stamp)
+    }
+}
+
+/// Thread-safe cache of compiled path templates.
+#[derive(Default)]
+pub struct TemplateCache {
+    entries: Mutex<HashMap<PathBuf, Entry>>,
+}
+
+impl TemplateCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Process-wide cache used by the `render_*_from_abs` functions.
+    pub fn global() -> &'static TemplateCache {
+        &GLOBAL
+    }
+
+    /// Compiled template for `path`, recompiled if it or a partial changed.
+    pub fn get(&self, path: &Path) -> Result<Arc<Template>> {
+        if let Some(entry) = self.lock().get(path) {
+            if entry.is_fresh() {
+                return Ok(Arc::clone(&entry.template));
+            }
+        }
+
+        // Stamp before compiling: an edit racing the compile leaves a stale
+        // stamp, which only forces another recompile.
+        let files = template_files(path).unwrap_or_else(|_| vec![path.to_path_buf()]);
+        let stamps = files
+            .into_iter()
+            .map(|f| {
+                let stamp = stamp_of(&f);
+                (f, stamp)
+            })
+            .collect();
+        let template = Arc::new(Template::from_path(path)?);
+        self.lock().insert(
+            path.to_path_buf(),
+            Entry {
+                stamps,
+                template: Arc::clone(&template),
+            },
+        );
+        Ok(template)
+    }
+
+    /// Number of cached templates.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drop every cached template.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<PathBuf, Entry>> {
+        // a panic while holding the lock cannot leave an entry half-written
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+fn stamp_of(path: &Path) -> Stamp {
+    let meta = fs::metadata(path).ok()?;
+    Some((meta.modified().ok()?, meta.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reuses_until_template_or_partial_changes() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        let main = dir.join("main.mustache");
+        fs::write(&main, "a{{> part}}").unwrap();
+        fs::write(dir.join("part.mustache"), "b").unwrap();
+
+        let cache = TemplateCache::new();
+        let first = cache.get(&main).unwrap();
+        assert!(Arc::ptr_eq(&first, &cache.get(&main).unwrap()));
+        assert_eq!(first.render(&()).unwrap(), "ab");
+
+        // size changes are picked up even within one mtime tick
+        fs::write(dir.join("part.mustache"), "bc").unwrap();
+        let second = cache.get(&main).unwrap();
+        assert!(!Arc::ptr_eq(&first, &second));
+        assert_eq!(second.render(&()).unwrap(), "abc");
+
+        fs::write(&main, "zz{{> part}}").unwrap();
+        assert_eq!(cache.get(&main).unwrap().render(&()).unwrap(), "zzbc");
+        assert_eq!(cache.len(), 1);
+    }
+}
@@ -2,17 +2,54 @@
 // Forge Template Codegen Expansion Engine
 // File: expand.rs
 // Role: Recursive expander for ValuesTemplateExpansion across arrays, objects,
-//       and scalar values with deterministic structure and hasNext flags.
+//       and scalar values with deterministic structure and iteration flags
+//       (`index`, `isFirst`, `hasNext`, … on elements, `<key>_len` beside).
+//       Every mapping `k` also gets a `k_entries` list (Mustache cannot
+//       iterate object keys), ordered per `ExpandOptions::entries_order`;
+//       entries hold scalars only, never copies of nested subtrees.
 // ============================================================================
 
 use serde_json::{Map, Value};
 use crate::codegen::utils::{
     expand_string_value,
     insert_expansions,
-    insert_has_next_flag,
+    insert_iteration_flags,
+    insert_length_flags,
+    insert_position_flags,
 };
 use crate::codegen::flags::insert_type_flags;
 
+// -----------------------------------------------------------------------------
+// Options
+// -----------------------------------------------------------------------------
+
+/// Order of `<key>_entries` lists, from `header.entries_order`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EntriesOrder {
+    /// As written in the source document (default)
+    #[default]
+    Source,
+    /// Ascending by key (byte-wise)
+    Key,
+}
+
+impl EntriesOrder {
+    /// `source` / `key`; anything else is `None`.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "source" => Some(Self::Source),
+            "key" => Some(Self::Key),
+            _ => None,
+        }
+    }
+}
+
+/// Knobs for one expansion pass.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ExpandOptions {
+    pub entries_order: EntriesOrder,
+}
+
 // -----------------------------------------------------------------------------
 // Expansion Policy — selective filtering for which keys may be expanded
 // -----------------------------------------------------------------------------
@@ -67,6 +104,7 @@ pub fn is_expandable_key(key: &str, value: &Value) -> bool {
         "name"
             | "type"
             | "module"
+            | "mod"
             | "pattern"
             | "variant"
             | "variable"
@@ -81,10 +119,12 @@ pub fn is_expandable_key(key: &str, value: &Value) -> bool {
 // -----------------------------------------------------------------------------
 
 /// Expand a single element of an array (handles strings, objects, and nested structures).
-fn expand_array_element(v: &Value) -> Value {
+/// Other scalars are wrapped by [`insert_iteration_flags`].
+fn expand_array_element(v: &Value, opts: &ExpandOptions) -> Value {
     match v {
         Value::String(s) => Value::Object(expand_string_value("value", s)),
-        Value::Object(o) => Value::Object(expand_object_fields(o)),
+        Value::Object(o) => Value::Object(expand_object_fields_with(o, opts)),
+        Value::Array(_) => expand_value_with(v, opts),
         _ => v.clone(),
     }
 }
@@ -94,8 +134,23 @@ fn expand_array_element(v: &Value) -> Value {
 /// - Respects non-expanding keys
 /// - Recurses into nested objects/arrays
 /// - Appends type flags for Mustache logic
+/// - Adds a `<key>_entries` list for every nested mapping
+/// - Adds `<key>_len` / `<key>_is_empty` beside every array (and entries list)
 pub fn expand_object_fields(obj: &Map<String, Value>) -> Map<String, Value> {
-    let mut expanded = obj.clone();
+    expand_object_fields_with(obj, &ExpandOptions::default())
+}
+
+/// [`expand_object_fields`] with explicit options.
+pub fn expand_object_fields_with(obj: &Map<String, Value>, opts: &ExpandOptions) -> Map<String, Value> {
+    // Nested arrays/objects are replaced by their expansion below, so only
+    // scalars are copied up front (avoids deep-cloning every subtree).
+    let mut expanded: Map<String, Value> = obj
+        .iter()
+        .map(|(k, v)| {
+            let shallow = if v.is_array() || v.is_object() { Value::Null } else { v.clone() };
+            (k.clone(), shallow)
+        })
+        .collect();
 
     for (k, v) in obj {
         if is_non_expanding_key(k) {
@@ -110,26 +165,97 @@ pub fn expand_object_fields(obj: &Map<String, Value>) -> Map<String, Value> {
                 insert_expansions(&mut expanded, k, s);
             }
             insert_type_flags(&mut expanded, v);
-        } else if v.is_array() || v.is_object() {
-            expanded.insert(k.clone(), expand_value(v));
+        } else if let Some(map) = v.as_object() {
+            let value = Value::Object(expand_object_fields_with(map, opts));
+            let entries_key = format!("{k}_entries");
+            if !obj.contains_key(&entries_key) {
+                insert_length_flags(&mut expanded, &entries_key, map.len());
+                expanded.insert(entries_key, entries(map, &value, opts));
+            }
+            expanded.insert(k.clone(), value);
+            insert_type_flags(&mut expanded, v);
+        } else if let Some(items) = v.as_array() {
+            expanded.insert(k.clone(), expand_value_with(v, opts));
+            insert_length_flags(&mut expanded, k, items.len());
             insert_type_flags(&mut expanded, v);
         } else {
             expanded.insert(k.clone(), v.clone());
         }
     }
 
-    insert_type_flags(&mut expanded, &Value::Object(obj.clone()));
+    // object-level flags only depend on the variant
+    insert_type_flags(&mut expanded, &Value::Object(Map::new()));
     expanded
 }
 
+/// `{ key, key_<case>…, value, value_<case>…, is_*, index, isFirst, hasNext,
+/// … }` for each key of `raw`. `expanded` is the already expanded mapping.
+/// Nested values are not copied into entries: a mapping contributes only its
+/// scalar fields as `value` (its own list is `<key>_entries` beside it), an
+/// array only `value_len` / `value_is_empty`. Copying whole subtrees would
+/// double the context per nesting level.
+fn entries(raw: &Map<String, Value>, expanded: &Value, opts: &ExpandOptions) -> Value {
+    let mut keys: Vec<&String> = raw.keys().collect();
+    if opts.entries_order == EntriesOrder::Key {
+        keys.sort();
+    }
+    let len = keys.len();
+    let out = keys
+        .into_iter()
+        .enumerate()
+        .map(|(i, key)| {
+            let original = &raw[key];
+            let mut entry = expand_string_value("key", key);
+            match original {
+                Value::String(s) => {
+                    entry.insert("value".into(), original.clone());
+                    if is_expandable_key("value", original) {
+                        insert_expansions(&mut entry, "value", s);
+                    }
+                }
+                Value::Object(_) => {
+                    let scalars = match expanded.get(key) {
+                        Some(Value::Object(map)) => scalar_fields(map),
+                        _ => Map::new(),
+                    };
+                    entry.insert("value".into(), Value::Object(scalars));
+                }
+                Value::Array(items) => {
+                    insert_length_flags(&mut entry, "value", items.len());
+                }
+                _ => {
+                    entry.insert("value".into(), original.clone());
+                }
+            }
+            insert_type_flags(&mut entry, original);
+            insert_position_flags(&mut entry, i, len);
+            Value::Object(entry)
+        })
+        .collect();
+    Value::Array(out)
+}
+
+/// Scalar fields (and their variants/flags) of an expanded mapping.
+fn scalar_fields(map: &Map<String, Value>) -> Map<String, Value> {
+    map.iter()
+        .filter(|(_, v)| !v.is_object() && !v.is_array())
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect()
+}
+
 /// Recursively expand any JSON value into a deterministic Mustache context.
 pub fn expand_value(value: &Value) -> Value {
+    expand_value_with(value, &ExpandOptions::default())
+}
+
+/// [`expand_value`] with explicit options.
+pub fn expand_value_with(value: &Value, opts: &ExpandOptions) -> Value {
     match value {
         Value::Array(arr) => {
-            let out: Vec<Value> = arr.iter().map(expand_array_element).collect();
-            insert_has_next_flag(out)
+            let out: Vec<Value> = arr.iter().map(|v| expand_array_element(v, opts)).collect();
+            insert_iteration_flags(out)
         }
-        Value::Object(o) => Value::Object(expand_object_fields(o)),
+        Value::Object(o) => Value::Object(expand_object_fields_with(o, opts)),
         Value::String(s) => Value::Object(expand_string_value("value", s)),
         _ => value.clone(),
     }
@@ -1,12 +1,20 @@
+pub mod cache;
 mod macros;
-mod render;
+pub(crate) mod render;
+pub mod scope;
+pub mod strict;
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
-use crate::codegen::render::{compile_template, render_with_ctx};
-use crate::context::{codegen_ctx, condition};
+use crate::codegen::cache::TemplateCache;
+use crate::codegen::render::partial_files;
+pub use crate::codegen::render::template_files;
+use crate::context::condition;
 use crate::read_and_parse;
-use anyhow::Result;
+use crate::template::Template;
+use anyhow::{anyhow, Result};
+use proc_macro2::TokenStream;
 use serde_json::Value;
 
 // ============================================================================
@@ -22,16 +30,128 @@ pub fn render_yaml_from_abs<P1: AsRef<Path>, P2: AsRef<Path>>(
     // 1) parse YAML
     let raw_yaml: Value = read_and_parse!(yaml, yaml_abs.as_ref())?;
 
-    // 2) condition to normalized shape (header/payload)
-    let conditioned = condition::condition_yaml(&raw_yaml);
+    // 2..4) shared with in-memory callers
+    render_value_from_abs(&raw_yaml, template_abs)
+}
+
+/// Render an in-memory `header`/`payload` value with an absolute Mustache template path.
+/// Same pipeline as [`render_yaml_from_abs`] minus the YAML read.
+/// With `header.backend: rust_ast` the template is not used: `payload.ast` is
+/// emitted through syn/quote and pretty-printed instead.
+pub fn render_value_from_abs<P: AsRef<Path>>(raw: &Value, template_abs: P) -> Result<String> {
+    render_value_with(raw, TemplateSource::Path(template_abs.as_ref()), false)
+}
+
+/// Where a template comes from.
+#[derive(Debug, Clone, Copy)]
+pub enum TemplateSource<'a> {
+    /// Template file; partials resolve next to it
+    Path(&'a Path),
+    /// Template text; partials resolve against the working directory
+    Inline(&'a str),
+}
+
+impl TemplateSource<'_> {
+    /// Compiled template; path templates come from [`TemplateCache::global`].
+    fn load(&self) -> Result<Arc<Template>> {
+        Ok(match *self {
+            TemplateSource::Path(path) => TemplateCache::global().get(path)?,
+            TemplateSource::Inline(text) => Arc::new(Template::compile(text)?),
+        })
+    }
+
+    /// Files read when rendering: the template file (if any) and its partials.
+    pub fn files(&self) -> Result<Vec<PathBuf>> {
+        Ok(match *self {
+            TemplateSource::Path(path) => template_files(path)?,
+            TemplateSource::Inline(text) => partial_files(text, Path::new(".")),
+        })
+    }
+}
+
+/// [`render_value_from_abs`] with any template source. With `strict`, a
+/// variable tag that does not resolve fails the render (see [`strict`]).
+pub fn render_value_with(raw: &Value, template: TemplateSource<'_>, strict: bool) -> Result<String> {
+    // 2) condition to normalized shape (header/payload); typed AST if opted in
+    let mut conditioned = condition::condition_yaml(raw);
+    if crate::ast::uses_rust_ast_backend(&conditioned) {
+        return Ok(crate::ast::emit_source(&conditioned)?);
+    }
+    crate::ast::apply(&mut conditioned)?;
+
+    // 3) compile (cached for paths); the template decides scope references
+    let template = template.load()?;
 
-    // 3) build codegen context via header-driven adapter
-    let ctx = codegen_ctx::build_yaml_codegen_context(&conditioned);
+    // 4) build codegen context via header-driven adapter and render
+    let ctx = template.build_context(conditioned);
+    Ok(template.render_context(&ctx, strict)?)
+}
 
-    // 4) compile template and render
-    let tpl = compile_template(template_abs.as_ref())?;
-    let rendered = render_with_ctx(&ctx, &tpl)?;
-    Ok(rendered)
+/// Token-level variant of [`render_yaml_from_abs`] for proc-macro callers.
+pub fn render_yaml_tokens_from_abs<P1: AsRef<Path>, P2: AsRef<Path>>(
+    yaml_abs: P1,
+    template_abs: P2,
+) -> Result<TokenStream> {
+    let raw_yaml: Value = read_and_parse!(yaml, yaml_abs.as_ref())?;
+    render_value_tokens_from_abs(&raw_yaml, template_abs)
+}
+
+/// Rendered tokens plus every file read to produce them (values YAML,
+/// template, partials), so proc-macro callers can register them with cargo.
+#[derive(Debug, Clone)]
+pub struct TrackedTokens {
+    pub tokens: TokenStream,
+    pub inputs: Vec<PathBuf>,
+}
+
+/// [`render_yaml_tokens_from_abs`] that also reports its input files.
+/// The template is only listed when the mustache backend actually reads it.
+pub fn render_yaml_tracked_from_abs<P1: AsRef<Path>, P2: AsRef<Path>>(
+    yaml_abs: P1,
+    template_abs: P2,
+) -> Result<TrackedTokens> {
+    let yaml_abs = yaml_abs.as_ref();
+    let raw_yaml: Value = read_and_parse!(yaml, yaml_abs)?;
+    let mut tracked =
+        render_value_tracked_with(&raw_yaml, Some(TemplateSource::Path(template_abs.as_ref())), false)?;
+    tracked.inputs.insert(0, yaml_abs.to_path_buf());
+    Ok(tracked)
+}
+
+/// Token-level render of an in-memory value for proc-macro callers.
+/// `rust_ast` documents need no template; otherwise a missing template is an
+/// error. `inputs` lists the template files read (not the values source).
+pub fn render_value_tracked_with(
+    raw: &Value,
+    template: Option<TemplateSource<'_>>,
+    strict: bool,
+) -> Result<TrackedTokens> {
+    let conditioned = condition::condition_yaml(raw);
+    if crate::ast::uses_rust_ast_backend(&conditioned) {
+        return Ok(TrackedTokens {
+            tokens: crate::ast::emit_tokens(&conditioned)?,
+            inputs: Vec::new(),
+        });
+    }
+    let template = template.ok_or_else(|| anyhow!("a template is required unless `header.backend: rust_ast`"))?;
+    let rendered = render_value_with(raw, template, strict)?;
+    let tokens = rendered
+        .parse::<TokenStream>()
+        .map_err(|e| anyhow!("rendered template is not valid Rust tokens: {e}"))?;
+    Ok(TrackedTokens {
+        tokens,
+        inputs: template.files()?,
+    })
+}
+
+/// `rust_ast` documents are emitted as tokens directly (no reparse);
+/// mustache output is tokenized.
+pub fn render_value_tokens_from_abs<P: AsRef<Path>>(
+    raw: &Value,
+    template_abs: P,
+) -> Result<TokenStream> {
+    render_value_tracked_with(raw, Some(TemplateSource::Path(template_abs.as_ref())), false)
+        .map(|tracked| tracked.tokens)
 }
 
 // ============================================================================
@@ -40,7 +160,6 @@ pub fn render_yaml_from_abs<P1: AsRef<Path>, P2: AsRef<Path>>(
 
 #[cfg(test)]
 mod tests {
-    use super::*;
     use std::fs;
     use std::path::PathBuf;
     use std::time::{SystemTime, UNIX_EPOCH};
@@ -66,7 +185,88 @@ mod tests {
         // Keeping it here for reference but marking as ignored.
         let _ = temp_workspace();
         eprintln!("⚠️  Legacy DTO test ignored due to refactor.");
-        assert!(true);
+    }
+
+    #[test]
+    fn rust_ast_header_normalizes_and_validates() {
+        let dir = temp_workspace();
+        let tpl = dir.join("items.mustache");
+        fs::write(
+            &tpl,
+            "{{#payload.ast.items}}{{{decl}}}{{#methods}}|{{{signature}}}{{/methods}}{{/payload.ast.items}}",
+        )
+        .unwrap();
+
+        let doc = |arg: &str| {
+            serde_json::json!({
+                "header": { "ast": "rust" },
+                "payload": { "ast": { "items": [{
+                    "item": "struct",
+                    "name": "Router",
+                    "methods": [{ "name": "info", "receiver": "&self", "args": [
+                        { "name": arg, "type": "u8" }
+                    ]}]
+                }]}}
+            })
+        };
+
+        let out = super::render_value_from_abs(&doc("n"), &tpl).unwrap();
+        assert_eq!(out, "pub struct Router|pub fn info(&self, n: u8)");
+
+        let err = super::render_value_from_abs(&doc("self"), &tpl).unwrap_err();
+        let msg = err.to_string();
+        assert!(msg.contains("items[0].methods[0].args[0]"), "{msg}");
+        assert!(msg.contains("receiver"), "{msg}");
+        fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn rust_ast_backend_skips_the_template() {
+        let doc = serde_json::json!({
+            "header": { "backend": "rust_ast", "file": "values/kind.yaml" },
+            "payload": { "ast": { "items": [{
+                "item": "enum",
+                "name": "Kind",
+                "derives": ["Debug"],
+                "variants": [{ "name": "A" }, { "name": "B" }]
+            }]}}
+        });
+        let missing = temp_workspace().join("unused.mustache");
+
+        let out = super::render_value_from_abs(&doc, &missing).unwrap();
+        assert_eq!(
+            out,
+            "// Generated by forge-template (backend: rust_ast) from values/kind.yaml\n\n\
+             #[derive(Debug)]\npub enum Kind {\n    A,\n    B,\n}\n"
+        );
+
+        let tokens = super::render_value_tokens_from_abs(&doc, &missing).unwrap();
+        assert_eq!(
+            tokens.to_string(),
+            "# [derive (Debug)] pub enum Kind { A , B }"
+        );
+    }
+
+    #[test]
+    fn tracked_render_lists_yaml_template_and_partials() {
+        let dir = temp_workspace();
+        fs::write(dir.join("values.yaml"), "payload:\n  name: widget\n").unwrap();
+        fs::write(dir.join("main.mustache"), "struct {{payload.name_PascalCase}}; {{> item}}{{> leaf}}").unwrap();
+        fs::write(dir.join("item.mustache"), "{{> leaf}}{{> missing}}").unwrap();
+        fs::write(dir.join("leaf.mustache"), "struct Leaf;").unwrap();
+
+        let tracked =
+            super::render_yaml_tracked_from_abs(dir.join("values.yaml"), dir.join("main.mustache"))
+                .unwrap();
+        assert_eq!(
+            tracked.tokens.to_string(),
+            "struct Widget ; struct Leaf ; struct Leaf ;"
+        );
+        assert_eq!(
+            tracked.inputs,
+            ["values.yaml", "main.mustache", "item.mustache", "leaf.mustache"].map(|f| dir.join(f))
+        );
+        fs::remove_dir_all(dir).ok();
     }
 }
 pub mod expand;
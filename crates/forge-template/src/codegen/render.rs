@@ -1,20 +1,51 @@
+use std::collections::BTreeSet;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use anyhow::Context;
-use serde_json::Value;
+use once_cell::sync::Lazy;
+use regex::Regex;
 
-#[inline]
-pub fn compile_template(path: &Path) -> anyhow::Result<mustache::Template> {
-    let tpl_txt =
-        fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
-    Ok(mustache::compile_str(&tpl_txt).context("compile mustache template")?)
+/// `{{> name}}` partial tags (default delimiters)
+pub(crate) static PARTIAL_TAG: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\{\{>\s*([^\s}]+)\s*\}\}").expect("valid partial regex"));
+
+pub fn read_template(path: &Path) -> anyhow::Result<String> {
+    fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))
+}
+
+/// Directory partials of the template file at `path` resolve against.
+pub fn partials_dir(path: &Path) -> &Path {
+    path.parent().unwrap_or_else(|| Path::new("."))
 }
 
-#[inline]
-pub fn render_with_ctx(ctx: &Value, tpl: &mustache::Template) -> anyhow::Result<String> {
-    let data = crate::utils::to_data(ctx);
-    let mut out = Vec::new();
-    tpl.render_data(&mut out, &data)
-        .context("render mustache template")?;
-    Ok(String::from_utf8(out).expect("rendered must be valid UTF-8"))
-}
\ No newline at end of file
+/// Every file rendering the template at `path` reads: the template itself plus
+/// its partials, recursively. Missing partials render empty and are skipped.
+pub fn template_files(path: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let mut files = vec![path.to_path_buf()];
+    files.extend(partial_files(&read_template(path)?, partials_dir(path)));
+    Ok(files)
+}
+
+/// Partial files pulled in (recursively) by template `text`.
+pub fn partial_files(text: &str, partials_dir: &Path) -> Vec<PathBuf> {
+    let ctx = mustache::Context::new(partials_dir.to_path_buf());
+    let mut files = Vec::new();
+    let mut seen = BTreeSet::new();
+    let mut pending = vec![text.to_string()];
+    while let Some(text) = pending.pop() {
+        for cap in PARTIAL_TAG.captures_iter(&text) {
+            let name = cap[1].to_string();
+            if !seen.insert(name.clone()) {
+                continue;
+            }
+            let partial = ctx
+                .template_path
+                .join(format!("{name}.{}", ctx.template_extension));
+            if let Ok(body) = fs::read_to_string(&partial) {
+                files.push(partial);
+                pending.push(body);
+            }
+        }
+    }
+    files
+}
@@ -0,0 +1,315 @@
+// ============================================================================
+// Forge Template — Scope References
+// File: codegen/scope.rs
+// Role: Lets nested sections reach enclosing data without copying it in YAML.
+//       With `header.scope_refs: true` (or a template using the syntax
+//       below) every nested object and object array element gets
+//         parent — the enclosing object's scalar fields (no further chain)
+//         path   — where it sits, e.g. `structs[0].fields.bg`
+//       and the context gets `root`, the payload's scalar fields. Keys the
+//       data already defines are never overwritten; `*_entries` lists are
+//       not annotated. Templates may also write `{{../x}}` and
+//       `{{@root.x}}`; `rewrite` resolves them against the enclosing
+//       sections before mustache compiles the text.
+// ============================================================================
+
+use std::borrow::Cow;
+use std::fs;
+use std::path::Path;
+
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+use serde_json::{Map, Value};
+
+use crate::codegen::render::{partial_files, partials_dir, PARTIAL_TAG};
+
+pub const PARENT: &str = "parent";
+pub const ROOT: &str = "root";
+pub const PATH: &str = "path";
+
+/// Partial nesting depth after which inlining stops (recursive partials).
+const MAX_PARTIAL_DEPTH: usize = 16;
+
+/// Tag names starting with `../` (any number) or `@root.`
+static SCOPE_TAG: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\{\{(\{|&|#|\^|/)?(\s*)((?:\.\./)+|@root\.)").expect("valid scope tag regex")
+});
+
+/// Any tag: sigil, leading space, name, trailing space and close.
+static TAG: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\{\{(\{|&|#|\^|/)?(\s*)([^{}\s]*)(\s*)(\}?\}\})").expect("valid tag regex")
+});
+
+/// Generated boolean flags; sections on them do not enter a new object.
+static FLAG_SECTION: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(is_|is[A-Z]|has[A-Z])|_is_empty$").expect("valid flag regex")
+});
+
+// ----------------------------------------------------------------------------
+// Template side
+// ----------------------------------------------------------------------------
+
+/// `{{../x}}` → `{{parent.x}}`, `{{@root.x}}` → `{{root.x}}`.
+///
+/// Deeper references are resolved through the enclosing section names:
+/// inside `{{#payload.traits}}{{#methods}}`, `{{../../x}}` is the payload,
+/// `{{root.x}}`, as it is past the outermost section. Other ancestors are
+/// reached by their section name, or through the `parent` of a dotted
+/// section part below them; a list element whose child is also a list
+/// element has no name and renders empty (reported in strict mode).
+pub fn rewrite(text: &str) -> Cow<'_, str> {
+    if !uses_scope_syntax(text) {
+        return Cow::Borrowed(text);
+    }
+    let mut out = String::with_capacity(text.len());
+    let mut levels: Vec<Level> = Vec::new();
+    // levels pushed by each open section, popped again on its close tag
+    let mut sections: Vec<usize> = Vec::new();
+    let mut last = 0;
+    for cap in TAG.captures_iter(text) {
+        let whole = cap.get(0).expect("whole match");
+        out.push_str(&text[last..whole.start()]);
+        last = whole.end();
+
+        let sigil = cap.get(1).map_or("", |m| m.as_str());
+        if sigil == "/" {
+            let pushed = sections.pop().unwrap_or(0);
+            levels.truncate(levels.len().saturating_sub(pushed));
+        }
+        let name = resolve(&cap[3], &levels);
+        match sigil {
+            "#" if !FLAG_SECTION.is_match(&name) => {
+                let before = levels.len();
+                levels.extend(section_levels(&name, levels.is_empty()));
+                sections.push(levels.len() - before);
+            }
+            "#" | "^" => sections.push(0),
+            _ => {}
+        }
+        out.push_str(&format!("{{{{{sigil}{}{name}{}{}", &cap[2], &cap[4], &cap[5]));
+    }
+    out.push_str(&text[last..]);
+    Cow::Owned(out)
+}
+
+/// One object a section steps into, and how deeper sections reach it.
+#[derive(Debug, Clone, PartialEq)]
+enum Level {
+    /// The payload (`root`)
+    Root,
+    /// Reached by a dotted name; `mapping` is known for the leading parts
+    /// of a section name, while the last part may be a list
+    Named { address: String, mapping: bool },
+    /// `{{#.}}`: the current list item, which has no name
+    Unnamed,
+}
+
+/// `a.b.c` enters `a`, then `a.b`, then `a.b.c`. A leading `payload` at
+/// the top is the payload itself.
+fn section_levels(name: &str, top: bool) -> Vec<Level> {
+    if name == "." {
+        return vec![Level::Unnamed];
+    }
+    let segments: Vec<&str> = name.split('.').collect();
+    (0..segments.len())
+        .map(|i| {
+            if top && i == 0 && segments[0] == PAYLOAD {
+                Level::Root
+            } else {
+                Level::Named { address: segments[..=i].join("."), mapping: i + 1 < segments.len() }
+            }
+        })
+        .collect()
+}
+
+/// Rewrite a tag name that starts with `../` or `@root.` (others unchanged).
+fn resolve(name: &str, levels: &[Level]) -> String {
+    if let Some(rest) = name.strip_prefix("@root.") {
+        return format!("{ROOT}.{rest}");
+    }
+    let up = name.len() - name.trim_start_matches("../").len();
+    let (up, rest) = (up / 3, &name[up..]);
+    if up == 0 {
+        return name.to_string();
+    }
+    if up == 1 {
+        return format!("{PARENT}.{rest}");
+    }
+    // the innermost level is the current object; the target is `up` above it
+    let Some(target) = levels.len().checked_sub(up + 1) else {
+        return format!("{ROOT}.{rest}");
+    };
+    match (&levels[target], &levels[target + 1]) {
+        (Level::Root, _) => format!("{ROOT}.{rest}"),
+        (Level::Named { address, mapping: true }, _) => format!("{address}.{rest}"),
+        // a list element has no name, but its child's `parent` is it
+        (_, Level::Named { address, mapping: true }) => format!("{address}.{PARENT}.{rest}"),
+        (Level::Named { address, .. }, _) => format!("{address}.{rest}"),
+        (Level::Unnamed, _) => format!("{}{rest}", format!("{PARENT}.").repeat(up)),
+    }
+}
+
+pub fn uses_scope_syntax(text: &str) -> bool {
+    SCOPE_TAG.is_match(text)
+}
+
+/// `text` ready to compile: scope syntax rewritten, and — when a partial
+/// uses scope syntax, which mustache would read unrewritten — partials
+/// inlined (mustache 0.9 inlines them at compile time anyway).
+pub fn prepare_template<'a>(text: &'a str, partials: &Path) -> Cow<'a, str> {
+    let partial_uses_scope = partial_files(text, partials)
+        .iter()
+        .any(|f| fs::read_to_string(f).is_ok_and(|body| uses_scope_syntax(&body)));
+    if partial_uses_scope {
+        Cow::Owned(rewrite(&inline_partials(text, partials, 0)).into_owned())
+    } else {
+        rewrite(text)
+    }
+}
+
+fn inline_partials(text: &str, dir: &Path, depth: usize) -> String {
+    if depth >= MAX_PARTIAL_DEPTH {
+        return text.to_string();
+    }
+    PARTIAL_TAG
+        .replace_all(text, |cap: &Captures| {
+            let file = dir.join(format!("{}.mustache", &cap[1]));
+            match fs::read_to_string(&file) {
+                Ok(body) => inline_partials(&body, partials_dir(&file), depth + 1),
+                // missing partials render empty, as in mustache
+                Err(_) => String::new(),
+            }
+        })
+        .into_owned()
+}
+
+// ----------------------------------------------------------------------------
+// Context side
+// ----------------------------------------------------------------------------
+
+/// Add `root` to `ctx` and `parent` / `path` below `ctx.payload` (or below
+/// `ctx` itself when it has no payload).
+pub fn insert_scope_refs(ctx: &mut Value) {
+    let Some(top) = ctx.as_object_mut() else {
+        return;
+    };
+    let root = match top.get(PAYLOAD).and_then(Value::as_object) {
+        Some(payload) => shallow(payload),
+        None => shallow(top),
+    };
+    match top.get_mut(PAYLOAD).and_then(Value::as_object_mut) {
+        Some(payload) => annotate_children(payload, ""),
+        None => annotate_children(top, ""),
+    }
+    top.entry(ROOT).or_insert(Value::Object(root));
+}
+
+const PAYLOAD: &str = "payload";
+
+/// Give every object below `node` its `parent` and `path`.
+fn annotate_children(node: &mut Map<String, Value>, path: &str) {
+    let parent = Value::Object(shallow(node));
+    for (key, value) in node.iter_mut() {
+        // injected parents, and `_entries` lists (views of a sibling map)
+        if (key == PARENT && is_injected(value)) || key.ends_with(ENTRIES_SUFFIX) {
+            continue;
+        }
+        let child_path = if path.is_empty() { key.clone() } else { format!("{path}.{key}") };
+        match value {
+            Value::Object(child) => annotate(child, &parent, &child_path),
+            Value::Array(items) => {
+                for (i, item) in items.iter_mut().enumerate() {
+                    if let Value::Object(child) = item {
+                        annotate(child, &parent, &format!("{child_path}[{i}]"));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+const ENTRIES_SUFFIX: &str = "_entries";
+
+fn annotate(node: &mut Map<String, Value>, parent: &Value, path: &str) {
+    if !node.contains_key(PARENT) {
+        node.insert(PARENT.into(), parent.clone());
+    }
+    node.entry(PATH).or_insert_with(|| Value::String(path.to_string()));
+    annotate_children(node, path);
+}
+
+/// Marker on injected `parent` objects, so the walk never descends into them.
+const INJECTED: &str = "is_scope_ref";
+
+fn is_injected(value: &Value) -> bool {
+    value.get(INJECTED).and_then(Value::as_bool).unwrap_or(false)
+}
+
+/// Scalar fields of `node`, marked as injected.
+fn shallow(node: &Map<String, Value>) -> Map<String, Value> {
+    let mut scalars: Map<String, Value> = node
+        .iter()
+        .filter(|(_, v)| !(v.is_array() || v.is_object()))
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect();
+    scalars.insert(INJECTED.into(), Value::Bool(true));
+    scalars
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn rewrites_parent_and_root_tags() {
+        assert_eq!(
+            rewrite("{{#xs}}{{../name}}{{{../../a}}}{{#../ys}}{{/../ys}}{{@root.name}}{{& ../b}}{{/xs}}"),
+            "{{#xs}}{{parent.name}}{{{root.a}}}{{#parent.ys}}{{/parent.ys}}{{root.name}}{{& parent.b}}{{/xs}}"
+        );
+        assert!(matches!(rewrite("{{a}} ../b"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn deeper_references_resolve_through_enclosing_sections() {
+        let inner = "{{../../a}}|{{../../../b}}";
+        let wrap = |open: &str, close: &str| rewrite(&format!("{open}{inner}{close}")).into_owned();
+        // payload → trait → method: two up is the payload
+        assert_eq!(
+            wrap("{{#payload.traits}}{{#methods}}", "{{/methods}}{{/payload.traits}}"),
+            "{{#payload.traits}}{{#methods}}{{root.a}}|{{root.b}}{{/methods}}{{/payload.traits}}"
+        );
+        // a mapping section is reached by name; flag sections are not levels
+        assert_eq!(
+            wrap("{{#config}}{{#routes}}{{#args}}{{#hasNext}}", "{{/hasNext}}{{/args}}{{/routes}}{{/config}}"),
+            "{{#config}}{{#routes}}{{#args}}{{#hasNext}}{{config.a}}|{{root.b}}{{/hasNext}}{{/args}}{{/routes}}{{/config}}"
+        );
+        // a list element is reached through its dotted child's `parent`
+        assert_eq!(
+            wrap("{{#services}}{{#spec.routes}}", "{{/spec.routes}}{{/services}}"),
+            "{{#services}}{{#spec.routes}}{{spec.parent.a}}|{{root.b}}{{/spec.routes}}{{/services}}"
+        );
+    }
+
+    #[test]
+    fn nested_objects_get_parent_and_path() {
+        let mut ctx = json!({
+            "header": {},
+            "payload": {
+                "name": "svc",
+                "traits": [{ "name": "Store", "methods": [{ "name": "get" }], "path": "own" }],
+                "fields_entries": [{ "key": "id" }]
+            }
+        });
+        insert_scope_refs(&mut ctx);
+        let method = &ctx["payload"]["traits"][0]["methods"][0];
+        assert_eq!(method["path"], "traits[0].methods[0]");
+        assert_eq!(method["parent"]["name"], "Store");
+        assert!(method["parent"].get("parent").is_none(), "no ancestor chain");
+        assert!(method["parent"].get("methods").is_none(), "parents are shallow");
+        assert_eq!(ctx["payload"]["traits"][0]["path"], "own", "data keys win");
+        assert!(ctx["payload"]["fields_entries"][0].get("parent").is_none(), "entries are skipped");
+        assert_eq!(ctx["root"]["name"], "svc");
+    }
+}
@@ -0,0 +1,237 @@
+// ============================================================================
+// Forge Template — Strict Mode
+// File: codegen/strict.rs
+// Role: Optional check that every variable tag of a template resolves in the
+//       render context. mustache renders a missing key as "", which hides
+//       typos; strict mode reports it as `RenderError::MissingKey` instead.
+//       Sections over missing / falsy keys stay legal (that is how optional
+//       parts are written) and their bodies are only checked when rendered.
+// ============================================================================
+
+use crate::errors::RenderError;
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+
+/// Partial nesting depth after which checking stops (recursive partials).
+const MAX_PARTIAL_DEPTH: usize = 16;
+
+#[derive(Debug)]
+enum Node {
+    Var(String),
+    Section {
+        name: String,
+        inverted: bool,
+        children: Vec<Node>,
+    },
+    Partial(String),
+}
+
+/// Fail with `MissingKey` for the first variable tag of `template` that does
+/// not resolve against `ctx`. Templates using `{{=...=}}` delimiter changes
+/// are not checked.
+pub fn check(template: &str, ctx: &Value, partials_dir: &Path) -> Result<(), RenderError> {
+    let Some(nodes) = parse(template) else {
+        return Ok(());
+    };
+    let mut checker = Checker {
+        partials_dir,
+        sections: Vec::new(),
+        depth: 0,
+    };
+    checker.walk(&nodes, &mut vec![ctx])
+}
+
+struct Checker<'a> {
+    partials_dir: &'a Path,
+    /// Enclosing section names, for error messages
+    sections: Vec<String>,
+    depth: usize,
+}
+
+impl Checker<'_> {
+    fn walk(&mut self, nodes: &[Node], stack: &mut Vec<&Value>) -> Result<(), RenderError> {
+        for node in nodes {
+            match node {
+                Node::Var(name) => {
+                    if find(name, stack).is_none() {
+                        return Err(self.missing(name));
+                    }
+                }
+                Node::Section {
+                    name,
+                    inverted,
+                    children,
+                } => {
+                    self.sections.push(name.clone());
+                    let value = find(name, stack);
+                    let result = match (value, inverted) {
+                        (None, false) => Ok(()),
+                        (None, true) => self.walk(children, stack),
+                        (Some(v), true) if !truthy(v) => self.walk(children, stack),
+                        (Some(_), true) => Ok(()),
+                        (Some(Value::Array(items)), false) => items.iter().try_for_each(|item| {
+                            stack.push(item);
+                            let r = self.walk(children, stack);
+                            stack.pop();
+                            r
+                        }),
+                        (Some(Value::Bool(true)), false) => self.walk(children, stack),
+                        (Some(v), false) if truthy(v) => {
+                            stack.push(v);
+                            let r = self.walk(children, stack);
+                            stack.pop();
+                            r
+                        }
+                        (Some(_), false) => Ok(()),
+                    };
+                    self.sections.pop();
+                    result?;
+                }
+                Node::Partial(name) => {
+                    if self.depth >= MAX_PARTIAL_DEPTH {
+                        continue;
+                    }
+                    let path = self.partials_dir.join(format!("{name}.mustache"));
+                    let Some(nodes) = fs::read_to_string(path).ok().and_then(|t| parse(&t)) else {
+                        continue;
+                    };
+                    self.depth += 1;
+                    let r = self.walk(&nodes, stack);
+                    self.depth -= 1;
+                    r?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn missing(&self, name: &str) -> RenderError {
+        let key_path = if self.sections.is_empty() {
+            name.to_string()
+        } else {
+            let within: Vec<String> = self.sections.iter().map(|s| format!("#{s}")).collect();
+            format!("{name} (in {})", within.join(" > "))
+        };
+        RenderError::MissingKey { key_path }
+    }
+}
+
+/// mustache 0.9 lookup: first stack frame holding the head, then walk the rest.
+fn find<'v>(name: &str, stack: &[&'v Value]) -> Option<&'v Value> {
+    if name == "." {
+        return stack.last().copied();
+    }
+    let mut parts = name.split('.');
+    let head = parts.next()?;
+    let mut value = stack.iter().rev().find_map(|frame| frame.get(head))?;
+    for part in parts {
+        value = value.get(part)?;
+    }
+    Some(value)
+}
+
+/// Section truthiness as rendered by mustache 0.9 (`null` and `""` are falsy).
+fn truthy(v: &Value) -> bool {
+    match v {
+        Value::Null => false,
+        Value::Bool(b) => *b,
+        Value::String(s) => !s.is_empty(),
+        Value::Array(items) => !items.is_empty(),
+        Value::Number(_) | Value::Object(_) => true,
+    }
+}
+
+/// Tag tree of `text`; `None` for delimiter changes or malformed templates
+/// (the latter are reported by the mustache compiler).
+fn parse(text: &str) -> Option<Vec<Node>> {
+    let mut frames: Vec<(String, bool, Vec<Node>)> = vec![(String::new(), false, Vec::new())];
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let (inner, next) = if let Some(triple) = after.strip_prefix('{') {
+            let end = triple.find("}}}")?;
+            (format!("&{}", &triple[..end]), &triple[end + 3..])
+        } else {
+            let end = after.find("}}")?;
+            (after[..end].to_string(), &after[end + 2..])
+        };
+        rest = next;
+
+        let inner = inner.trim();
+        let (sigil, name) = match inner.chars().next()? {
+            c @ ('#' | '^' | '/' | '!' | '>' | '&' | '=') => (Some(c), inner[1..].trim()),
+            _ => (None, inner),
+        };
+        let current = &mut frames.last_mut()?.2;
+        match sigil {
+            None | Some('&') => current.push(Node::Var(name.to_string())),
+            Some('#') => frames.push((name.to_string(), false, Vec::new())),
+            Some('^') => frames.push((name.to_string(), true, Vec::new())),
+            Some('/') => {
+                let (open, inverted, children) = frames.pop()?;
+                if open != name || frames.is_empty() {
+                    return None;
+                }
+                frames.last_mut()?.2.push(Node::Section {
+                    name: open,
+                    inverted,
+                    children,
+                });
+            }
+            Some('>') => current.push(Node::Partial(name.to_string())),
+            Some('!') => {}
+            _ => return None, // `{{=<% %>=}}`
+        }
+    }
+    match frames.pop() {
+        Some((_, _, nodes)) if frames.is_empty() => Some(nodes),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn check_str(tpl: &str, ctx: Value) -> Result<(), String> {
+        check(tpl, &ctx, Path::new(".")).map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn reports_unresolved_variables_with_their_sections() {
+        let ctx = json!({ "payload": { "name": "w", "items": [{ "id": 1 }, { "id": 2, "extra": "x" }] } });
+
+        assert!(check_str(
+            "{{payload.name}}{{#payload.items}}{{id}}{{payload.name}}{{/payload.items}}",
+            ctx.clone()
+        )
+        .is_ok());
+        assert_eq!(
+            check_str(
+                "{{#payload.items}}{{{extra}}}{{/payload.items}}",
+                ctx.clone()
+            )
+            .unwrap_err(),
+            "missing key: extra (in #payload.items) (policy=Error)"
+        );
+        assert!(check_str("{{payload.nmae}}", ctx.clone()).is_err());
+    }
+
+    #[test]
+    fn optional_sections_and_inverted_bodies() {
+        let ctx = json!({ "flag": false, "empty": "", "list": [] });
+        // bodies that never render are not checked
+        assert!(check_str(
+            "{{#missing}}{{nope}}{{/missing}}{{#flag}}{{nope}}{{/flag}}{{#list}}{{nope}}{{/list}}",
+            ctx.clone()
+        )
+        .is_ok());
+        // inverted bodies render for missing / falsy values
+        assert!(check_str("{{^missing}}{{nope}}{{/missing}}", ctx.clone()).is_err());
+        assert!(check_str("{{^empty}}{{nope}}{{/empty}}", ctx.clone()).is_err());
+        // delimiter changes are not checked
+        assert!(check_str("{{=<% %>=}}<% nope %>", ctx).is_ok());
+    }
+}
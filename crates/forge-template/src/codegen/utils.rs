@@ -2,12 +2,13 @@
 // Forge Template Codegen Utilities
 // File: utils.rs
 // Role: Shared helpers for expansion routines — keeps `expand.rs` clean by
-//       encapsulating string/value expansion, hasNext flag insertion, and
+//       encapsulating string/value expansion, iteration flag insertion, and
 //       ValueTemplateExpansion integration logic.
 // ============================================================================
 
 use serde_json::{Map, Value};
 use std::collections::BTreeMap;
+use crate::codegen::flags::insert_type_flags;
 use crate::context::values_template_expansion::ValuesTemplateExpansion;
 
 // ----------------------------------------------------------------------------
@@ -35,21 +36,46 @@ pub fn insert_expansions(map: &mut Map<String, Value>, key: &str, value: &str) {
 // Array Utilities
 // ----------------------------------------------------------------------------
 
-/// Insert a `hasNext` boolean flag for all array elements.
-/// - Used in Mustache templates to detect the last element.
+/// Insert iteration metadata into every array element:
+/// `index`, `index1`, `isFirst`, `isLast`, `hasPrev`, `hasNext`.
+/// - Non-object elements are wrapped as `{ value, is_* }` so they are flagged too.
 /// - Returns `Value::Array` with modified elements.
-pub fn insert_has_next_flag(arr: Vec<Value>) -> Value {
+pub fn insert_iteration_flags(arr: Vec<Value>) -> Value {
     let len = arr.len();
-    let mut out = Vec::with_capacity(len);
-
-    for (i, mut v) in arr.into_iter().enumerate() {
-        if let Some(obj) = v.as_object_mut() {
-            obj.insert("hasNext".into(), Value::Bool(i < len - 1));
-            out.push(Value::Object(obj.clone()));
-        } else {
-            out.push(v);
-        }
-    }
-
+    let out = arr
+        .into_iter()
+        .enumerate()
+        .map(|(i, v)| {
+            let mut obj = match v {
+                Value::Object(obj) => obj,
+                other => {
+                    let mut wrapped = Map::new();
+                    insert_type_flags(&mut wrapped, &other);
+                    wrapped.insert("value".into(), other);
+                    wrapped
+                }
+            };
+            insert_position_flags(&mut obj, i, len);
+            Value::Object(obj)
+        })
+        .collect();
     Value::Array(out)
 }
+
+/// Iteration metadata for item `i` of `len` (shared by arrays and `_entries`
+/// lists), unless the item already defines a key.
+pub fn insert_position_flags(obj: &mut Map<String, Value>, i: usize, len: usize) {
+    obj.entry("index").or_insert(Value::from(i));
+    obj.entry("index1").or_insert(Value::from(i + 1));
+    obj.entry("isFirst").or_insert(Value::Bool(i == 0));
+    obj.entry("isLast").or_insert(Value::Bool(i + 1 == len));
+    obj.entry("hasPrev").or_insert(Value::Bool(i > 0));
+    obj.entry("hasNext").or_insert(Value::Bool(i + 1 < len));
+}
+
+/// `<key>_len` and `<key>_is_empty` for an array field, unless the data
+/// already defines them.
+pub fn insert_length_flags(map: &mut Map<String, Value>, key: &str, len: usize) {
+    map.entry(format!("{key}_len")).or_insert(Value::from(len));
+    map.entry(format!("{key}_is_empty")).or_insert(Value::Bool(len == 0));
+}
@@ -6,8 +6,9 @@
 // ============================================================================
 
 use serde_json::{json, Map, Value};
-use crate::context::helpers::{header_file_or_default, header_template_or_default};
-use crate::codegen::expand::expand_value;
+use crate::context::helpers::{header_file_or_default, header_str_or_default, header_template_or_default};
+use crate::codegen::expand::{expand_value_with, EntriesOrder, ExpandOptions};
+use crate::codegen::scope::insert_scope_refs;
 
 // -----------------------------------------------------------------------------
 // Public Context Builders
@@ -16,18 +17,13 @@ use crate::codegen::expand::expand_value;
 /// Domain-agnostic build: merges header + payload and expands naming variants recursively.
 /// This function now delegates to `codegen::expand::expand_value` for all deep logic.
 pub fn build_codegen_context(conditioned: &Value) -> Value {
-    let header = conditioned.get("header").cloned().unwrap_or_else(|| json!({}));
-    let payload = conditioned.get("payload").cloned().unwrap_or_else(|| json!({}));
-
     // Merge header + payload
-    let mut merged: Map<String, Value> = header.as_object().cloned().unwrap_or_default();
-    if let Some(pobj) = payload.as_object() {
-        for (k, v) in pobj {
-            merged.insert(k.clone(), v.clone());
-        }
+    let mut expanded =
+        expand_value_with(&Value::Object(merge_header_payload(conditioned)), &expand_options(conditioned));
+    if scope_refs(conditioned) {
+        insert_scope_refs(&mut expanded);
     }
-
-    expand_value(&Value::Object(merged))
+    expanded
 }
 
 /// Standard entrypoint used by `render_yaml_from_abs`.
@@ -42,25 +38,45 @@ pub fn build_yaml_codegen_context(conditioned: &Value) -> Value {
         .unwrap_or("")
         .to_string();
 
-    let header = conditioned.get("header").cloned().unwrap_or_else(|| json!({}));
-    let payload = conditioned.get("payload").cloned().unwrap_or_else(|| json!({}));
-
     // Merge
-    let mut merged: Map<String, Value> = header.as_object().cloned().unwrap_or_default();
-    if let Some(pobj) = payload.as_object() {
-        for (k, v) in pobj {
-            merged.insert(k.clone(), v.clone());
-        }
-    }
+    let expanded = expand_value_with(&Value::Object(merge_header_payload(conditioned)), &expand_options(conditioned));
 
-    let expanded = expand_value(&Value::Object(merged));
-
-    json!({
+    let mut ctx = json!({
         "header": {
             "file": file,
             "template": template,
             "type": ytype
         },
         "payload": expanded
-    })
+    });
+    if scope_refs(conditioned) {
+        insert_scope_refs(&mut ctx);
+    }
+    ctx
+}
+
+/// `parent` / `root` / `path` references are off unless `header.scope_refs: true`.
+fn scope_refs(conditioned: &Value) -> bool {
+    conditioned
+        .pointer("/header/scope_refs")
+        .and_then(Value::as_bool)
+        .unwrap_or(false)
+}
+
+/// Expansion options from the header: `entries_order: source | key`
+/// (anything else keeps the default, `source`).
+fn expand_options(conditioned: &Value) -> ExpandOptions {
+    ExpandOptions {
+        entries_order: EntriesOrder::parse(header_str_or_default(conditioned, "entries_order", "source"))
+            .unwrap_or_default(),
+    }
+}
+
+/// Header keys overlaid with payload keys, copying each value once.
+fn merge_header_payload(conditioned: &Value) -> Map<String, Value> {
+    let section = |key: &str| conditioned.get(key).and_then(Value::as_object).into_iter().flatten();
+    section("header")
+        .chain(section("payload"))
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect()
 }
@@ -2,6 +2,7 @@
 //! Generic conditioning: only stabilize the shapes of `header` and `payload`.
 //! - If `header` is an object, pass it through; otherwise use `{}`
 //! - If `payload` is an object, pass it through; otherwise use `{}`
+//!
 //! No other keys are interpreted or synthesized here.
 
 use serde_json::{Map, Value};
@@ -31,7 +32,6 @@ pub fn condition_schema(raw: &Value) -> Value {
 }
 
 /// YAML path: generic—only return `{ header: {...}, payload: {...} }`.
-#[cfg_attr(feature = "trace", tracing::instrument(level = "debug", skip_all))]
 pub fn condition_yaml(raw: &Value) -> Value {
     condition_generic(raw)
 }
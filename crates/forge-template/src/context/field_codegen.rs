@@ -14,8 +14,6 @@ fn merge_expansions(obj: &mut Map<String, Value>, key: &str, value: &str) {
 /// Build a `field` object given a `name`, its spec, and a setter prefix (header/payload).
 #[inline]
 pub fn build_field_object(name: &str, spec: &Value, setter_prefix: &str) -> Value {
-    use serde_json::json;
-
     let json_ty = spec.get("type").and_then(Value::as_str).unwrap_or("string");
     let rust_ty = json_type_to_rust(json_ty);
 
@@ -73,10 +71,10 @@ mod tests {
     #[test]
     fn build_field_object_integer_payload() {
         let spec   = mk_spec("integer");
-        let fieldV = build_field_object("user_id", &spec, "payload");
+        let field_v = build_field_object("user_id", &spec, "payload");
 
         // must be an object
-        let field = fieldV.as_object().expect("field should be an object");
+        let field = field_v.as_object().expect("field should be an object");
 
         // base keys populated
         assert_eq!(field.get("name").and_then(Value::as_str), Some("user_id"));
@@ -106,8 +104,8 @@ mod tests {
     #[test]
     fn build_field_object_string_header() {
         let spec   = mk_spec("string");
-        let fieldV = build_field_object("version", &spec, "header");
-        let field  = fieldV.as_object().unwrap();
+        let field_v = build_field_object("version", &spec, "header");
+        let field  = field_v.as_object().unwrap();
 
         assert_eq!(field.get("name").and_then(Value::as_str), Some("version"));
         assert_eq!(field.get("json_type").and_then(Value::as_str), Some("string"));
@@ -163,8 +161,8 @@ mod tests {
     fn default_type_when_missing() {
         // if "type" is missing, default should behave as "string"
         let spec = json!({});
-        let fieldV = build_field_object("note", &spec, "payload");
-        let field  = fieldV.as_object().unwrap();
+        let field_v = build_field_object("note", &spec, "payload");
+        let field  = field_v.as_object().unwrap();
 
         assert_eq!(field.get("json_type").and_then(Value::as_str), Some("string"));
         assert_eq!(field.get("rust_type").and_then(Value::as_str), Some("String"));
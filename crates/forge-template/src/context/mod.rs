@@ -6,13 +6,16 @@ pub mod value;
 pub mod condition;
 pub mod values_template_expansion;
 pub mod codegen_ctx;
+#[allow(dead_code)]
 pub(crate) mod helpers;
 pub mod field_codegen;
 
 pub use value::Value;
 
 /// Wrapper around a string-keyed map used for rendering contexts.
-#[derive(Debug, Default, Clone, PartialEq)]
+/// Serializes as the inner map, so it can be passed to [`crate::Template::render`].
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize)]
+#[serde(transparent)]
 pub struct Context(pub BTreeMap<String, Value>);
 
 impl Context {
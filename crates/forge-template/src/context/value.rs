@@ -1,7 +1,8 @@
 //! Value model + serde bridge for template contexts.
 
 use std::collections::BTreeMap;
-use serde::Serialize;
+use serde::ser::{SerializeMap, SerializeSeq};
+use serde::{Serialize, Serializer};
 
 /// Minimal JSON-like value type we control (stable, no serde_json leakage).
 #[derive(Debug, Clone, PartialEq)]
@@ -37,6 +38,33 @@ impl From<serde_json::Value> for Value {
     }
 }
 
+impl Serialize for Value {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Value::Null => serializer.serialize_unit(),
+            Value::Bool(b) => serializer.serialize_bool(*b),
+            // integral numbers stay integers so `{{count}}` renders `3`, not `3.0`
+            Value::Number(n) if n.fract() == 0.0 && n.abs() < i64::MAX as f64 => serializer.serialize_i64(*n as i64),
+            Value::Number(n) => serializer.serialize_f64(*n),
+            Value::String(s) => serializer.serialize_str(s),
+            Value::Array(items) => {
+                let mut seq = serializer.serialize_seq(Some(items.len()))?;
+                for item in items {
+                    seq.serialize_element(item)?;
+                }
+                seq.end()
+            }
+            Value::Object(map) => {
+                let mut out = serializer.serialize_map(Some(map.len()))?;
+                for (k, v) in map {
+                    out.serialize_entry(k, v)?;
+                }
+                out.end()
+            }
+        }
+    }
+}
+
 /// Convert any `Serialize` data into our `Value`.
 pub fn from_serde<T: Serialize>(t: &T) -> Result<Value, crate::errors::RenderError> {
     serde_json::to_value(t)
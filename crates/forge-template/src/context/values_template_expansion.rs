@@ -5,7 +5,7 @@
 //! - Sanitizes: trims + removes non [A-Za-z0-9_-]
 //! - Converts to snake_case, then emits variants from that baseline
 //! - Output keys follow the JS suffixes exactly:
-//!     _snake_case, _SCREAMING_SNAKE_CASE, _PascalCase, _camelCase, _kebab_case
+//!   _snake_case, _SCREAMING_SNAKE_CASE, _PascalCase, _camelCase, _kebab_case
 
 use std::collections::BTreeMap;
 
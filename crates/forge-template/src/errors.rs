@@ -25,7 +25,30 @@ pub enum RenderError {
 
     #[error("serde conversion failed: {0}")]
     Serde(String),
+
+    #[error("render failed: {msg}")]
+    Render { msg: String },
+
+    #[error(transparent)]
+    Ast(#[from] AstErrors),
+
+    #[error("write failed: {0}")]
+    Write(#[source] std::io::Error),
 }
 
 /// Convenience alias used throughout the crate.
-pub type Result<T> = std::result::Result<T, RenderError>;
\ No newline at end of file
+pub type Result<T> = std::result::Result<T, RenderError>;
+
+/// One problem found while validating a typed Rust AST (`payload.ast`).
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error("{path}: {message}")]
+pub struct AstError {
+    /// Location inside the AST, e.g. `items[1].methods[0].args[2]`
+    pub path: String,
+    pub message: String,
+}
+
+/// Every validation problem of one AST, reported together.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error("invalid Rust AST:\n  {}", .0.iter().map(ToString::to_string).collect::<Vec<_>>().join("\n  "))]
+pub struct AstErrors(pub Vec<AstError>);
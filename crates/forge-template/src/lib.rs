@@ -3,12 +3,19 @@
 //! Core template rendering library for Forge projects.
 //! Provides YAML → Mustache → rendered text pipeline,
 //! plus an optional CLI (`forge-template`) and future service mode.
+//! Library users compile a [`Template`] once and render it with any
+//! `Serialize` value or [`context::Context`].
 
+pub mod ast;
+pub mod bdd;
+pub mod build;
 pub mod codegen;
 pub mod context;
 pub mod utils;
 pub mod errors;
 pub mod helpers;
+pub mod template;
+pub mod tokens;
 
 // -----------------------------------------------------------------------------
 // Optional higher layers (CLI + future HTTP service)
@@ -18,6 +25,10 @@ pub mod cli;
 // -----------------------------------------------------------------------------
 // Public re-exports (primary API surface)
 // -----------------------------------------------------------------------------
-pub use codegen::render_yaml_from_abs;
+pub use codegen::{
+    render_value_from_abs, render_value_tokens_from_abs, render_yaml_from_abs,
+    render_yaml_tokens_from_abs,
+};
 pub use errors::*;
+pub use template::Template;
 pub use helpers::*;
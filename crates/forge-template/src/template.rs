@@ -0,0 +1,251 @@
+// ============================================================================
+// Forge Template — Library API
+// File: template.rs
+// Role: Compile a Mustache template once (from text or a file) and render it
+//       many times with any `Serialize` value or `Context`, through the same
+//       condition → expand pipeline as `render_yaml_from_abs`. Output goes to
+//       a `String` or any `io::Write`.
+// ============================================================================
+
+use std::borrow::Cow;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::codegen::render::{partials_dir, read_template};
+use crate::codegen::{scope, strict};
+use crate::context::{codegen_ctx, condition};
+use crate::errors::{RenderError, Result};
+
+/// A compiled template plus its render options.
+///
+/// ```
+/// use forge_template::Template;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Widget { name: &'static str }
+///
+/// let tpl = Template::compile("pub struct {{payload.name_PascalCase}};").unwrap();
+/// assert_eq!(tpl.render(&Widget { name: "big_widget" }).unwrap(), "pub struct BigWidget;");
+/// ```
+#[derive(Debug)]
+pub struct Template {
+    text: String,
+    compiled: mustache::Template,
+    partials_dir: PathBuf,
+    expand: bool,
+    strict: bool,
+    /// Uses `../` or `@root`, so renders need scope references
+    scoped: bool,
+}
+
+impl Template {
+    /// Compile template text; partials resolve against the working directory.
+    pub fn compile(text: impl Into<String>) -> Result<Self> {
+        Self::compile_with_partials(text, ".")
+    }
+
+    /// Compile template text; `{{> name}}` resolves to `<partials_dir>/name.mustache`.
+    /// `{{../x}}` and `{{@root.x}}` are rewritten to `parent.x` / `root.x`
+    /// (see [`scope`]), and turn on `header.scope_refs` unless the values
+    /// set it.
+    pub fn compile_with_partials(text: impl Into<String>, partials_dir: impl Into<PathBuf>) -> Result<Self> {
+        let partials_dir = partials_dir.into();
+        let source = text.into();
+        let prepared = scope::prepare_template(&source, &partials_dir);
+        // only scope syntax makes the prepared text differ from the source
+        let scoped = matches!(prepared, Cow::Owned(_));
+        let text = prepared.into_owned();
+        let compiled = mustache::Context::new(partials_dir.clone())
+            .compile(text.chars())
+            .map_err(|e| RenderError::InvalidTemplate { msg: e.to_string() })?;
+        Ok(Self {
+            text,
+            compiled,
+            partials_dir,
+            expand: true,
+            strict: false,
+            scoped,
+        })
+    }
+
+    /// Read and compile a template file; partials resolve next to it.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let text = read_template(path).map_err(|e| RenderError::Io {
+            path: path.to_path_buf(),
+            source: e
+                .downcast::<std::io::Error>()
+                .unwrap_or_else(|e| std::io::Error::other(e.to_string())),
+        })?;
+        Self::compile_with_partials(text, partials_dir(path))
+    }
+
+    /// With `false`, values are rendered exactly as serialized: no
+    /// header/payload conditioning and no naming variants. Default `true`.
+    pub fn expand(mut self, on: bool) -> Self {
+        self.expand = on;
+        self
+    }
+
+    /// With `true`, a variable tag missing from the context fails the render
+    /// with [`RenderError::MissingKey`]. Default `false`.
+    pub fn strict(mut self, on: bool) -> Self {
+        self.strict = on;
+        self
+    }
+
+    /// Render `values` (any `Serialize`, including [`crate::context::Context`]).
+    pub fn render<T: Serialize + ?Sized>(&self, values: &T) -> Result<String> {
+        let mut out = Vec::new();
+        self.render_to(values, &mut out)?;
+        String::from_utf8(out).map_err(|e| RenderError::Render { msg: e.to_string() })
+    }
+
+    /// Render `values` into `out`.
+    pub fn render_to<T: Serialize + ?Sized, W: Write>(&self, values: &T, out: W) -> Result<()> {
+        match self.prepare(values)? {
+            Prepared::Source(source) => write_all(out, &source),
+            Prepared::Context(ctx) => self.write_context(&ctx, self.strict, out),
+        }
+    }
+
+    /// Render an already built codegen context (pipeline callers).
+    pub(crate) fn render_context(&self, ctx: &Value, strict: bool) -> Result<String> {
+        let mut out = Vec::new();
+        self.write_context(ctx, strict, &mut out)?;
+        String::from_utf8(out).map_err(|e| RenderError::Render { msg: e.to_string() })
+    }
+
+    fn write_context<W: Write>(&self, ctx: &Value, strict: bool, mut out: W) -> Result<()> {
+        if strict {
+            strict::check(&self.text, ctx, &self.partials_dir)?;
+        }
+        self.compiled
+            .render_data(&mut out, &crate::utils::to_data(ctx))
+            .map_err(|e| match e {
+                mustache::Error::Io(e) => RenderError::Write(e),
+                other => RenderError::Render { msg: other.to_string() },
+            })
+    }
+
+    /// The context the template sees for `values` (useful for debugging templates).
+    pub fn context<T: Serialize + ?Sized>(&self, values: &T) -> Result<Value> {
+        match self.prepare(values)? {
+            Prepared::Context(ctx) => Ok(ctx),
+            Prepared::Source(_) => Err(RenderError::InvalidTemplate {
+                msg: "`header.backend: rust_ast` values are not rendered through a template".into(),
+            }),
+        }
+    }
+
+    fn prepare<T: Serialize + ?Sized>(&self, values: &T) -> Result<Prepared> {
+        let raw = serde_json::to_value(values).map_err(|e| RenderError::Serde(e.to_string()))?;
+        if !self.expand {
+            return Ok(Prepared::Context(raw));
+        }
+        let mut conditioned = condition::condition_yaml(&with_payload(raw));
+        if crate::ast::uses_rust_ast_backend(&conditioned) {
+            return Ok(Prepared::Source(crate::ast::emit_source(&conditioned)?));
+        }
+        crate::ast::apply(&mut conditioned)?;
+        Ok(Prepared::Context(self.build_context(conditioned)))
+    }
+
+    /// Codegen context for conditioned values; scope references default to
+    /// on when the template uses them.
+    pub(crate) fn build_context(&self, mut conditioned: Value) -> Value {
+        if self.scoped {
+            default_scope_refs(&mut conditioned);
+        }
+        codegen_ctx::build_yaml_codegen_context(&conditioned)
+    }
+}
+
+enum Prepared {
+    Context(Value),
+    /// Output of the `rust_ast` backend, which bypasses the template
+    Source(String),
+}
+
+fn write_all<W: Write>(mut out: W, text: &str) -> Result<()> {
+    out.write_all(text.as_bytes()).map_err(RenderError::Write)
+}
+
+/// `header.scope_refs: true` unless the values already decide.
+fn default_scope_refs(conditioned: &mut Value) {
+    if let Some(root) = conditioned.as_object_mut() {
+        let header = root.entry("header").or_insert_with(|| Value::Object(Map::new()));
+        if let Some(header) = header.as_object_mut() {
+            header.entry("scope_refs").or_insert(Value::Bool(true));
+        }
+    }
+}
+
+/// Values without `header` / `payload` keys are the payload itself; anything
+/// else is returned as is. Shared with `render_yaml!` inline values.
+pub fn with_payload(values: Value) -> Value {
+    let wrapped = values
+        .as_object()
+        .is_some_and(|m| m.contains_key("header") || m.contains_key("payload"));
+    if wrapped {
+        values
+    } else {
+        Value::Object(Map::from_iter([("payload".to_string(), values)]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::{self, Context};
+    use serde_json::json;
+
+    #[test]
+    fn renders_serialize_values_and_contexts_through_the_pipeline() {
+        let tpl = Template::compile("{{#payload.fields}}{{name_camelCase}}:{{type}}{{#hasNext}},{{/hasNext}}{{/payload.fields}}")
+            .unwrap();
+        let values = json!({ "fields": [{ "name": "user_id", "type": "u64" }, { "name": "label", "type": "String" }] });
+        assert_eq!(tpl.render(&values).unwrap(), "userId:u64,label:String");
+
+        // header/payload documents are conditioned as-is
+        let doc = json!({ "header": { "type": "dto" }, "payload": values });
+        assert_eq!(tpl.render(&doc).unwrap(), "userId:u64,label:String");
+
+        let mut ctx = Context::new();
+        ctx.insert("count", context::Value::Number(3.0));
+        let counted = Template::compile("{{payload.count}}").unwrap();
+        let mut out = Vec::new();
+        counted.render_to(&ctx, &mut out).unwrap();
+        assert_eq!(out, b"3");
+
+        let verbatim = Template::compile("{{count}}|{{count_snake_case}}").unwrap().expand(false);
+        assert_eq!(verbatim.render(&ctx).unwrap(), "3|");
+    }
+
+    #[test]
+    fn file_templates_partials_and_strict_mode() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        std::fs::write(dir.join("main.mustache"), "mod {{payload.name_snake_case}};{{> tail}}").unwrap();
+        std::fs::write(dir.join("tail.mustache"), " // {{payload.nmae}}").unwrap();
+
+        let tpl = Template::from_path(dir.join("main.mustache")).unwrap();
+        assert_eq!(tpl.render(&json!({ "name": "FileTree" })).unwrap(), "mod file_tree; // ");
+
+        let err = tpl.strict(true).render(&json!({ "name": "FileTree" })).unwrap_err();
+        assert!(matches!(err, RenderError::MissingKey { ref key_path } if key_path == "payload.nmae"), "{err}");
+
+        // partials may use scope syntax too
+        std::fs::write(dir.join("list.mustache"), "{{#payload.items}}{{> item}}{{/payload.items}}").unwrap();
+        std::fs::write(dir.join("item.mustache"), "{{label}}<{{../name}}>").unwrap();
+        let list = Template::from_path(dir.join("list.mustache")).unwrap().strict(true);
+        assert_eq!(list.render(&json!({ "name": "ui", "items": [{ "label": "a" }] })).unwrap(), "a<ui>");
+
+        assert!(matches!(Template::from_path(dir.join("nope.mustache")), Err(RenderError::Io { .. })));
+        assert!(matches!(Template::compile("{{#open}}"), Err(RenderError::InvalidTemplate { .. })));
+    }
+}
@@ -0,0 +1,404 @@
+// ============================================================================
+// Forge Template — Design Token Compiler
+// File: tokens/mod.rs
+// Role: Turns a design-token YAML (`payload.structs`, one group of
+//       key → CSS value fields each) into ordered groups, validates every
+//       value against its kind and every `var(--…)` reference against the
+//       set, and emits CSS `:root` custom properties, a Rust `tokens` module
+//       of typed consts and a TypeScript `.d.ts`. Groups and fields keep the
+//       order they are written in. Custom templates receive `context()`.
+// ============================================================================
+
+pub mod value;
+
+pub use value::TokenKind;
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use once_cell::sync::Lazy;
+use serde_json::{json, Value};
+use serde_yaml::Value as Yaml;
+
+use crate::context::values_template_expansion::ValuesTemplateExpansion as Naming;
+use crate::template::Template;
+
+static CSS: Lazy<Template> = Lazy::new(|| embedded(include_str!("templates/css.mustache")));
+static RUST: Lazy<Template> = Lazy::new(|| embedded(include_str!("templates/rust.mustache")));
+static DTS: Lazy<Template> = Lazy::new(|| embedded(include_str!("templates/dts.mustache")));
+
+fn embedded(text: &str) -> Template {
+    Template::compile(text).expect("embedded token template compiles").expand(false)
+}
+
+// ----------------------------------------------------------------------------
+// Model
+// ----------------------------------------------------------------------------
+
+/// Every token group of one file, in file order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenSet {
+    /// Where the tokens came from (shown in generated headers)
+    pub source: String,
+    pub groups: Vec<TokenGroup>,
+}
+
+/// One `payload.structs[]` entry, e.g. `Colors`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenGroup {
+    pub name: String,
+    /// Declared `kind:`; applies to fields without their own
+    pub kind: Option<TokenKind>,
+    pub tokens: Vec<Token>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    pub key: String,
+    /// Literal CSS value
+    pub value: String,
+    /// Declared (field, then group) or inferred from the value
+    pub kind: TokenKind,
+}
+
+impl TokenGroup {
+    /// snake_case group name (`Colors` → `colors`)
+    pub fn snake(&self) -> String {
+        snake(&self.name)
+    }
+
+    /// CSS custom property of `token`: `--<group>-<key>` in kebab-case.
+    pub fn var_name(&self, token: &Token) -> String {
+        format!("--{}-{}", Naming::snake_to_kebab(&self.snake()), Naming::snake_to_kebab(&snake(&token.key)))
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Loading & validation
+// ----------------------------------------------------------------------------
+
+/// Read, parse and validate a token file.
+pub fn load(path: &Path) -> Result<TokenSet> {
+    let text = fs::read_to_string(path).with_context(|| format!("read design tokens {}", path.display()))?;
+    parse(&text, &path.display().to_string()).with_context(|| format!("invalid design tokens {}", path.display()))
+}
+
+/// Parse and validate token YAML:
+///
+/// ```yaml
+/// payload:
+///   structs:
+///     - name: Colors
+///       kind: color            # optional; otherwise inferred per value
+///       fields:
+///         bg: "#0d0d0d"
+///         accent: { value: "var(--colors-bg)", kind: color }
+/// ```
+pub fn parse(text: &str, source: &str) -> Result<TokenSet> {
+    let doc: Yaml = serde_yaml::from_str(text)?;
+    let structs = doc
+        .get("payload")
+        .unwrap_or(&doc)
+        .get("structs")
+        .and_then(Yaml::as_sequence)
+        .context("expected a `payload.structs` list of token groups")?;
+
+    let mut problems = Vec::new();
+    let mut groups = Vec::new();
+    for (i, group) in structs.iter().enumerate() {
+        let Some(name) = group.get("name").and_then(Yaml::as_str) else {
+            problems.push(format!("structs[{i}]: missing `name`"));
+            continue;
+        };
+        let kind = match group.get("kind").map(parse_kind).transpose() {
+            Ok(kind) => kind,
+            Err(e) => {
+                problems.push(format!("{name}: {e}"));
+                None
+            }
+        };
+        let Some(fields) = group.get("fields").and_then(Yaml::as_mapping) else {
+            problems.push(format!("{name}: missing `fields` mapping"));
+            continue;
+        };
+
+        let mut tokens = Vec::new();
+        for (key, field) in fields {
+            let key = scalar(key).unwrap_or_default();
+            let (value, field_kind) = match field {
+                Yaml::Mapping(_) => {
+                    let own = field.get("kind").map(parse_kind).transpose();
+                    let own = own.unwrap_or_else(|e| {
+                        problems.push(format!("{name}.{key}: {e}"));
+                        None
+                    });
+                    (field.get("value").and_then(scalar), own)
+                }
+                other => (scalar(other), None),
+            };
+            let Some(value) = value else {
+                problems.push(format!("{name}.{key}: value must be a string or number"));
+                continue;
+            };
+            let kind = field_kind.or(kind).unwrap_or_else(|| TokenKind::infer(&value));
+            tokens.push(Token { key, value, kind });
+        }
+        groups.push(TokenGroup { name: name.to_string(), kind, tokens });
+    }
+
+    let set = TokenSet { source: source.to_string(), groups };
+    problems.extend(set.problems());
+    if !problems.is_empty() {
+        bail!("{}", problems.join("\n"));
+    }
+    Ok(set)
+}
+
+impl TokenSet {
+    /// Naming clashes, kind mismatches and dangling `var()` references.
+    fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        let mut group_names = BTreeSet::new();
+        let mut vars = BTreeSet::new();
+        for group in &self.groups {
+            if !group_names.insert(group.snake()) {
+                problems.push(format!("{}: group name clashes with an earlier group", group.name));
+            }
+            // the snake_case name becomes a Rust module
+            if syn::parse_str::<syn::Ident>(&group.snake()).is_err() {
+                problems.push(format!("{}: `{}` is not usable as a Rust module name", group.name, group.snake()));
+            }
+            let mut keys = BTreeSet::new();
+            for token in &group.tokens {
+                if !keys.insert(snake(&token.key)) {
+                    problems.push(format!("{}.{}: key clashes with an earlier key", group.name, token.key));
+                }
+                if let Some(problem) = token.kind.check(&token.value) {
+                    problems.push(format!("{}.{} ({}): {problem}", group.name, token.key, token.kind.as_str()));
+                }
+                vars.insert(group.var_name(token));
+            }
+        }
+        for group in &self.groups {
+            for token in &group.tokens {
+                for var in value::var_refs(&token.value) {
+                    if !vars.contains(var) {
+                        problems.push(format!("{}.{}: `var({var})` is not a token", group.name, token.key));
+                    }
+                }
+            }
+        }
+        problems
+    }
+
+    /// Ordered template context:
+    /// `{ source, groups: [{ name, name_<case>…, kind, tokens: [{ key,
+    /// key_<case>…, var, value, kind, is_<kind>, rust_const, rust_type,
+    /// rust_value, rust_str, ts_key, ts_value, hasNext }], ts_key, hasNext }] }`.
+    pub fn context(&self) -> Value {
+        let groups: Vec<Value> = self
+            .groups
+            .iter()
+            .enumerate()
+            .map(|(i, group)| {
+                let tokens: Vec<Value> = group
+                    .tokens
+                    .iter()
+                    .enumerate()
+                    .map(|(j, token)| {
+                        let key = snake(&token.key);
+                        let (rust_type, rust_value) = value::rust_literal(token.kind, &token.value);
+                        let mut entry = json!({
+                            "key": token.key,
+                            "var": group.var_name(token),
+                            "value": token.value,
+                            "kind": token.kind.as_str(),
+                            "rust_const": rust_const(&key),
+                            "rust_type": rust_type,
+                            "rust_value": rust_value,
+                            "rust_str": format!("{:?}", token.value),
+                            "ts_key": ts_key(&Naming::snake_to_camel(&key)),
+                            "ts_value": serde_json::to_string(&token.value).unwrap_or_default(),
+                            "hasNext": j + 1 < group.tokens.len(),
+                        });
+                        insert_cases(&mut entry, "key", &key);
+                        entry[format!("is_{}", token.kind.as_str())] = json!(true);
+                        entry
+                    })
+                    .collect();
+                let mut entry = json!({
+                    "name": group.name,
+                    "kind": group.kind.map(TokenKind::as_str),
+                    "ts_key": ts_key(&Naming::snake_to_camel(&group.snake())),
+                    "tokens": tokens,
+                    "hasNext": i + 1 < self.groups.len(),
+                });
+                insert_cases(&mut entry, "name", &group.snake());
+                entry
+            })
+            .collect();
+        json!({ "source": self.source, "groups": groups })
+    }
+
+    /// `:root { --group-key: value; … }`
+    pub fn to_css(&self) -> Result<String> {
+        Ok(CSS.render(&self.context())?)
+    }
+
+    /// Rust module body with `Color` / `Length` types and one module of
+    /// consts per group; meant for `mod tokens;` or `include!`.
+    pub fn to_rust(&self) -> Result<String> {
+        Ok(RUST.render(&self.context())?)
+    }
+
+    /// TypeScript declarations for the same tokens (camelCase keys).
+    pub fn to_dts(&self) -> Result<String> {
+        Ok(DTS.render(&self.context())?)
+    }
+
+    /// Render a custom template against [`TokenSet::context`].
+    pub fn render_with(&self, template_path: &Path) -> Result<String> {
+        let template = Template::from_path(template_path)?.expand(false);
+        Ok(template.render(&self.context())?)
+    }
+}
+
+fn parse_kind(value: &Yaml) -> Result<TokenKind> {
+    serde_yaml::from_value(value.clone()).map_err(|_| {
+        anyhow::anyhow!("unknown kind {value:?}; expected color, length, number, shadow, font_family or raw")
+    })
+}
+
+/// Strings, numbers and booleans as text (`weight: 400` is fine).
+fn scalar(value: &Yaml) -> Option<String> {
+    match value {
+        Yaml::String(s) => Some(s.clone()),
+        Yaml::Number(n) => Some(n.to_string()),
+        Yaml::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+fn snake(name: &str) -> String {
+    let clean = Naming::sanitize(name);
+    Naming::to_snake_case(&clean, Naming::detect_format(&clean))
+        .replace('-', "_")
+        .to_ascii_lowercase()
+}
+
+fn insert_cases(entry: &mut Value, key: &str, snake: &str) {
+    entry[format!("{key}_snake_case")] = json!(snake);
+    entry[format!("{key}_kebab_case")] = json!(Naming::snake_to_kebab(snake));
+    entry[format!("{key}_camelCase")] = json!(Naming::snake_to_camel(snake));
+    entry[format!("{key}_PascalCase")] = json!(Naming::snake_to_pascal(snake));
+    entry[format!("{key}_SCREAMING_SNAKE_CASE")] = json!(Naming::snake_to_screaming(snake));
+}
+
+/// SCREAMING_SNAKE const name; keys starting with a digit (`2xl`) get `_`.
+fn rust_const(snake: &str) -> String {
+    let name = Naming::snake_to_screaming(snake);
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{name}")
+    } else {
+        name
+    }
+}
+
+/// Property name, quoted unless it is a plain identifier.
+fn ts_key(camel: &str) -> String {
+    let plain = camel.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && camel.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if plain {
+        camel.to_string()
+    } else {
+        format!("{camel:?}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOKENS: &str = r##"
+payload:
+  structs:
+    - name: Colors
+      kind: color
+      fields:
+        primary_fg: "#fff"
+        bg: "#0d0d0d"
+    - name: Spacing
+      fields:
+        "2xl": "3rem"
+        md: "1rem"
+    - name: Button
+      fields:
+        bg: { value: "var(--colors-primary-fg)", kind: color }
+        weight: 500
+"##;
+
+    #[test]
+    fn emits_css_rust_and_dts_in_file_order() {
+        let set = parse(TOKENS, "tokens.yaml").unwrap();
+        assert_eq!(set.groups[1].tokens[0].kind, TokenKind::Length);
+
+        let css = set.to_css().unwrap();
+        assert!(
+            css.contains("  /* Colors */\n  --colors-primary-fg: #fff;\n  --colors-bg: #0d0d0d;\n"),
+            "{css}"
+        );
+        assert!(css.contains("  --spacing-2xl: 3rem;\n") && css.contains("  --button-bg: var(--colors-primary-fg);\n"));
+
+        let rust = set.to_rust().unwrap();
+        assert!(rust.contains("pub mod colors {"), "{rust}");
+        assert!(rust.contains("    pub const PRIMARY_FG: Color = Color { r: 255, g: 255, b: 255, a: 255 };\n"), "{rust}");
+        assert!(rust.contains("    pub const _2XL: Length = Length { value: 3.0, unit: \"rem\" };\n"), "{rust}");
+        assert!(rust.contains("    pub const BG: &str = \"var(--colors-primary-fg)\";\n"), "{rust}");
+        assert!(rust.contains("    pub const WEIGHT: f64 = 500.0;\n"), "{rust}");
+        syn::parse_file(&rust).expect("generated Rust parses");
+
+        let dts = set.to_dts().unwrap();
+        assert!(dts.contains("    readonly primaryFg: \"#fff\";\n"), "{dts}");
+        assert!(dts.contains("    readonly \"2xl\": \"3rem\";\n"), "{dts}");
+    }
+
+    #[test]
+    fn reports_every_invalid_value_and_reference() {
+        let bad = r#"
+structs:
+  - name: Colors
+    kind: color
+    fields:
+      bg: "12px"
+      fg: "var(--colors-nope)"
+  - name: Radius
+    kind: length
+    fields:
+      sm: "4"
+  - name: Shadow
+    kind: glow
+    fields: {}
+"#;
+        let err = parse(bad, "bad.yaml").unwrap_err().to_string();
+        assert!(err.contains("Colors.bg (color): `12px` is not a color"), "{err}");
+        assert!(err.contains("Colors.fg: `var(--colors-nope)` is not a token"), "{err}");
+        assert!(err.contains("Radius.sm (length): `4` is not a length"), "{err}");
+        assert!(err.contains("Shadow: unknown kind"), "{err}");
+    }
+
+    #[test]
+    fn bundled_design_tokens_compile() {
+        let set = load(Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../../resources/templates/design_tokens.yaml")))
+            .unwrap();
+        let names: Vec<&str> = set.groups.iter().map(|g| g.name.as_str()).collect();
+        assert_eq!(names, ["Colors", "Spacing", "Radius", "Shadow", "Font", "Button", "Card"]);
+        syn::parse_file(&set.to_rust().unwrap()).expect("generated Rust parses");
+
+        let custom = set
+            .render_with(Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../../resources/templates/css_from_tokens.mustache")))
+            .unwrap();
+        assert!(custom.contains("  --colors-primary-fg: #ffffff;\n"), "{custom}");
+    }
+}
@@ -0,0 +1,215 @@
+// ============================================================================
+// Forge Template — Design Token Values
+// File: tokens/value.rs
+// Role: Token kinds, per-kind validation of CSS values and the typed Rust
+//       literal each value becomes. CSS expressions (`var()`, `calc()`,
+//       `color-mix()`, …) are accepted for every kind and stay strings.
+// ============================================================================
+
+use serde::{Deserialize, Serialize};
+
+/// What a token's value must be.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenKind {
+    /// `#rgb[a]`, `#rrggbb[aa]`, `rgb[a]()` / `hsl[a]()`, `transparent`, `currentColor`
+    Color,
+    /// `0` or a number with a CSS unit (`px`, `rem`, `%`, …)
+    Length,
+    /// Unitless number (font weights, line heights, z-indices)
+    Number,
+    /// `none` or comma-separated layers of at least two lengths
+    Shadow,
+    /// Comma-separated font family list
+    FontFamily,
+    /// Anything non-empty
+    Raw,
+}
+
+impl TokenKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Color => "color",
+            Self::Length => "length",
+            Self::Number => "number",
+            Self::Shadow => "shadow",
+            Self::FontFamily => "font_family",
+            Self::Raw => "raw",
+        }
+    }
+
+    /// Best kind for a value without a declared kind.
+    pub fn infer(value: &str) -> Self {
+        if is_expression(value) {
+            Self::Raw
+        } else if parse_color(value).is_some() || is_color_function(value) {
+            Self::Color
+        } else if parse_length(value).is_some() {
+            Self::Length
+        } else if parse_number(value).is_some() {
+            Self::Number
+        } else {
+            Self::Raw
+        }
+    }
+
+    /// Why `value` is not a valid value of this kind, if it is not.
+    pub fn check(self, value: &str) -> Option<String> {
+        let value = value.trim();
+        if value.is_empty() {
+            return Some("value is empty".into());
+        }
+        if is_expression(value) {
+            return None;
+        }
+        let ok = match self {
+            Self::Color => parse_color(value).is_some() || is_color_function(value),
+            Self::Length => parse_length(value).is_some(),
+            Self::Number => parse_number(value).is_some(),
+            Self::Shadow => value == "none" || split_top_level(value).iter().all(|layer| is_shadow_layer(layer)),
+            Self::FontFamily => split_top_level(value).iter().all(|f| !f.is_empty()),
+            Self::Raw => true,
+        };
+        let expected = match self {
+            Self::Color => "a color (#hex, rgb()/hsl(), transparent)",
+            Self::Length => "a length (0 or a number with a unit such as px, rem, %)",
+            Self::Number => "a unitless number",
+            Self::Shadow => "a shadow (none, or layers of at least two lengths)",
+            Self::FontFamily => "a comma-separated font family list",
+            Self::Raw => "a value",
+        };
+        (!ok).then(|| format!("`{value}` is not {expected}"))
+    }
+}
+
+/// Typed Rust literal for a value: `(type, expression)`.
+/// Values without a typed form (expressions, shadows, …) are `&str`.
+pub fn rust_literal(kind: TokenKind, value: &str) -> (&'static str, String) {
+    let value = value.trim();
+    match kind {
+        TokenKind::Color => {
+            if let Some([r, g, b, a]) = parse_color(value) {
+                return ("Color", format!("Color {{ r: {r}, g: {g}, b: {b}, a: {a} }}"));
+            }
+        }
+        TokenKind::Length => {
+            if let Some((number, unit)) = parse_length(value) {
+                return ("Length", format!("Length {{ value: {number:?}, unit: {unit:?} }}"));
+            }
+        }
+        TokenKind::Number => {
+            if let Some(number) = parse_number(value) {
+                return ("f64", format!("{number:?}"));
+            }
+        }
+        _ => {}
+    }
+    ("&str", format!("{value:?}"))
+}
+
+/// `var(--x)` names referenced by `value`.
+pub fn var_refs(value: &str) -> Vec<&str> {
+    value
+        .match_indices("var(")
+        .filter_map(|(i, _)| {
+            let rest = value[i + 4..].trim_start();
+            let end = rest.find(|c: char| c == ',' || c == ')' || c.is_whitespace())?;
+            rest[..end].starts_with("--").then(|| &rest[..end])
+        })
+        .collect()
+}
+
+const EXPRESSIONS: [&str; 6] = ["var(", "calc(", "min(", "max(", "clamp(", "color-mix("];
+
+const UNITS: [&str; 14] = ["px", "rem", "em", "%", "vh", "vw", "vmin", "vmax", "ch", "ex", "pt", "dvh", "svh", "lvh"];
+
+fn is_expression(value: &str) -> bool {
+    EXPRESSIONS.iter().any(|f| value.starts_with(f)) && value.ends_with(')')
+}
+
+fn is_color_function(value: &str) -> bool {
+    let lower = value.to_ascii_lowercase();
+    ["rgb(", "rgba(", "hsl(", "hsla(", "oklch(", "oklab("].iter().any(|f| lower.starts_with(f)) && lower.ends_with(')')
+        || lower == "transparent"
+        || lower == "currentcolor"
+}
+
+/// `#rgb`, `#rgba`, `#rrggbb`, `#rrggbbaa` → `[r, g, b, a]`.
+fn parse_color(value: &str) -> Option<[u8; 4]> {
+    let hex = value.strip_prefix('#')?;
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let digits: Vec<u8> = match hex.len() {
+        3 | 4 => hex.chars().map(|c| c.to_digit(16).unwrap() as u8 * 17).collect(),
+        6 | 8 => (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap()).collect(),
+        _ => return None,
+    };
+    Some([digits[0], digits[1], digits[2], digits.get(3).copied().unwrap_or(255)])
+}
+
+fn parse_length(value: &str) -> Option<(f64, &str)> {
+    if value == "0" {
+        return Some((0.0, "px"));
+    }
+    let split = value.find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-'))?;
+    let (number, unit) = value.split_at(split);
+    UNITS.contains(&unit).then_some(())?;
+    Some((parse_number(number)?, UNITS.iter().find(|u| **u == unit)?))
+}
+
+fn parse_number(value: &str) -> Option<f64> {
+    let looks_numeric = !value.is_empty() && value.chars().all(|c| c.is_ascii_digit() || c == '.' || c == '-');
+    looks_numeric.then(|| value.parse().ok()).flatten()
+}
+
+fn is_shadow_layer(layer: &str) -> bool {
+    let lengths = layer
+        .split_whitespace()
+        .filter(|part| *part != "inset" && (parse_length(part).is_some() || is_expression(part)))
+        .count();
+    lengths >= 2
+}
+
+/// Split on commas that are not inside parentheses; parts are trimmed.
+fn split_top_level(value: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let (mut depth, mut start) = (0usize, 0);
+    for (i, c) in value.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(value[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(value[start..].trim());
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kinds_validate_and_type_values() {
+        assert_eq!(TokenKind::Color.check("#7c3aed"), None);
+        assert_eq!(TokenKind::Color.check("var(--colors-primary)"), None);
+        assert!(TokenKind::Color.check("#7c3ae").is_some());
+        assert!(TokenKind::Length.check("12").unwrap().contains("not a length"));
+        assert_eq!(TokenKind::Shadow.check("0 1px 2px rgba(0,0,0,0.06), inset 0 0 1px red"), None);
+        assert!(TokenKind::Shadow.check("rgba(0,0,0,0.06)").is_some());
+
+        assert_eq!(TokenKind::infer("0.25rem"), TokenKind::Length);
+        assert_eq!(TokenKind::infer("700"), TokenKind::Number);
+        assert_eq!(TokenKind::infer("calc(var(--spacing-xs) + 2px)"), TokenKind::Raw);
+
+        assert_eq!(rust_literal(TokenKind::Color, "#0d0d0d80").1, "Color { r: 13, g: 13, b: 13, a: 128 }");
+        assert_eq!(rust_literal(TokenKind::Length, "1.5rem").1, "Length { value: 1.5, unit: \"rem\" }");
+        assert_eq!(rust_literal(TokenKind::Number, "400"), ("f64", "400.0".into()));
+        assert_eq!(var_refs("calc(var(--spacing-xs) + var( --a, 1px))"), ["--spacing-xs", "--a"]);
+    }
+}
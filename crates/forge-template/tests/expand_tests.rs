@@ -2,10 +2,13 @@
 // Forge Integration Test
 // File: tests/expand_tests.rs
 // Crate: forge-template
-// Description: Verifies recursive expansion, type flags, and hasNext behavior.
+// Description: Verifies recursive expansion, type flags, iteration flags,
+//              `<key>_entries` lists for mappings and parent/root/path scope
+//              references.
 // ============================================================================
 
 use forge_template::context::codegen_ctx::build_yaml_codegen_context;
+use forge_template::Template;
 use serde_json::json;
 
 #[test]
@@ -43,3 +46,164 @@ fn expand_generates_case_variants_and_flags() {
 
     println!("✅ expand_generates_case_variants_and_flags executed successfully.");
 }
+
+#[test]
+fn mappings_get_ordered_entries_lists() {
+    let input = json!({
+        "header": { "type": "dto" },
+        "payload": {
+            "fields": { "user_id": "u64", "display_name": "String", "meta": { "zeta": 1, "alpha": true } }
+        }
+    });
+
+    let ctx = build_yaml_codegen_context(&input);
+    let entries = ctx["payload"]["fields_entries"].as_array().expect("fields_entries");
+    let keys: Vec<&str> = entries.iter().map(|e| e["key"].as_str().unwrap()).collect();
+    assert_eq!(keys, ["user_id", "display_name", "meta"], "source order by default");
+
+    assert_eq!(entries[0]["key_PascalCase"], "UserId");
+    assert_eq!(entries[0]["value"], "u64");
+    assert_eq!(entries[0]["is_string"], true);
+    assert_eq!(entries[0]["index"], 0);
+    assert_eq!(entries[0]["isFirst"], true);
+    assert_eq!(entries[2]["hasNext"], false);
+
+    // nested maps: scalars on the entry, the list beside the expanded map
+    assert_eq!(entries[2]["is_object"], true);
+    assert_eq!(entries[2]["value"]["zeta"], 1);
+    assert!(entries[2].get("value_entries").is_none());
+    assert_eq!(ctx["payload"]["fields"]["meta_entries"][1]["key"], "alpha");
+    assert_eq!(ctx["payload"]["fields"]["meta_entries"][1]["is_boolean"], true);
+
+    let sorted = json!({ "header": { "entries_order": "key" }, "payload": input["payload"].clone() });
+    let ctx = build_yaml_codegen_context(&sorted);
+    let keys: Vec<&str> = ctx["payload"]["fields_entries"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["key"].as_str().unwrap())
+        .collect();
+    assert_eq!(keys, ["display_name", "meta", "user_id"]);
+
+    let tpl = Template::compile(
+        "{{#payload.fields_entries}}{{#is_string}}{{key_camelCase}}: {{value}}{{#hasNext}}, {{/hasNext}}{{/is_string}}{{/payload.fields_entries}}",
+    )
+    .unwrap();
+    assert_eq!(tpl.render(&input).unwrap(), "userId: u64, displayName: String, ");
+}
+
+#[test]
+fn entries_do_not_copy_nested_subtrees() {
+    // ten levels of `{ name, child: { … } }`; copying each subtree into the
+    // parent's entries (or each ancestor chain into `parent`) grows the
+    // context per level
+    let mut payload = json!({ "name": "leaf" });
+    for depth in (0..10).rev() {
+        payload = json!({ "name": format!("level{depth}"), "child": payload });
+    }
+    let input = json!({ "header": { "type": "tree", "scope_refs": true }, "payload": payload });
+
+    let ctx = build_yaml_codegen_context(&input);
+    let size = serde_json::to_string(&ctx).unwrap().len();
+    assert!(size < 32 * 1024, "context grew to {size} bytes for a depth-10 map");
+
+    let mut node = &ctx["payload"];
+    for _ in 0..10 {
+        assert_eq!(node["child_entries"][0]["key"], "name");
+        node = &node["child"];
+    }
+    assert_eq!(node["name"], "leaf");
+}
+
+#[test]
+fn nested_sections_reach_enclosing_data() {
+    let input = json!({
+        "header": { "type": "service" },
+        "payload": {
+            "name": "store",
+            "traits": [
+                { "name": "reader", "methods": [{ "name": "get" }, { "name": "list" }] },
+                { "name": "writer", "methods": [{ "name": "put" }] }
+            ]
+        }
+    });
+
+    // off by default: no keys appear that could shadow enclosing data
+    let ctx = build_yaml_codegen_context(&input);
+    assert!(ctx.get("root").is_none());
+    assert!(ctx["payload"]["traits"][0].get("parent").is_none());
+
+    let on = json!({ "header": { "scope_refs": true }, "payload": input["payload"].clone() });
+    let ctx = build_yaml_codegen_context(&on);
+    assert_eq!(ctx["payload"]["traits"][1]["methods"][0]["path"], "traits[1].methods[0]");
+    assert_eq!(ctx["payload"]["traits"][1]["methods"][0]["parent"]["name_PascalCase"], "Writer");
+    assert_eq!(ctx["root"]["name"], "store");
+
+    // templates using `../` / `@root` turn them on themselves
+    let tpl = Template::compile(
+        "{{#payload.traits}}{{#methods}}{{../name_PascalCase}}::{{name}}@{{../../name}}/{{@root.name}} {{/methods}}{{/payload.traits}}",
+    )
+    .unwrap();
+    assert_eq!(
+        tpl.render(&input).unwrap(),
+        "Reader::get@store/store Reader::list@store/store Writer::put@store/store "
+    );
+
+    let off = json!({ "header": { "scope_refs": false }, "payload": input["payload"].clone() });
+    assert_eq!(tpl.render(&off).unwrap(), "::get@/ ::list@/ ::put@/ ");
+}
+
+#[test]
+fn plain_templates_see_enclosing_fields_unshadowed() {
+    let input = json!({ "payload": { "path": "/api", "routes": [{ "name": "users" }] } });
+    let tpl = Template::compile("{{#payload}}{{#routes}}{{path}}/{{name}}{{/routes}}{{/payload}}").unwrap();
+    assert_eq!(tpl.render(&input).unwrap(), "/api/users");
+}
+
+#[test]
+fn array_elements_carry_iteration_metadata() {
+    let input = json!({
+        "header": { "type": "enum" },
+        "payload": { "variants": ["idle", "busy", "done"], "codes": [200, 404], "tags": [] }
+    });
+
+    let ctx = build_yaml_codegen_context(&input);
+    let payload = &ctx["payload"];
+    assert_eq!(payload["variants_len"], 3);
+    assert_eq!(payload["variants_is_empty"], false);
+    assert_eq!(payload["tags_is_empty"], true);
+
+    let last = &payload["variants"][2];
+    assert_eq!((last["index"].clone(), last["index1"].clone()), (json!(2), json!(3)));
+    assert_eq!(last["isLast"], true);
+    assert_eq!(last["hasPrev"], true);
+    assert_eq!(payload["variants"][0]["isFirst"], true);
+    assert_eq!(payload["variants"][0]["hasPrev"], false);
+
+    // primitives are wrapped, not left unflagged
+    assert_eq!(payload["codes"][1]["value"], 404);
+    assert_eq!(payload["codes"][1]["is_integer"], true);
+    assert_eq!(payload["codes"][1]["isLast"], true);
+
+    let tpl = Template::compile(
+        "{{#payload.variants}}{{^isFirst}} | {{/isFirst}}{{value_PascalCase}} = {{index1}}{{/payload.variants}}",
+    )
+    .unwrap();
+    assert_eq!(tpl.render(&input).unwrap(), "Idle = 1 | Busy = 2 | Done = 3");
+}
+
+#[test]
+fn iteration_metadata_keeps_user_fields() {
+    let input = json!({
+        "header": { "type": "table" },
+        "payload": { "columns": [{ "name": "id", "index": "btree" }, { "name": "email" }] }
+    });
+
+    let ctx = build_yaml_codegen_context(&input);
+    assert_eq!(ctx["payload"]["columns"][0]["index"], "btree");
+    assert_eq!(ctx["payload"]["columns"][0]["index1"], 1);
+    assert_eq!(ctx["payload"]["columns"][1]["index"], 1);
+
+    let tpl = Template::compile("{{#payload.columns}}{{name}} index={{index}};{{/payload.columns}}").unwrap();
+    assert_eq!(tpl.render(&input).unwrap(), "id index=btree;email index=1;");
+}
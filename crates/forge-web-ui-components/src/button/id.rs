@@ -0,0 +1,12 @@
+//! Stable element ids for Button.
+//!
+//! Created by `forge-ide component new button` from
+//! `templates/ui_component/id.mustache`; edit freely, it is not regenerated.
+
+/// Prefix of every button element id
+pub const PREFIX: &str = "forge-button";
+
+/// `<PREFIX>-<key>`
+pub fn element_id(key: &str) -> String {
+    format!("{PREFIX}-{key}")
+}
@@ -1,8 +1,55 @@
-//! Module: button
-//! Auto-generated by create_mod_dir CLI.
-//! Add your module-specific code here.
+//! Forge UI Component: Button
+//! Auto-generated by forge-template.
+//!
+//! Kind: ui_component
+//! Generated from templates/ui_component/context.yaml using template templates/ui_component/mod.mustache
+//!
+//! -----------------------------------------------------------------------------
+//! ⚙️  This file was generated automatically.
+//! Do not edit directly — edit `context.yaml` or `mod.mustache` instead and
+//! rerun `forge-ide component new button`.
+//! -----------------------------------------------------------------------------
 
-pub fn init() {
-    println!("Initialized module: button");
-}
+// -----------------------------------------------------------------------------
+// Submodules
+// -----------------------------------------------------------------------------
+/// Submodule: Id
+pub mod id;
+/// Submodule: Model
 pub mod model;
+/// Submodule: Render
+pub mod render;
+/// Submodule: Style
+pub mod style;
+
+// -----------------------------------------------------------------------------
+// Exports
+// -----------------------------------------------------------------------------
+/// Component definition
+pub use model::Button;
+/// Data the component renders
+pub use model::ButtonViewModel;
+/// Visual and thematic styling
+pub use style::ButtonStyle;
+
+// -----------------------------------------------------------------------------
+// Type Summary
+// -----------------------------------------------------------------------------
+#[allow(dead_code)]
+pub fn describe_button() {
+    println!("🧩 Component: Button");
+    println!("📜 Description: Forge UI Component");
+    println!("🔖 Kind: ui_component");
+    println!("---");
+    println!("Modules:");
+    println!(" - Model (snake: model)");
+    println!(" - Style (snake: style)");
+    println!(" - Render (snake: render)");
+    println!(" - Id (snake: id)");
+    println!("Exports:");
+    println!(" - Model -> Button");
+    println!(" - Model -> ButtonViewModel");
+    println!(" - Style -> ButtonStyle");
+    println!("---");
+    println!("Source: templates/ui_component/context.yaml  |  Template: templates/ui_component/mod.mustache");
+}
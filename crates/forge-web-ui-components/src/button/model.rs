@@ -1,7 +1,52 @@
-//! Module: model
-//! Parent: button
-//! Auto-generated by create_mod_file CLI.
+//! Button component definition.
+//!
+//! Created by `forge-ide component new button` from
+//! `templates/ui_component/model.mustache`; edit freely, it is not regenerated.
 
-pub fn init() {
-    println!("Initialized button::model()");
+use super::style::{ButtonStyle, ButtonVariant};
+use crate::vdom::HandlerId;
+
+/// Configuration of a button: fixed for its lifetime.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Button {
+    /// Stable key, unique among siblings; part of the element id
+    pub key: String,
+    pub style: ButtonStyle,
+    /// Intent raised on click (not bound while disabled)
+    pub on_click: Option<HandlerId>,
+}
+
+impl Button {
+    pub fn new(key: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            ..Self::default()
+        }
+    }
+
+    pub fn on_click(mut self, handler: impl Into<HandlerId>) -> Self {
+        self.on_click = Some(handler.into());
+        self
+    }
+
+    pub fn variant(mut self, variant: ButtonVariant) -> Self {
+        self.style.variant = variant;
+        self
+    }
+}
+
+/// Data a button renders.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ButtonViewModel {
+    pub label: String,
+    pub disabled: bool,
+}
+
+impl ButtonViewModel {
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            disabled: false,
+        }
+    }
 }
@@ -0,0 +1,66 @@
+//! Virtual DOM view of Button.
+//!
+//! Created by `forge-ide component new button` from
+//! `templates/ui_component/render.mustache`; edit freely, it is not regenerated.
+
+use super::id::element_id;
+use super::model::Button;
+use super::model::ButtonViewModel;
+use crate::vdom::{VElement, VNode};
+use crate::Component;
+
+impl Component for Button {
+    type ViewModel = ButtonViewModel;
+
+    /// `<button type="button">` with the label as its only child; disabled
+    /// buttons carry `disabled` / `aria-disabled` and no click handler.
+    fn view(&self, vm: &ButtonViewModel) -> VNode {
+        let mut el = VElement::new("button")
+            .key(&self.key)
+            .attr("id", element_id(&self.key))
+            .attr("type", "button")
+            .attr("class", self.style.classes())
+            .flag("disabled", vm.disabled)
+            .text(&vm.label);
+        if vm.disabled {
+            el = el.attr("aria-disabled", "true");
+        } else if let Some(handler) = &self.on_click {
+            el = el.on("click", handler.clone());
+        }
+        el.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::button::style::ButtonVariant;
+    use crate::vdom::{apply, diff, render_to_string, Patch};
+
+    #[test]
+    fn button_renders_and_patches_between_states() {
+        let button = Button::new("build").on_click("BuildCmd").variant(ButtonVariant::Ghost);
+        let enabled = button.view(&ButtonViewModel::new("Build"));
+        assert_eq!(
+            render_to_string(&enabled),
+            "<button data-key=\"build\" class=\"forge-button forge-button--ghost\" id=\"forge-button-build\" \
+             type=\"button\" data-on-click=\"BuildCmd\">Build</button>"
+        );
+
+        let busy = ButtonViewModel { label: "Building…".into(), disabled: true };
+        let disabled = button.view(&busy);
+        let patches = diff(&enabled, &disabled);
+        assert_eq!(
+            patches,
+            [
+                Patch::SetAttr { path: vec![], name: "aria-disabled".into(), value: "true".into() },
+                Patch::SetAttr { path: vec![], name: "disabled".into(), value: "".into() },
+                Patch::RemoveHandler { path: vec![], event: "click".into() },
+                Patch::SetText { path: vec![0], text: "Building…".into() },
+            ]
+        );
+        let mut patched = enabled;
+        apply(&mut patched, &patches).unwrap();
+        assert_eq!(patched, disabled);
+    }
+}
@@ -0,0 +1,50 @@
+//! Visual and thematic styling for Button.
+//!
+//! Created by `forge-ide component new button` from
+//! `templates/ui_component/style.mustache`; edit freely, it is not regenerated.
+
+/// CSS class of the component root
+pub const CLASS: &str = "forge-button";
+
+/// Visual emphasis of a button.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ButtonVariant {
+    #[default]
+    Primary,
+    Secondary,
+    /// No background until hovered (toolbars)
+    Ghost,
+}
+
+impl ButtonVariant {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Primary => "primary",
+            Self::Secondary => "secondary",
+            Self::Ghost => "ghost",
+        }
+    }
+}
+
+/// Classes applied to a button.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ButtonStyle {
+    pub class: String,
+    pub variant: ButtonVariant,
+}
+
+impl ButtonStyle {
+    /// `class` plus the BEM modifier of the variant: `forge-button forge-button--primary`.
+    pub fn classes(&self) -> String {
+        format!("{} {CLASS}--{}", self.class, self.variant.as_str())
+    }
+}
+
+impl Default for ButtonStyle {
+    fn default() -> Self {
+        Self {
+            class: CLASS.to_string(),
+            variant: ButtonVariant::default(),
+        }
+    }
+}
@@ -0,0 +1,14 @@
+//! The component abstraction every UI component implements.
+
+use crate::vdom::VNode;
+
+/// A piece of UI rendered from a ViewModel slice.
+///
+/// `view` is pure: the same component and ViewModel always give the same
+/// tree, so the renderer can diff consecutive views into DOM patches.
+pub trait Component {
+    /// Data the component renders
+    type ViewModel;
+
+    fn view(&self, vm: &Self::ViewModel) -> VNode;
+}
@@ -1,15 +1,14 @@
-pub fn add(left: u64, right: u64) -> u64 {
-    left + right
-}
+//! Forge web UI components.
+//!
+//! Components are generated with `forge-ide component new <name>` from
+//! `templates/ui_component/` and implement [`Component`], which renders a
+//! ViewModel into a [`vdom::VNode`] tree. Trees are diffed into
+//! [`vdom::Patch`]es for the DOM patcher or rendered to HTML; [`workbench`]
+//! renders the IDE layout skeleton server-side.
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
-    }
-}
 pub mod button;
+pub mod component;
+pub mod vdom;
+pub mod workbench;
+
+pub use component::Component;
@@ -0,0 +1,299 @@
+//! Keyed diff of two virtual trees into DOM patch operations.
+//!
+//! Nodes are addressed by a path of child indices from the root. Patches are
+//! meant to be applied in order: every path refers to the tree as left by the
+//! patches before it. Children are matched by key when they have one and by
+//! position among the unkeyed siblings otherwise; keys must be unique among
+//! siblings (later duplicates are treated as new nodes).
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use super::node::{HandlerId, VElement, VNode};
+
+/// Child indices from the root; empty for the root itself.
+pub type Path = Vec<usize>;
+
+/// One DOM operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Patch {
+    /// Swap the node at `path` for `node`
+    Replace { path: Path, node: VNode },
+    /// Text content of the text node at `path`
+    SetText { path: Path, text: String },
+    SetAttr { path: Path, name: String, value: String },
+    RemoveAttr { path: Path, name: String },
+    SetHandler { path: Path, event: String, handler: HandlerId },
+    RemoveHandler { path: Path, event: String },
+    /// Insert `node` as child `index` of the element at `parent`
+    Insert { parent: Path, index: usize, node: VNode },
+    /// Remove child `index` of the element at `parent`
+    Remove { parent: Path, index: usize },
+    /// Move child `from` of the element at `parent` to position `to`
+    Move { parent: Path, from: usize, to: usize },
+}
+
+/// Patches turning `old` into `new`.
+pub fn diff(old: &VNode, new: &VNode) -> Vec<Patch> {
+    let mut patches = Vec::new();
+    diff_node(old, new, &mut Vec::new(), &mut patches);
+    patches
+}
+
+fn diff_node(old: &VNode, new: &VNode, path: &mut Path, patches: &mut Vec<Patch>) {
+    match (old, new) {
+        (VNode::Text(a), VNode::Text(b)) => {
+            if a != b {
+                patches.push(Patch::SetText { path: path.clone(), text: b.clone() });
+            }
+        }
+        (VNode::Element(a), VNode::Element(b)) if a.tag == b.tag && a.key == b.key => {
+            diff_map(&a.attrs, &b.attrs, patches, |name, value| match value {
+                Some(value) => Patch::SetAttr { path: path.clone(), name: name.clone(), value: value.clone() },
+                None => Patch::RemoveAttr { path: path.clone(), name: name.clone() },
+            });
+            diff_map(&a.events, &b.events, patches, |event, handler| match handler {
+                Some(handler) => Patch::SetHandler { path: path.clone(), event: event.clone(), handler: handler.clone() },
+                None => Patch::RemoveHandler { path: path.clone(), event: event.clone() },
+            });
+            diff_children(a, b, path, patches);
+        }
+        _ => patches.push(Patch::Replace { path: path.clone(), node: new.clone() }),
+    }
+}
+
+/// Set changed / added entries, remove missing ones.
+fn diff_map<V: PartialEq>(
+    old: &BTreeMap<String, V>,
+    new: &BTreeMap<String, V>,
+    patches: &mut Vec<Patch>,
+    patch: impl Fn(&String, Option<&V>) -> Patch,
+) {
+    for name in old.keys().filter(|name| !new.contains_key(*name)) {
+        patches.push(patch(name, None));
+    }
+    for (name, value) in new {
+        if old.get(name) != Some(value) {
+            patches.push(patch(name, Some(value)));
+        }
+    }
+}
+
+/// How a child is recognised across renders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Identity<'a> {
+    Key(&'a str),
+    /// n-th unkeyed child
+    Position(usize),
+}
+
+fn identities(children: &[VNode]) -> Vec<Identity<'_>> {
+    let mut unkeyed = 0;
+    children
+        .iter()
+        .map(|child| match child.key() {
+            Some(key) => Identity::Key(key),
+            None => {
+                unkeyed += 1;
+                Identity::Position(unkeyed - 1)
+            }
+        })
+        .collect()
+}
+
+/// Remove unmatched old children (last first), then walk the new children in
+/// order: move matched ones into place and diff them, insert the rest.
+/// Positions before the current index are final, so paths stay valid.
+fn diff_children(old: &VElement, new: &VElement, path: &mut Path, patches: &mut Vec<Patch>) {
+    let old_ids = identities(&old.children);
+    let new_ids = identities(&new.children);
+
+    let mut wanted: HashMap<Identity, usize> = HashMap::new();
+    for (i, id) in new_ids.iter().enumerate() {
+        wanted.entry(*id).or_insert(i);
+    }
+    let mut available: HashMap<Identity, usize> = HashMap::new();
+    for (i, id) in old_ids.iter().enumerate() {
+        if wanted.contains_key(id) {
+            available.entry(*id).or_insert(i);
+        }
+    }
+
+    // simulated child list: index into `old.children` per current position
+    let mut current: Vec<usize> = (0..old.children.len()).collect();
+    for i in (0..old.children.len()).rev() {
+        if available.get(&old_ids[i]) != Some(&i) {
+            current.remove(i);
+            patches.push(Patch::Remove { parent: path.clone(), index: i });
+        }
+    }
+
+    for (i, (id, child)) in new_ids.iter().zip(&new.children).enumerate() {
+        let matched = (wanted.get(id) == Some(&i)).then(|| available.get(id).copied()).flatten();
+        match matched {
+            Some(from_old) => {
+                let at = current.iter().position(|&o| o == from_old).expect("matched child is present");
+                if at != i {
+                    let moved = current.remove(at);
+                    current.insert(i, moved);
+                    patches.push(Patch::Move { parent: path.clone(), from: at, to: i });
+                }
+                path.push(i);
+                diff_node(&old.children[from_old], child, path, patches);
+                path.pop();
+            }
+            None => {
+                current.insert(i, usize::MAX);
+                patches.push(Patch::Insert { parent: path.clone(), index: i, node: child.clone() });
+            }
+        }
+    }
+}
+
+/// A patch that does not fit the tree it is applied to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatchError {
+    /// Index of the offending patch
+    pub index: usize,
+    pub patch: Box<Patch>,
+}
+
+impl fmt::Display for PatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "patch {} does not apply: {:?}", self.index, self.patch)
+    }
+}
+
+impl std::error::Error for PatchError {}
+
+/// Apply `patches` to `root` in order, the way a DOM patcher would.
+pub fn apply(root: &mut VNode, patches: &[Patch]) -> Result<(), PatchError> {
+    for (index, patch) in patches.iter().enumerate() {
+        apply_one(root, patch).ok_or_else(|| PatchError { index, patch: Box::new(patch.clone()) })?;
+    }
+    Ok(())
+}
+
+fn apply_one(root: &mut VNode, patch: &Patch) -> Option<()> {
+    match patch {
+        Patch::Replace { path, node } => *node_at(root, path)? = node.clone(),
+        Patch::SetText { path, text } => match node_at(root, path)? {
+            VNode::Text(current) => *current = text.clone(),
+            VNode::Element(_) => return None,
+        },
+        Patch::SetAttr { path, name, value } => {
+            element_at(root, path)?.attrs.insert(name.clone(), value.clone());
+        }
+        Patch::RemoveAttr { path, name } => {
+            element_at(root, path)?.attrs.remove(name)?;
+        }
+        Patch::SetHandler { path, event, handler } => {
+            element_at(root, path)?.events.insert(event.clone(), handler.clone());
+        }
+        Patch::RemoveHandler { path, event } => {
+            element_at(root, path)?.events.remove(event)?;
+        }
+        Patch::Insert { parent, index, node } => {
+            let children = &mut element_at(root, parent)?.children;
+            (*index <= children.len()).then(|| children.insert(*index, node.clone()))?;
+        }
+        Patch::Remove { parent, index } => {
+            let children = &mut element_at(root, parent)?.children;
+            (*index < children.len()).then(|| children.remove(*index))?;
+        }
+        Patch::Move { parent, from, to } => {
+            let children = &mut element_at(root, parent)?.children;
+            (*from < children.len() && *to < children.len()).then_some(())?;
+            let child = children.remove(*from);
+            children.insert(*to, child);
+        }
+    }
+    Some(())
+}
+
+fn node_at<'a>(root: &'a mut VNode, path: &[usize]) -> Option<&'a mut VNode> {
+    path.iter().try_fold(root, |node, &i| match node {
+        VNode::Element(el) => el.children.get_mut(i),
+        VNode::Text(_) => None,
+    })
+}
+
+fn element_at<'a>(root: &'a mut VNode, path: &[usize]) -> Option<&'a mut VElement> {
+    match node_at(root, path)? {
+        VNode::Element(el) => Some(el),
+        VNode::Text(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(key: &str) -> VNode {
+        VElement::new("li").key(key).text(key).into()
+    }
+
+    fn list(keys: &[&str]) -> VNode {
+        VElement::new("ul").children(keys.iter().map(|k| item(k))).into()
+    }
+
+    fn roundtrip(old: &VNode, new: &VNode) -> Vec<Patch> {
+        let patches = diff(old, new);
+        let mut patched = old.clone();
+        apply(&mut patched, &patches).unwrap();
+        assert_eq!(&patched, new, "patches: {patches:?}");
+        patches
+    }
+
+    #[test]
+    fn keyed_children_are_moved_not_recreated() {
+        let patches = roundtrip(&list(&["a", "b", "c", "d"]), &list(&["d", "a", "c", "e"]));
+        assert_eq!(
+            patches,
+            [
+                Patch::Remove { parent: vec![], index: 1 },
+                Patch::Move { parent: vec![], from: 2, to: 0 },
+                Patch::Insert { parent: vec![], index: 3, node: item("e") },
+            ]
+        );
+        assert!(roundtrip(&list(&["a", "b"]), &list(&["a", "b"])).is_empty());
+        roundtrip(&list(&["a", "b", "c"]), &list(&[]));
+        roundtrip(&list(&[]), &list(&["x", "y"]));
+        roundtrip(&list(&["a", "b", "c", "d", "e"]), &list(&["e", "d", "c", "b", "a"]));
+    }
+
+    #[test]
+    fn attributes_handlers_text_and_kinds_are_patched_in_place() {
+        let old: VNode = VElement::new("div")
+            .attr("class", "a")
+            .attr("title", "t")
+            .on("click", "Open")
+            .text("one")
+            .child(VElement::new("span"))
+            .into();
+        let new: VNode = VElement::new("div")
+            .attr("class", "b")
+            .on("input", "Edit")
+            .text("two")
+            .child(VElement::new("em"))
+            .child(VElement::new("li").key("k"))
+            .into();
+        let patches = roundtrip(&old, &new);
+        assert!(patches.contains(&Patch::SetText { path: vec![0], text: "two".into() }));
+        assert!(patches.contains(&Patch::RemoveAttr { path: vec![], name: "title".into() }));
+        assert!(patches.contains(&Patch::Replace { path: vec![1], node: VElement::new("em").into() }));
+
+        // mixed keyed / unkeyed siblings, duplicate keys, root replacement
+        let mixed = |keys: &[&str]| -> VNode {
+            VElement::new("ol")
+                .children(keys.iter().map(|k| if k.is_empty() { VNode::text("-") } else { item(k) }))
+                .into()
+        };
+        roundtrip(&mixed(&["a", "", "b", "", "a"]), &mixed(&["", "b", "a", "a", ""]));
+        roundtrip(&old, &VNode::text("gone"));
+
+        let mut tree = list(&["a"]);
+        let err = apply(&mut tree, &[Patch::Remove { parent: vec![0, 0], index: 0 }]).unwrap_err();
+        assert_eq!(err.index, 0);
+    }
+}
@@ -0,0 +1,89 @@
+//! HTML string rendering (server-side rendering and tests).
+//!
+//! Keys become `data-key` and handlers `data-on-<event>="<handler id>"`, so a
+//! client runtime can bind events to the rendered markup without the tree.
+
+use std::fmt::Write;
+
+use super::node::{VElement, VNode};
+
+/// Elements that never have children or a closing tag.
+const VOID_ELEMENTS: [&str; 8] = ["area", "br", "col", "hr", "img", "input", "link", "meta"];
+
+/// `node` as HTML; text and attribute values are escaped.
+pub fn render_to_string(node: &VNode) -> String {
+    let mut out = String::new();
+    write_node(&mut out, node);
+    out
+}
+
+fn write_node(out: &mut String, node: &VNode) {
+    match node {
+        VNode::Text(text) => out.push_str(&escape(text)),
+        VNode::Element(el) => write_element(out, el),
+    }
+}
+
+fn write_element(out: &mut String, el: &VElement) {
+    out.push('<');
+    out.push_str(&el.tag);
+    if let Some(key) = &el.key {
+        write_attr(out, "data-key", key);
+    }
+    for (name, value) in &el.attrs {
+        write_attr(out, name, value);
+    }
+    for (event, handler) in &el.events {
+        write_attr(out, &format!("data-on-{event}"), handler.as_str());
+    }
+    out.push('>');
+    if VOID_ELEMENTS.contains(&el.tag.as_str()) {
+        return;
+    }
+    for child in &el.children {
+        write_node(out, child);
+    }
+    let _ = write!(out, "</{}>", el.tag);
+}
+
+fn write_attr(out: &mut String, name: &str, value: &str) {
+    let _ = write!(out, " {name}=\"{}\"", escape(value));
+}
+
+/// Escape `&`, `<`, `>`, `"` and `'`.
+pub fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_escaped_deterministic_markup() {
+        let node: VNode = VElement::new("form")
+            .key("f1")
+            .attr("title", "a \"quoted\" <title>")
+            .attr("class", "x")
+            .on("submit", "SaveFile")
+            .child(VElement::new("input").attr("value", "R&D").text("ignored"))
+            .text("1 < 2")
+            .into();
+        assert_eq!(
+            render_to_string(&node),
+            "<form data-key=\"f1\" class=\"x\" title=\"a &quot;quoted&quot; &lt;title&gt;\" data-on-submit=\"SaveFile\">\
+             <input value=\"R&amp;D\">1 &lt; 2</form>"
+        );
+    }
+}
@@ -0,0 +1,10 @@
+//! Target-independent virtual DOM: nodes, a keyed diff producing DOM patch
+//! operations, and an HTML string renderer. Nothing here needs a browser.
+
+pub mod diff;
+pub mod html;
+pub mod node;
+
+pub use diff::{apply, diff, Patch, PatchError, Path};
+pub use html::render_to_string;
+pub use node::{HandlerId, VElement, VNode};
@@ -0,0 +1,137 @@
+//! Virtual DOM nodes.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Identifier of an event handler. The runtime (browser glue, test harness)
+/// maps it to an intent; nodes never hold closures, so trees stay `Clone`,
+/// comparable and renderable without a DOM.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HandlerId(pub String);
+
+impl HandlerId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for HandlerId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for HandlerId {
+    fn from(id: &str) -> Self {
+        Self::new(id)
+    }
+}
+
+/// A node of the virtual tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VNode {
+    Element(VElement),
+    Text(String),
+}
+
+impl VNode {
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::Text(text.into())
+    }
+
+    /// Sibling key, if this is a keyed element.
+    pub fn key(&self) -> Option<&str> {
+        match self {
+            Self::Element(el) => el.key.as_deref(),
+            Self::Text(_) => None,
+        }
+    }
+
+    pub fn as_element(&self) -> Option<&VElement> {
+        match self {
+            Self::Element(el) => Some(el),
+            Self::Text(_) => None,
+        }
+    }
+
+    /// Child nodes (none for text).
+    pub fn children(&self) -> &[VNode] {
+        match self {
+            Self::Element(el) => &el.children,
+            Self::Text(_) => &[],
+        }
+    }
+}
+
+impl From<VElement> for VNode {
+    fn from(el: VElement) -> Self {
+        Self::Element(el)
+    }
+}
+
+/// An element: tag, optional sibling key, attributes, event handlers and children.
+/// Attributes and handlers are ordered maps, so rendering is deterministic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VElement {
+    pub tag: String,
+    /// Identifies the element among its siblings across renders (see [`crate::vdom::diff`])
+    pub key: Option<String>,
+    pub attrs: BTreeMap<String, String>,
+    /// Event name (`click`, `input`, …) → handler
+    pub events: BTreeMap<String, HandlerId>,
+    pub children: Vec<VNode>,
+}
+
+impl VElement {
+    pub fn new(tag: impl Into<String>) -> Self {
+        Self {
+            tag: tag.into(),
+            key: None,
+            attrs: BTreeMap::new(),
+            events: BTreeMap::new(),
+            children: Vec::new(),
+        }
+    }
+
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+
+    pub fn attr(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.attrs.insert(name.into(), value.into());
+        self
+    }
+
+    /// Set attribute `name` (to an empty value) only when `on`, e.g. `disabled`.
+    pub fn flag(self, name: impl Into<String>, on: bool) -> Self {
+        if on {
+            self.attr(name, "")
+        } else {
+            self
+        }
+    }
+
+    pub fn on(mut self, event: impl Into<String>, handler: impl Into<HandlerId>) -> Self {
+        self.events.insert(event.into(), handler.into());
+        self
+    }
+
+    pub fn child(mut self, child: impl Into<VNode>) -> Self {
+        self.children.push(child.into());
+        self
+    }
+
+    pub fn children(mut self, children: impl IntoIterator<Item = VNode>) -> Self {
+        self.children.extend(children);
+        self
+    }
+
+    pub fn text(self, text: impl Into<String>) -> Self {
+        self.child(VNode::text(text))
+    }
+}
@@ -0,0 +1,161 @@
+//! The `ui_tree` section of a workbench spec and its validation.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::Deserialize;
+
+/// Whole spec file; sections other than `ui_tree` are ignored here.
+#[derive(Debug, Clone, Deserialize)]
+pub struct WorkbenchSpec {
+    pub ui_tree: UiTree,
+}
+
+/// Spatial containment tree: `root` plus named nodes.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct UiTree {
+    pub root: String,
+    pub nodes: BTreeMap<String, UiNode>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct UiNode {
+    /// `container`, `toolbar`, `split(horizontal)`, … (see [`NodeKind`])
+    pub kind: String,
+    #[serde(default)]
+    pub children: Vec<String>,
+}
+
+/// Split direction: `horizontal` lays children out side by side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    Horizontal,
+    Vertical,
+}
+
+impl Orientation {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Horizontal => "horizontal",
+            Self::Vertical => "vertical",
+        }
+    }
+
+    /// Orientation of the separator between the panes of a split.
+    pub fn cross(self) -> Self {
+        match self {
+            Self::Horizontal => Self::Vertical,
+            Self::Vertical => Self::Horizontal,
+        }
+    }
+}
+
+/// Node kinds the workbench renderer knows; each maps to a region component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeKind {
+    Container,
+    Toolbar,
+    Split(Orientation),
+    NavTree,
+    CodeEditor,
+    ConsoleDock,
+    StatusLine,
+}
+
+impl NodeKind {
+    pub fn parse(kind: &str) -> Option<Self> {
+        Some(match kind.trim() {
+            "container" => Self::Container,
+            "toolbar" => Self::Toolbar,
+            "split(horizontal)" => Self::Split(Orientation::Horizontal),
+            "split(vertical)" => Self::Split(Orientation::Vertical),
+            "nav_tree" => Self::NavTree,
+            "code_editor" => Self::CodeEditor,
+            "console_dock" => Self::ConsoleDock,
+            "status_line" => Self::StatusLine,
+            _ => return None,
+        })
+    }
+}
+
+/// Why a `ui_tree` cannot be rendered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LayoutError {
+    /// The YAML does not have the `ui_tree` shape
+    Parse(String),
+    UnknownKind { node: String, kind: String },
+    /// `name` is the root or a child but has no entry in `nodes`
+    MissingNode { parent: Option<String>, name: String },
+    /// Containment loops back: `path` ends with the repeated node
+    Cycle { path: Vec<String> },
+    /// A node reachable through two parents (element ids must be unique)
+    SharedNode { name: String, parents: [String; 2] },
+}
+
+impl fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(msg) => write!(f, "invalid workbench spec: {msg}"),
+            Self::UnknownKind { node, kind } => write!(f, "node `{node}` has unknown kind `{kind}`"),
+            Self::MissingNode { parent: Some(parent), name } => {
+                write!(f, "node `{parent}` lists child `{name}`, which is not defined")
+            }
+            Self::MissingNode { parent: None, name } => write!(f, "root node `{name}` is not defined"),
+            Self::Cycle { path } => write!(f, "ui_tree cycle: {}", path.join(" -> ")),
+            Self::SharedNode { name, parents: [a, b] } => {
+                write!(f, "node `{name}` is a child of both `{a}` and `{b}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LayoutError {}
+
+impl UiTree {
+    /// `ui_tree` of a workbench spec (YAML text).
+    pub fn parse(yaml: &str) -> Result<Self, LayoutError> {
+        serde_yaml::from_str::<WorkbenchSpec>(yaml)
+            .map(|spec| spec.ui_tree)
+            .map_err(|e| LayoutError::Parse(e.to_string()))
+    }
+
+    /// Check every node reachable from `root`: known kind, defined children,
+    /// no cycles and a single parent each.
+    pub fn validate(&self) -> Result<(), LayoutError> {
+        let mut parents = BTreeMap::new();
+        self.visit(None, &self.root, &mut Vec::new(), &mut parents)
+    }
+
+    fn visit<'a>(
+        &'a self,
+        parent: Option<&'a str>,
+        name: &'a str,
+        stack: &mut Vec<&'a str>,
+        parents: &mut BTreeMap<&'a str, Option<&'a str>>,
+    ) -> Result<(), LayoutError> {
+        if let Some(start) = stack.iter().position(|n| *n == name) {
+            let mut path: Vec<String> = stack[start..].iter().map(|n| n.to_string()).collect();
+            path.push(name.to_string());
+            return Err(LayoutError::Cycle { path });
+        }
+        if let Some(first) = parents.insert(name, parent) {
+            return Err(LayoutError::SharedNode {
+                name: name.to_string(),
+                parents: [first.unwrap_or_default().to_string(), parent.unwrap_or_default().to_string()],
+            });
+        }
+        let node = self.nodes.get(name).ok_or_else(|| LayoutError::MissingNode {
+            parent: parent.map(str::to_string),
+            name: name.to_string(),
+        })?;
+        if NodeKind::parse(&node.kind).is_none() {
+            return Err(LayoutError::UnknownKind { node: name.to_string(), kind: node.kind.clone() });
+        }
+        stack.push(name);
+        for child in &node.children {
+            self.visit(Some(name), child, stack, parents)?;
+        }
+        stack.pop();
+        Ok(())
+    }
+}
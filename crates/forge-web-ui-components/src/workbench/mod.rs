@@ -0,0 +1,83 @@
+//! Server-side rendering of the workbench layout (`ui_tree` of
+//! `resources/forge_ide_workbench.yaml`) into an HTML skeleton: one region
+//! component per node, with ARIA roles and stable `forge-wb-<node>` ids for
+//! the DOM patcher to target.
+
+pub mod layout;
+pub mod regions;
+
+pub use layout::{LayoutError, NodeKind, Orientation, UiNode, UiTree};
+pub use regions::Region;
+
+use crate::vdom::{render_to_string, VNode};
+
+impl UiTree {
+    /// Virtual tree of the layout, after [`UiTree::validate`].
+    pub fn view(&self) -> Result<VNode, LayoutError> {
+        self.validate()?;
+        Ok(self.view_node(&self.root))
+    }
+
+    /// Validated nodes only: every name is defined and every kind known.
+    fn view_node(&self, name: &str) -> VNode {
+        let node = &self.nodes[name];
+        let region = Region {
+            name: name.to_string(),
+            children: node.children.iter().map(|child| self.view_node(child)).collect(),
+        };
+        NodeKind::parse(&node.kind).expect("validated kind").view(&region)
+    }
+}
+
+/// Workbench HTML skeleton for a spec (YAML text).
+pub fn render_html(yaml: &str) -> Result<String, LayoutError> {
+    Ok(render_to_string(&UiTree::parse(yaml)?.view()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPEC: &str = include_str!("../../../../resources/forge_ide_workbench.yaml");
+
+    #[test]
+    fn workbench_spec_renders_landmarks_with_stable_ids() {
+        let html = render_html(SPEC).unwrap();
+        assert!(html.starts_with("<div data-key=\"app_shell\" aria-label=\"app shell\" class=\"forge-wb-container\" id=\"forge-wb-app-shell\" role=\"group\">"), "{html}");
+        for fragment in [
+            "id=\"forge-wb-header\" role=\"toolbar\"",
+            "aria-orientation=\"horizontal\" class=\"forge-wb-split\" id=\"forge-wb-body-split\"",
+            "<nav data-key=\"sidebar\"",
+            "<ul id=\"forge-wb-sidebar-items\" role=\"tree\"></ul></nav><div data-key=\"separator-1\" aria-orientation=\"vertical\" role=\"separator\"></div><main data-key=\"editor\"",
+            "id=\"forge-wb-terminal\" role=\"log\"",
+            "<footer data-key=\"status_bar\"",
+        ] {
+            assert!(html.contains(fragment), "missing {fragment}\n{html}");
+        }
+        // document order follows `children`
+        let order: Vec<usize> = ["forge-wb-header", "forge-wb-sidebar", "forge-wb-editor", "forge-wb-terminal", "forge-wb-status-bar"]
+            .iter()
+            .map(|id| html.find(&format!("id=\"{id}\"")).unwrap())
+            .collect();
+        assert!(order.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(render_html(SPEC).unwrap(), html);
+    }
+
+    #[test]
+    fn unknown_kinds_cycles_and_missing_nodes_are_errors() {
+        let tree = |nodes: &str| UiTree::parse(&format!("ui_tree:\n  root: a\n  nodes:\n{nodes}"));
+
+        let err = tree("    a: { kind: container, children: [b] }\n    b: { kind: carousel }\n").unwrap().view();
+        assert_eq!(err, Err(LayoutError::UnknownKind { node: "b".into(), kind: "carousel".into() }));
+
+        let err = tree("    a: { kind: container, children: [b] }\n    b: { kind: container, children: [a] }\n").unwrap().view();
+        assert_eq!(err.unwrap_err().to_string(), "ui_tree cycle: a -> b -> a");
+
+        let err = tree("    a: { kind: container, children: [b, c] }\n    b: { kind: toolbar, children: [c] }\n    c: { kind: toolbar }\n").unwrap().view();
+        assert!(matches!(err, Err(LayoutError::SharedNode { ref name, .. }) if name == "c"), "{err:?}");
+
+        let err = tree("    a: { kind: container, children: [ghost] }\n").unwrap().view();
+        assert!(matches!(err, Err(LayoutError::MissingNode { parent: Some(_), .. })));
+        assert!(matches!(UiTree::parse("ui_tree: []"), Err(LayoutError::Parse(_))));
+    }
+}
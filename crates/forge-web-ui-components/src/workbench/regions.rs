@@ -0,0 +1,166 @@
+//! One component per `ui_tree` node kind. Each renders a region skeleton —
+//! landmark element, ARIA role and label, stable id — around the regions of
+//! its child nodes; the widgets inside are rendered later from ViewModel slices.
+
+use super::layout::{NodeKind, Orientation};
+use crate::vdom::{VElement, VNode};
+use crate::Component;
+
+/// Prefix of every workbench element id
+pub const ID_PREFIX: &str = "forge-wb";
+
+/// ViewModel of a region: the node it renders and its rendered children.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Region {
+    /// Node name in the `ui_tree` (`status_bar`)
+    pub name: String,
+    pub children: Vec<VNode>,
+}
+
+impl Region {
+    /// `forge-wb-<name>` with `_` as `-`: stable across renders.
+    pub fn id(&self) -> String {
+        format!("{ID_PREFIX}-{}", self.name.replace('_', "-"))
+    }
+
+    pub fn label(&self) -> String {
+        self.name.replace('_', " ")
+    }
+
+    /// Element keyed and labelled for this region, holding its children.
+    fn element(&self, tag: &str, role: &str, kind: &str) -> VElement {
+        VElement::new(tag)
+            .key(&self.name)
+            .attr("id", self.id())
+            .attr("role", role)
+            .attr("aria-label", self.label())
+            .attr("class", format!("{ID_PREFIX}-{kind}"))
+            .children(self.children.iter().cloned())
+    }
+}
+
+/// `container`: grouping without semantics of its own
+pub struct Container;
+
+impl Component for Container {
+    type ViewModel = Region;
+
+    fn view(&self, region: &Region) -> VNode {
+        region.element("div", "group", "container").into()
+    }
+}
+
+/// `toolbar`
+pub struct Toolbar;
+
+impl Component for Toolbar {
+    type ViewModel = Region;
+
+    fn view(&self, region: &Region) -> VNode {
+        region
+            .element("div", "toolbar", "toolbar")
+            .attr("aria-orientation", "horizontal")
+            .into()
+    }
+}
+
+/// `split(horizontal|vertical)`: panes with a separator between each pair
+pub struct Split(pub Orientation);
+
+impl Component for Split {
+    type ViewModel = Region;
+
+    fn view(&self, region: &Region) -> VNode {
+        let separator = |i: usize| -> VNode {
+            VElement::new("div")
+                .key(format!("separator-{i}"))
+                .attr("role", "separator")
+                .attr("aria-orientation", self.0.cross().as_str())
+                .into()
+        };
+        let mut panes = Vec::new();
+        for (i, child) in region.children.iter().enumerate() {
+            if i > 0 {
+                panes.push(separator(i));
+            }
+            panes.push(child.clone());
+        }
+        let panes = Region { name: region.name.clone(), children: panes };
+        panes
+            .element("div", "group", "split")
+            .attr("aria-orientation", self.0.as_str())
+            .into()
+    }
+}
+
+/// `nav_tree`: navigation landmark around a tree widget
+pub struct NavTree;
+
+impl Component for NavTree {
+    type ViewModel = Region;
+
+    fn view(&self, region: &Region) -> VNode {
+        let tree = VElement::new("ul")
+            .attr("id", format!("{}-items", region.id()))
+            .attr("role", "tree");
+        region.element("nav", "navigation", "nav-tree").child(tree).into()
+    }
+}
+
+/// `code_editor`: main landmark around a multi-line text box
+pub struct CodeEditor;
+
+impl Component for CodeEditor {
+    type ViewModel = Region;
+
+    fn view(&self, region: &Region) -> VNode {
+        let textbox = VElement::new("div")
+            .attr("id", format!("{}-text", region.id()))
+            .attr("role", "textbox")
+            .attr("aria-multiline", "true");
+        region.element("main", "main", "code-editor").child(textbox).into()
+    }
+}
+
+/// `console_dock`: output log announced politely
+pub struct ConsoleDock;
+
+impl Component for ConsoleDock {
+    type ViewModel = Region;
+
+    fn view(&self, region: &Region) -> VNode {
+        region
+            .element("section", "log", "console-dock")
+            .attr("aria-live", "polite")
+            .into()
+    }
+}
+
+/// `status_line`
+pub struct StatusLine;
+
+impl Component for StatusLine {
+    type ViewModel = Region;
+
+    fn view(&self, region: &Region) -> VNode {
+        region
+            .element("footer", "status", "status-line")
+            .attr("aria-live", "polite")
+            .into()
+    }
+}
+
+impl NodeKind {
+    /// Render `region` with the component of this kind.
+    pub fn view(self, region: &Region) -> VNode {
+        match self {
+            Self::Container => Container.view(region),
+            Self::Toolbar => Toolbar.view(region),
+            Self::Split(orientation) => Split(orientation).view(region),
+            Self::NavTree => NavTree.view(region),
+            Self::CodeEditor => CodeEditor.view(region),
+            Self::ConsoleDock => ConsoleDock.view(region),
+            Self::StatusLine => StatusLine.view(region),
+        }
+    }
+}